@@ -0,0 +1,594 @@
+//! `chord2mml batch`: convert many inputs in parallel.
+//!
+//! Every line of every input file (or of stdin) is one entry, or with
+//! `--multi-line` or `--chart` every file is one; entries convert on a pool of
+//! threads, and each file's output is then written in order next to it
+//! (or under `--out-dir`). A failed entry does not stop the rest: failures
+//! are listed in a table at the end.
+
+use anyhow::{anyhow, Context, Result};
+use chord2mml_core::{Conversion, ConvertOptions, ConverterPool};
+use std::fs;
+use std::io::{self, BufRead, IsTerminal, Write};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
+
+use crate::output::{Format, Output, Writer};
+use crate::{locate, open_output, Batch, Failure, Reading};
+
+/// Extension of the files a directory is searched for.
+const CHORD_EXTENSION: &str = "chord";
+
+/// Where entries come from and where their output goes.
+struct Source {
+    /// The name in messages and the failure table
+    name: String,
+    /// `None` for stdin, whose output goes to `--output` or stdout
+    destination: Option<PathBuf>,
+    /// Non-empty lines with their line numbers
+    entries: Vec<(usize, String)>,
+}
+
+/// A row of the failure table.
+struct Failed {
+    name: String,
+    line: Option<usize>,
+    column: Option<usize>,
+    message: String,
+    /// Reading or writing failed rather than converting
+    io: bool,
+}
+
+pub fn run(
+    batch: Batch,
+    format: Format,
+    options: &ConvertOptions,
+    reading: Reading,
+    output_path: Option<&PathBuf>,
+) -> Result<(), Failure> {
+    let output =
+        Output::new(crate::output::Kind::Convert(format), Some(format)).map_err(Failure::Usage)?;
+    let paths = find_inputs(&batch.paths).map_err(Failure::Usage)?;
+    let read_stdin = batch.paths.is_empty() || batch.paths.iter().any(|path| path == "-");
+    if read_stdin && io::stdin().is_terminal() {
+        return Err(Failure::Usage(anyhow!(
+            "give PATHS or pipe the entries to convert to stdin"
+        )));
+    }
+    if output_path.is_some() && !read_stdin {
+        return Err(Failure::Usage(anyhow!(
+            "--output is for entries from stdin; use --out-dir for files"
+        )));
+    }
+    if read_stdin && output.is_binary() && output_path.is_none() && io::stdout().is_terminal() {
+        return Err(Failure::Usage(anyhow!(
+            "refusing to write binary output to a terminal; use --output"
+        )));
+    }
+
+    let mut failures = Vec::new();
+    let mut sources = Vec::new();
+    if read_stdin {
+        let lines: Vec<String> = io::stdin()
+            .lock()
+            .lines()
+            .collect::<io::Result<_>>()
+            .context("Failed to read stdin")
+            .map_err(Failure::Io)?;
+        sources.push(Source {
+            name: "<stdin>".to_string(),
+            destination: None,
+            entries: entries(lines.iter().map(String::as_str), reading.is_whole()),
+        });
+    }
+    for (path, relative) in paths {
+        let name = path.display().to_string();
+        let destination = match &batch.out_dir {
+            Some(directory) => directory.join(relative),
+            None => path.clone(),
+        }
+        .with_extension(format.extension());
+        if let Some(message) = clash(&path, &destination, &sources) {
+            failures.push(Failed {
+                name,
+                line: None,
+                column: None,
+                message,
+                io: true,
+            });
+            continue;
+        }
+        match fs::read_to_string(&path) {
+            Ok(text) => {
+                sources.push(Source {
+                    name,
+                    destination: Some(destination),
+                    entries: entries(text.lines(), reading.is_whole()),
+                });
+            }
+            Err(e) => failures.push(Failed {
+                name,
+                line: None,
+                column: None,
+                message: format!("Failed to read: {}", e),
+                io: true,
+            }),
+        }
+    }
+
+    let inputs: Vec<&str> = sources
+        .iter()
+        .flat_map(|source| source.entries.iter().map(|(_, entry)| entry.as_str()))
+        .collect();
+    let jobs = batch
+        .jobs
+        .or_else(|| thread::available_parallelism().ok().map(usize::from))
+        .unwrap_or(1);
+    let pool = ConverterPool::new(options.clone());
+    let mut results = convert_all(&pool, &inputs, jobs, reading).into_iter();
+
+    let mut converted = 0;
+    for source in &sources {
+        let results: Vec<_> = results.by_ref().take(source.entries.len()).collect();
+        let mut writer = None;
+        for ((line, entry), result) in source.entries.iter().zip(results) {
+            match result {
+                Ok(conversion) => {
+                    converted += 1;
+                    if writer.is_none() {
+                        match open(source, output_path) {
+                            Ok(destination) => {
+                                writer = Some(
+                                    Writer::new(output, options, destination)
+                                        .with_origin(source.name.clone()),
+                                )
+                            }
+                            Err(e) => {
+                                failures.push(io_failure(source, e));
+                                break;
+                            }
+                        }
+                    }
+                    let writer = writer.as_mut().expect("opened above");
+                    if let Err(e) = writer.entry(Some(*line), entry, conversion) {
+                        failures.push(io_failure(source, e));
+                        break;
+                    }
+                }
+                Err(e) => {
+                    let located = locate(Some(*line), entry, &e);
+                    failures.push(Failed {
+                        name: source.name.clone(),
+                        line: located.line,
+                        column: located.column,
+                        message: located.message,
+                        io: false,
+                    })
+                }
+            }
+        }
+        if let Some(writer) = writer {
+            if let Err(e) = writer.finish() {
+                failures.push(io_failure(source, e));
+            }
+        }
+    }
+
+    // Entries are lines, or whole files (or stdin) read as one chart
+    let unit = if reading.is_whole() {
+        "charts"
+    } else {
+        "lines"
+    };
+    eprintln!("Converted {} of {} {}", converted, inputs.len(), unit);
+    if failures.is_empty() {
+        return Ok(());
+    }
+    eprint!("{}", failure_table(&failures));
+    if failures.iter().any(|failed| failed.io) {
+        Err(Failure::Io(anyhow!(
+            "some files could not be read or written"
+        )))
+    } else {
+        Err(Failure::Conversion)
+    }
+}
+
+/// The files PATHS name: files as given, `*.chord` files under directories
+/// (with their path below the directory, kept under `--out-dir`), and the
+/// matches of glob patterns (for shells that do not expand them).
+fn find_inputs(paths: &[String]) -> Result<Vec<(PathBuf, PathBuf)>> {
+    let mut found = Vec::new();
+    for path in paths.iter().filter(|path| *path != "-") {
+        let as_path = Path::new(path);
+        if as_path.is_dir() {
+            let mut files = Vec::new();
+            chord_files(as_path, &mut files)
+                .with_context(|| format!("Failed to read directory {}", path))?;
+            if files.is_empty() {
+                return Err(anyhow!("no .{} files in {}", CHORD_EXTENSION, path));
+            }
+            for file in files {
+                let relative = file.strip_prefix(as_path).unwrap_or(&file).to_path_buf();
+                found.push((file, relative));
+            }
+        } else if as_path.exists() || !path.contains(['*', '?', '[']) {
+            // A missing file is reported when it is read
+            found.push((as_path.to_path_buf(), file_name(as_path)));
+        } else {
+            let matches = glob::glob(path)
+                .with_context(|| format!("Invalid pattern {}", path))?
+                .filter_map(Result::ok)
+                .filter(|file| file.is_file())
+                .collect::<Vec<_>>();
+            if matches.is_empty() {
+                return Err(anyhow!("no files match {}", path));
+            }
+            found.extend(matches.into_iter().map(|file| {
+                let relative = file_name(&file);
+                (file, relative)
+            }));
+        }
+    }
+    Ok(found)
+}
+
+/// The `*.chord` files under `directory`, in name order.
+fn chord_files(directory: &Path, files: &mut Vec<PathBuf>) -> io::Result<()> {
+    let mut entries = fs::read_dir(directory)?
+        .map(|entry| entry.map(|entry| entry.path()))
+        .collect::<io::Result<Vec<_>>>()?;
+    entries.sort();
+    for path in entries {
+        if path.is_dir() {
+            chord_files(&path, files)?;
+        } else if path.extension().is_some_and(|ext| ext == CHORD_EXTENSION) {
+            files.push(path);
+        }
+    }
+    Ok(())
+}
+
+/// Why the output of `path` cannot go to `destination`: it is the input
+/// itself, or an earlier source already writes there.
+fn clash(path: &Path, destination: &Path, sources: &[Source]) -> Option<String> {
+    let resolved_destination = resolved(destination);
+    if resolved(path) == resolved_destination {
+        return Some(format!(
+            "Output {} would overwrite the input; use --out-dir or another --format",
+            destination.display()
+        ));
+    }
+    sources
+        .iter()
+        .find(|source| {
+            source
+                .destination
+                .as_deref()
+                .is_some_and(|other| resolved(other) == resolved_destination)
+        })
+        .map(|source| {
+            format!(
+                "Output {} is also the output of {}",
+                destination.display(),
+                source.name
+            )
+        })
+}
+
+/// `path` with links and `.`/`..` resolved, for paths that may not exist
+/// yet: the longest existing ancestor is canonicalized.
+fn resolved(path: &Path) -> PathBuf {
+    if let Ok(canonical) = fs::canonicalize(path) {
+        return canonical;
+    }
+    match (path.parent(), path.file_name()) {
+        (Some(parent), Some(name)) if parent.as_os_str().is_empty() => {
+            resolved(Path::new(".")).join(name)
+        }
+        (Some(parent), Some(name)) => resolved(parent).join(name),
+        _ => path.to_path_buf(),
+    }
+}
+
+fn file_name(path: &Path) -> PathBuf {
+    path.file_name().map(PathBuf::from).unwrap_or_default()
+}
+
+/// The non-empty lines of a text with their line numbers, or all of it as
+/// one entry when `whole`.
+fn entries<'a>(lines: impl Iterator<Item = &'a str>, whole: bool) -> Vec<(usize, String)> {
+    if whole {
+        // One entry, blank lines kept so that its errors name the lines
+        let chart: Vec<&str> = lines.collect();
+        if chart.iter().all(|line| line.trim().is_empty()) {
+            return Vec::new();
+        }
+        return vec![(1, chart.join("\n"))];
+    }
+    lines
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(i, line)| (i + 1, line.to_string()))
+        .collect()
+}
+
+/// Convert `inputs` on `jobs` threads, each read as `reading` says; the
+/// results are in input order.
+fn convert_all(
+    pool: &ConverterPool,
+    inputs: &[&str],
+    jobs: usize,
+    reading: Reading,
+) -> Vec<Result<Conversion>> {
+    let next = AtomicUsize::new(0);
+    let mut results: Vec<Option<Result<Conversion>>> = inputs.iter().map(|_| None).collect();
+    thread::scope(|scope| {
+        let workers: Vec<_> = (0..jobs.clamp(1, inputs.len().max(1)))
+            .map(|_| {
+                scope.spawn(|| {
+                    let mut done = Vec::new();
+                    loop {
+                        let i = next.fetch_add(1, Ordering::Relaxed);
+                        let Some(input) = inputs.get(i) else {
+                            return done;
+                        };
+                        let result =
+                            pool.with_converter(|converter| reading.convert(converter, input));
+                        done.push((i, result));
+                    }
+                })
+            })
+            .collect();
+        for worker in workers {
+            for (i, result) in worker.join().expect("a conversion thread panicked") {
+                results[i] = Some(result);
+            }
+        }
+    });
+    results
+        .into_iter()
+        .map(|result| result.expect("every input is converted once"))
+        .collect()
+}
+
+fn open(source: &Source, output_path: Option<&PathBuf>) -> Result<Box<dyn Write>> {
+    match &source.destination {
+        Some(path) => {
+            if let Some(parent) = path
+                .parent()
+                .filter(|parent| !parent.as_os_str().is_empty())
+            {
+                fs::create_dir_all(parent)
+                    .with_context(|| format!("Failed to create {}", parent.display()))?;
+            }
+            open_output(Some(path))
+        }
+        None => open_output(output_path),
+    }
+}
+
+fn io_failure(source: &Source, error: anyhow::Error) -> Failed {
+    Failed {
+        name: source.name.clone(),
+        line: None,
+        column: None,
+        message: format!("{:#}", error),
+        io: true,
+    }
+}
+
+/// The failures as an aligned table.
+fn failure_table(failures: &[Failed]) -> String {
+    let number = |n: Option<usize>| n.map(|n| n.to_string()).unwrap_or_default();
+    let rows: Vec<[String; 4]> = failures
+        .iter()
+        .map(|failed| {
+            [
+                failed.name.clone(),
+                number(failed.line),
+                number(failed.column),
+                failed.message.clone(),
+            ]
+        })
+        .collect();
+    let header = ["FILE", "LINE", "COLUMN", "ERROR"].map(String::from);
+    let mut widths = [0; 3];
+    for row in std::iter::once(&header).chain(&rows) {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+    let mut table = String::new();
+    for row in std::iter::once(&header).chain(&rows) {
+        for (width, cell) in widths.iter().zip(row) {
+            table.push_str(cell);
+            table.push_str(&" ".repeat(width - cell.chars().count() + 2));
+        }
+        table.push_str(&row[3]);
+        table.push('\n');
+    }
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_convert_all_keeps_order() {
+        let pool = ConverterPool::new(ConvertOptions::default());
+        let inputs = ["C", "H", "F", "G7", "Am"];
+        let results = convert_all(&pool, &inputs, 3, Reading::Lines);
+        let mml: Vec<_> = results
+            .iter()
+            .map(|result| result.as_ref().map(|c| c.mml.as_str()).ok())
+            .collect();
+        assert_eq!(
+            mml,
+            [
+                Some("v11'c1eg'"),
+                None,
+                Some("v11'f1a<c'"),
+                Some("v11'g1b<df'"),
+                Some("v11'a1<ce'"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_multi_line_entries() {
+        let text = "key=D\n\nI IV\nV I\n";
+        assert_eq!(
+            entries(text.lines(), false),
+            [
+                (1, "key=D".to_string()),
+                (3, "I IV".to_string()),
+                (4, "V I".to_string())
+            ]
+        );
+        let chart = entries(text.lines(), true);
+        assert_eq!(chart, [(1, "key=D\n\nI IV\nV I".to_string())]);
+        assert!(entries("\n \n".lines(), true).is_empty());
+
+        let pool = ConverterPool::new(ConvertOptions::default());
+        let results = convert_all(
+            &pool,
+            &[chart[0].1.as_str(), "C\n\nH"],
+            2,
+            Reading::MultiLine,
+        );
+        assert_eq!(
+            results[0].as_ref().unwrap().mml,
+            pool.convert("key=D I IV V I").unwrap()
+        );
+        let error = results[1].as_ref().unwrap_err();
+        assert!(error.to_string().starts_with("Line 3:"), "{error}");
+        // The failure row names the line and column in the file
+        let located = locate(Some(1), "C\n\nH", error);
+        assert_eq!((located.line, located.column), (Some(3), Some(1)));
+        assert!(!located.message.starts_with("Line"), "{}", located.message);
+    }
+
+    #[test]
+    fn test_chart_entries() {
+        let pool = ConverterPool::new(ConvertOptions::default());
+        let song = "[C]Hello [G]world\n[Am]so [F]long";
+        let results = convert_all(&pool, &[song], 1, Reading::Chart { line_bars: true });
+        assert_eq!(
+            results[0].as_ref().unwrap().mml,
+            pool.convert("C G | Am F").unwrap()
+        );
+    }
+
+    #[test]
+    fn test_find_inputs() {
+        let root = std::env::temp_dir().join(format!("chord2mml-batch-{}", std::process::id()));
+        fs::create_dir_all(root.join("sub")).unwrap();
+        fs::write(root.join("b.chord"), "C").unwrap();
+        fs::write(root.join("sub").join("a.chord"), "F").unwrap();
+        fs::write(root.join("notes.txt"), "G").unwrap();
+
+        let dir = [root.display().to_string()];
+        let found = find_inputs(&dir).unwrap();
+        let relative: Vec<_> = found.iter().map(|(_, relative)| relative.clone()).collect();
+        assert_eq!(
+            relative,
+            [PathBuf::from("b.chord"), Path::new("sub").join("a.chord")]
+        );
+
+        let pattern = root.join("*.txt").display().to_string();
+        let found = find_inputs(&[pattern, "-".to_string()]).unwrap();
+        assert_eq!(
+            found,
+            [(root.join("notes.txt"), PathBuf::from("notes.txt"))]
+        );
+        assert!(find_inputs(&[root.join("*.mml").display().to_string()]).is_err());
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn test_outputs_never_overwrite() {
+        let root = std::env::temp_dir().join(format!("chord2mml-clash-{}", std::process::id()));
+        for directory in ["a", "b"] {
+            fs::create_dir_all(root.join(directory)).unwrap();
+            fs::write(
+                root.join(directory).join("x.chord"),
+                directory.to_uppercase(),
+            )
+            .unwrap();
+        }
+        fs::write(root.join("prog.txt"), "C F").unwrap();
+        let batch = |paths: &[&str], out_dir: Option<PathBuf>| Batch {
+            paths: paths
+                .iter()
+                .map(|path| root.join(path).display().to_string())
+                .collect(),
+            out_dir,
+            jobs: Some(2),
+        };
+        let options = ConvertOptions::default();
+
+        // The notes of prog.txt would go to prog.txt
+        let result = run(
+            batch(&["prog.txt"], None),
+            Format::Notes,
+            &options,
+            Reading::Lines,
+            None,
+        );
+        assert!(matches!(result, Err(Failure::Io(_))));
+        assert_eq!(fs::read_to_string(root.join("prog.txt")).unwrap(), "C F");
+
+        // a/x.chord and b/x.chord would both go to out/x.mml
+        let out = root.join("out");
+        let result = run(
+            batch(&["a/x.chord", "b/x.chord"], Some(out.clone())),
+            Format::Mml,
+            &options,
+            Reading::Lines,
+            None,
+        );
+        assert!(matches!(result, Err(Failure::Io(_))));
+        assert_eq!(
+            fs::read_to_string(out.join("x.mml")).unwrap(),
+            "v11'a1<c+e'\n"
+        );
+        let result = run(
+            batch(&["a", "b"], Some(out)),
+            Format::Mml,
+            &options,
+            Reading::Lines,
+            None,
+        );
+        assert!(matches!(result, Err(Failure::Io(_))));
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn test_failure_table() {
+        let failures = [
+            Failed {
+                name: "a.chord".to_string(),
+                line: Some(12),
+                column: Some(3),
+                message: "Syntax error".to_string(),
+                io: false,
+            },
+            Failed {
+                name: "songs/b.chord".to_string(),
+                line: None,
+                column: None,
+                message: "Failed to read".to_string(),
+                io: true,
+            },
+        ];
+        assert_eq!(
+            failure_table(&failures),
+            "FILE           LINE  COLUMN  ERROR\n\
+             a.chord        12    3       Syntax error\n\
+             songs/b.chord                Failed to read\n"
+        );
+    }
+}
@@ -1,42 +1,923 @@
-use anyhow::Result;
-use chord2mml_core::convert;
-use std::env;
-use std::io::{self, BufRead};
-
-fn main() -> Result<()> {
-    let args: Vec<String> = env::args().collect();
-
-    if args.len() > 1 {
-        // Process command-line arguments
-        let input = args[1..].join(" ");
-        process_input(&input)?;
-    } else {
-        // Read from stdin
-        println!("chord2mml - Convert chord notation to MML");
-        println!("Enter chord notation (e.g., 'C' or 'C-F-G-C'):");
-        println!("Press Ctrl+D (Unix) or Ctrl+Z (Windows) to exit\n");
+mod batch;
+mod output;
+mod play;
+mod repl;
+
+use anyhow::{anyhow, Context, Result};
+use chord2mml_core::{
+    generate, Conversion, ConvertOptions, Converter, Degrees, DiatonicQuality, Envelope,
+    GenerateOptions, InputError, Key, LineError, MmlProfile, Reharmonization, Span, Spelling,
+    Style, SynthOptions, Waveform,
+};
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use std::fs;
+use std::io::{self, IsTerminal, Write};
+use std::path::PathBuf;
+use std::process::ExitCode;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use output::{Format, Kind, Output, Writer};
+
+/// Convert chord notation to MML.
+///
+/// Without a subcommand, converts like `chord2mml convert`. Inputs are the
+/// CHORDS arguments (joined into one progression), else the lines of
+/// --input, else the lines of stdin; each line converts on its own. With
+/// neither and stdin at a terminal, starts the REPL (`chord2mml repl`).
+#[derive(Parser)]
+#[command(name = "chord2mml", version)]
+#[command(
+    after_help = "Exit status: 0 on success, 1 when an input did not convert, \
+2 on a usage error, 3 on an I/O error."
+)]
+struct Cli {
+    #[command(subcommand)]
+    command: Option<Command>,
+    #[command(flatten)]
+    chords: Chords,
+    #[command(flatten)]
+    options: Options,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Convert to MML, or to another --format
+    Convert(Chords),
+    /// Print the notes of each chord (--format notes)
+    Notes(Chords),
+    /// Print the parsed events as JSON, one line per input (--format json)
+    Ast(Chords),
+    /// Print how each chord was read and voiced, with its Roman numeral
+    /// and function in the key
+    Analyze {
+        #[command(flatten)]
+        chords: Chords,
+        /// Key to analyze in until a `key=` directive (default: C major, or
+        /// the key estimated from the chords with --detect-key), e.g.
+        /// --key F, --key "A minor"
+        #[arg(long)]
+        key: Option<Key>,
+    },
+    /// Convert with every chord and key moved by SEMITONES
+    Transpose {
+        /// Semitones to move by (negative for down)
+        #[arg(allow_negative_numbers = true)]
+        semitones: i32,
+        #[command(flatten)]
+        chords: Chords,
+    },
+    /// Print the dialect reading each input converts as
+    Preprocess(Chords),
+    /// Render the chords to a WAV file with a built-in synthesizer
+    Render {
+        #[command(flatten)]
+        chords: Chords,
+        /// The WAV file to write
+        #[arg(long, value_name = "FILE")]
+        wav: PathBuf,
+        #[command(flatten)]
+        synth: Synth,
+    },
+    /// Play the chords on a raw MIDI device in real time (Linux ALSA
+    /// /dev/snd/midiC*D*, e.g. of snd-virmidi; not sequencer, CoreMIDI or
+    /// Windows ports)
+    Play {
+        #[command(flatten)]
+        chords: Chords,
+        /// Raw MIDI device to send to (default: the first ALSA
+        /// /dev/snd/midiC*D* device)
+        #[arg(long, value_name = "DEVICE")]
+        port: Option<PathBuf>,
+        /// Play again from the start until interrupted (Ctrl+C)
+        #[arg(long = "loop")]
+        repeat: bool,
+        /// List the raw MIDI devices and exit
+        #[arg(long)]
+        list_ports: bool,
+    },
+    /// Convert lines as they are typed, keeping the key and modes earlier
+    /// lines set; `:help` lists the meta-commands
+    Repl,
+    /// Convert files (or the lines of stdin) in parallel, writing each
+    /// file's output next to it, and list the inputs that failed
+    Batch(Batch),
+    /// Make random progressions, one per line (--format chords), e.g.
+    /// for ear training
+    Generate(Generate),
+}
+
+#[derive(Args)]
+struct Synth {
+    /// Waveform of every note (default: by the GM program of `@` events)
+    #[arg(long, value_enum)]
+    waveform: Option<WaveformArg>,
+    /// Envelope of every note: attack, decay and release in seconds and
+    /// the sustain level (0-1), e.g. --adsr 0.01 0.3 0.6 0.4
+    #[arg(
+        long,
+        num_args = 4,
+        value_names = ["ATTACK", "DECAY", "SUSTAIN", "RELEASE"],
+        allow_negative_numbers = true
+    )]
+    adsr: Option<Vec<f32>>,
+    /// Samples per second
+    #[arg(long, default_value_t = 44_100, value_parser = clap::value_parser!(u32).range(1..))]
+    sample_rate: u32,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum WaveformArg {
+    Sine,
+    Saw,
+    Square,
+}
+
+impl Synth {
+    fn synth_options(&self) -> Result<SynthOptions> {
+        let envelope = match self.adsr.as_deref() {
+            None => None,
+            Some(&[attack, decay, sustain, release]) => {
+                if [attack, decay, release].iter().any(|time| *time < 0.0)
+                    || !(0.0..=1.0).contains(&sustain)
+                {
+                    return Err(anyhow!(
+                        "--adsr takes times of 0 or more and a sustain level from 0 to 1"
+                    ));
+                }
+                Some(Envelope {
+                    attack,
+                    decay,
+                    sustain,
+                    release,
+                })
+            }
+            Some(_) => unreachable!("clap takes 4 values"),
+        };
+        Ok(SynthOptions {
+            sample_rate: self.sample_rate,
+            waveform: self.waveform.map(|waveform| match waveform {
+                WaveformArg::Sine => Waveform::Sine,
+                WaveformArg::Saw => Waveform::Saw,
+                WaveformArg::Square => Waveform::Square,
+            }),
+            envelope,
+        })
+    }
+}
+
+#[derive(Args)]
+struct Batch {
+    /// Files, directories (searched for *.chord files) or glob patterns;
+    /// none or "-" reads the entries from stdin
+    paths: Vec<String>,
+    /// Write each output under DIR instead of next to its input
+    #[arg(long, value_name = "DIR")]
+    out_dir: Option<PathBuf>,
+    /// Number of conversion threads (default: one per CPU)
+    #[arg(short, long, value_name = "N")]
+    jobs: Option<usize>,
+}
+
+#[derive(Args)]
+struct Generate {
+    /// Model the progressions follow
+    #[arg(long, value_enum, default_value = "functional")]
+    style: StyleArg,
+    /// Key, e.g. --key F, --key "A minor"
+    #[arg(long, default_value = "C")]
+    key: Key,
+    /// Chords in each progression
+    #[arg(long, default_value_t = 8, value_parser = clap::value_parser!(u32).range(1..))]
+    length: u32,
+    /// Chords between bar lines (0 for none)
+    #[arg(long, default_value_t = 1)]
+    per_bar: usize,
+    /// Number of progressions
+    #[arg(long, default_value_t = 1)]
+    count: u64,
+    /// Seed of the first progression, the next ones taking the seeds after
+    /// it (default: random, printed to stderr)
+    #[arg(long)]
+    seed: Option<u64>,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum StyleArg {
+    /// Diatonic triads moving T-S-D-T, ending V I
+    Functional,
+    /// Four-chord loops such as I V vi IV
+    Pop,
+    /// Seventh-chord turnarounds such as iii7 VI7 ii7 V7
+    Jazz,
+}
+
+impl Generate {
+    /// The text of each progression.
+    fn progressions(&self) -> Vec<String> {
+        let seed = self.seed.unwrap_or_else(|| {
+            let seed = SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map_or(0, |elapsed| elapsed.as_nanos() as u64);
+            eprintln!("Seed: {}", seed);
+            seed
+        });
+        (0..self.count)
+            .map(|i| {
+                generate(&GenerateOptions {
+                    style: match self.style {
+                        StyleArg::Functional => Style::Functional,
+                        StyleArg::Pop => Style::Pop,
+                        StyleArg::Jazz => Style::Jazz,
+                    },
+                    key: self.key,
+                    length: self.length as usize,
+                    chords_per_bar: self.per_bar,
+                    seed: seed.wrapping_add(i),
+                })
+                .text
+            })
+            .collect()
+    }
+}
+
+#[derive(Args, Default)]
+struct Chords {
+    /// Chord notation, e.g. "Dm G7 C"
+    chords: Vec<String>,
+}
+
+#[derive(Args)]
+struct Options {
+    /// Output format (default: per subcommand)
+    #[arg(short, long, global = true)]
+    format: Option<Format>,
+    /// Read inputs from FILE, one per line ("-" for stdin)
+    #[arg(short, long, value_name = "FILE", global = true)]
+    input: Option<PathBuf>,
+    /// Write output to FILE instead of stdout
+    #[arg(short, long, value_name = "FILE", global = true)]
+    output: Option<PathBuf>,
+    /// MML dialect of the output
+    #[arg(long, value_enum, default_value = "mmlabc", global = true)]
+    profile: ProfileArg,
+    /// How note names are spelled
+    #[arg(long, value_enum, default_value = "key", global = true)]
+    spelling: SpellingArg,
+    /// What degree numerals count from
+    #[arg(long, value_enum, default_value = "ionian", global = true)]
+    degrees: DegreesArg,
+    /// Quality of a degree written without one
+    #[arg(long, value_enum, default_value = "none", global = true)]
+    diatonic_quality: DiatonicQualityArg,
+    /// Without a key= directive, spell notes (and analyze) in the key
+    /// estimated from the chords instead of C major
+    #[arg(long, global = true)]
+    detect_key: bool,
+    /// Follow each chord with a run up its chord scale in the MML
+    #[arg(long, global = true)]
+    scale_runs: bool,
+    /// Reharmonize before converting, applying OPERATIONS in order (e.g.
+    /// --reharmonize two-five,tritone-substitution)
+    #[arg(
+        long,
+        value_enum,
+        value_delimiter = ',',
+        value_name = "OPERATIONS",
+        global = true
+    )]
+    reharmonize: Vec<ReharmonizationArg>,
+    /// Convert all the input lines as one chart into one output, each
+    /// line starting in the key, modes and octave shifts the lines before
+    /// it left (batch: each file)
+    #[arg(long, global = true)]
+    multi_line: bool,
+    /// Read all the input as one song chart (ChordPro "[Am]Hello" text or
+    /// chords over lyrics) and convert the chords in it (batch: each file)
+    #[arg(long, global = true, conflicts_with = "multi_line")]
+    chart: bool,
+    /// With --chart, make each line of chords one bar
+    #[arg(long, global = true, requires = "chart")]
+    line_bars: bool,
+    /// Longest input accepted, in characters (each line with
+    /// --multi-line); 0 for no limit
+    #[arg(long, value_name = "CHARS", default_value_t = 1000, global = true)]
+    max_input_len: usize,
+    /// Report inputs that fail and go on with the rest
+    #[arg(short, long, global = true)]
+    keep_going: bool,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum ProfileArg {
+    Mmlabc,
+    Tracks,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum SpellingArg {
+    Key,
+    Chord,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum DegreesArg {
+    Ionian,
+    Scale,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum ReharmonizationArg {
+    /// G7 C → Db7 C
+    TritoneSubstitution,
+    /// C Am → C Bm7(b5) E7 Am
+    TwoFive,
+    /// C Am Dm G → C A7 D7 G
+    SecondaryDominants,
+    /// C F → C Gb F
+    ApproachChords,
+    /// C Am → Cmaj7 Am7
+    Sevenths,
+    /// C G7 → Cmaj7(add9) G9
+    Ninths,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum DiatonicQualityArg {
+    None,
+    Triad,
+    Seventh,
+}
+
+/// How the input lines make up the inputs to convert.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Reading {
+    /// Each line is an input
+    Lines,
+    /// All the lines are one input, converted line by line (--multi-line)
+    MultiLine,
+    /// All the lines are one song chart (--chart)
+    Chart { line_bars: bool },
+}
+
+impl Reading {
+    /// Whether all the lines are one input.
+    fn is_whole(self) -> bool {
+        self != Reading::Lines
+    }
 
-        let stdin = io::stdin();
-        for line in stdin.lock().lines() {
-            let line = line?;
-            if !line.trim().is_empty() {
-                process_input(&line)?;
+    fn convert(self, converter: &mut Converter, input: &str) -> Result<Conversion> {
+        match self {
+            Reading::Lines => converter.convert_explained(input),
+            Reading::MultiLine => converter.convert_lines_explained(input),
+            Reading::Chart { line_bars } => converter.convert_chart_explained(input, line_bars),
+        }
+    }
+}
+
+impl Options {
+    fn reading(&self) -> Reading {
+        if self.chart {
+            Reading::Chart {
+                line_bars: self.line_bars,
             }
+        } else if self.multi_line {
+            Reading::MultiLine
+        } else {
+            Reading::Lines
         }
     }
 
+    fn convert_options(&self) -> ConvertOptions {
+        ConvertOptions {
+            spelling: match self.spelling {
+                SpellingArg::Key => Spelling::Key,
+                SpellingArg::Chord => Spelling::Chord,
+            },
+            degrees: match self.degrees {
+                DegreesArg::Ionian => Degrees::Ionian,
+                DegreesArg::Scale => Degrees::Scale,
+            },
+            diatonic_quality: match self.diatonic_quality {
+                DiatonicQualityArg::None => DiatonicQuality::None,
+                DiatonicQualityArg::Triad => DiatonicQuality::Triad,
+                DiatonicQualityArg::Seventh => DiatonicQuality::Seventh,
+            },
+            mml_profile: match self.profile {
+                ProfileArg::Mmlabc => MmlProfile::Mmlabc,
+                ProfileArg::Tracks => MmlProfile::Tracks,
+            },
+            max_input_len: (self.max_input_len > 0).then_some(self.max_input_len),
+            detect_key: self.detect_key,
+            scale_runs: self.scale_runs,
+            reharmonize: self
+                .reharmonize
+                .iter()
+                .map(|operation| match operation {
+                    ReharmonizationArg::TritoneSubstitution => Reharmonization::TritoneSubstitution,
+                    ReharmonizationArg::TwoFive => Reharmonization::TwoFive,
+                    ReharmonizationArg::SecondaryDominants => Reharmonization::SecondaryDominants,
+                    ReharmonizationArg::ApproachChords => Reharmonization::ApproachChords,
+                    ReharmonizationArg::Sevenths => Reharmonization::Sevenths,
+                    ReharmonizationArg::Ninths => Reharmonization::Ninths,
+                })
+                .collect(),
+            ..ConvertOptions::default()
+        }
+    }
+}
+
+/// Why the CLI stopped, mapped to the exit status.
+enum Failure {
+    /// An input did not convert (already reported)
+    Conversion,
+    Usage(anyhow::Error),
+    Io(anyhow::Error),
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    match run(cli) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(Failure::Conversion) => ExitCode::from(1),
+        Err(Failure::Usage(e)) => {
+            eprintln!("Error: {:#}", e);
+            ExitCode::from(2)
+        }
+        Err(Failure::Io(e)) => {
+            eprintln!("Error: {:#}", e);
+            ExitCode::from(3)
+        }
+    }
+}
+
+fn run(cli: Cli) -> Result<(), Failure> {
+    let mut options = cli.options.convert_options();
+    let mut output_path = cli.options.output.clone();
+    let mut port = None;
+    let mut generated = None;
+    let (chords, command) = match cli.command {
+        // Typing at a terminal starts the REPL
+        None if cli.chords.chords.is_empty()
+            && cli.options.input.is_none()
+            && io::stdin().is_terminal() =>
+        {
+            return repl::run(options);
+        }
+        None => (cli.chords, Kind::Convert(Format::Mml)),
+        Some(Command::Convert(chords)) => (chords, Kind::Convert(Format::Mml)),
+        Some(Command::Notes(chords)) => (chords, Kind::Convert(Format::Notes)),
+        Some(Command::Ast(chords)) => (chords, Kind::Convert(Format::Json)),
+        Some(Command::Analyze { chords, key }) => (chords, Kind::Analyze { key }),
+        Some(Command::Transpose { semitones, chords }) => {
+            options.transpose = semitones;
+            (chords, Kind::Convert(Format::Mml))
+        }
+        Some(Command::Preprocess(_)) if cli.options.reading().is_whole() => {
+            return Err(Failure::Usage(anyhow!(
+                "preprocess reads each line on its own, not --multi-line or --chart"
+            )));
+        }
+        Some(Command::Preprocess(chords)) => (chords, Kind::Preprocess),
+        Some(Command::Render { chords, wav, synth }) => {
+            if output_path.is_some() {
+                return Err(Failure::Usage(anyhow!("render writes --wav, not --output")));
+            }
+            output_path = Some(wav);
+            let synth = synth.synth_options().map_err(Failure::Usage)?;
+            (chords, Kind::Render(synth))
+        }
+        Some(Command::Play {
+            chords,
+            port: port_path,
+            repeat,
+            list_ports,
+        }) => {
+            if list_ports {
+                let ports = play::ports();
+                if ports.is_empty() {
+                    eprintln!("No raw MIDI devices (/dev/snd/midiC*D*) found");
+                }
+                for port in ports {
+                    println!("{}", port.display());
+                }
+                return Ok(());
+            }
+            if output_path.is_some() {
+                return Err(Failure::Usage(anyhow!(
+                    "play sends to --port, not --output"
+                )));
+            }
+            port = Some(port_path);
+            (chords, Kind::Play { repeat })
+        }
+        Some(Command::Repl) => return repl::run(options),
+        Some(Command::Batch(batch)) => {
+            if cli.options.input.is_some() {
+                return Err(Failure::Usage(anyhow!(
+                    "batch reads PATHS or stdin, not --input"
+                )));
+            }
+            let format = cli.options.format.unwrap_or(Format::Mml);
+            return batch::run(
+                batch,
+                format,
+                &options,
+                cli.options.reading(),
+                cli.options.output.as_ref(),
+            );
+        }
+        Some(Command::Generate(generate)) => {
+            if cli.options.input.is_some() {
+                return Err(Failure::Usage(anyhow!(
+                    "generate makes its own inputs, not --input"
+                )));
+            }
+            generated = Some(generate.progressions());
+            (Chords::default(), Kind::Convert(Format::Chords))
+        }
+    };
+    let output = Output::new(command, cli.options.format).map_err(Failure::Usage)?;
+    if output.is_binary() && output_path.is_none() && io::stdout().is_terminal() {
+        return Err(Failure::Usage(anyhow!(
+            "refusing to write binary output to a terminal; use --output"
+        )));
+    }
+
+    let inputs = match generated {
+        Some(progressions) => Box::new(
+            progressions
+                .into_iter()
+                .map(|progression| (None, Ok(progression))),
+        ),
+        None => read_inputs(chords, cli.options.input.as_ref())?,
+    };
+    let reading = cli.options.reading();
+    let inputs = if reading.is_whole() {
+        whole(inputs)
+    } else {
+        inputs
+    };
+    let mut converter = Converter::with_options(options.clone())
+        .map_err(|e| Failure::Io(e.context("Failed to initialize the parser")))?;
+    let destination = match &port {
+        Some(port) => play::open_port(port.as_ref()).map(|port| Box::new(port) as Box<dyn Write>),
+        None => open_output(output_path.as_ref()),
+    }
+    .map_err(Failure::Io)?;
+    let mut writer = Writer::new(output, &options, destination);
+    let mut failed = 0;
+    let mut total = 0;
+
+    for (line, input) in inputs {
+        let input = input.map_err(Failure::Io)?;
+        if input.trim().is_empty() {
+            continue;
+        }
+        total += 1;
+        let written = match output {
+            Output::Preprocess { .. } => writer.preprocess(&mut converter, &input),
+            _ => match reading.convert(&mut converter, &input) {
+                Ok(conversion) => writer.entry(line, &input, conversion),
+                Err(e) => {
+                    failed += 1;
+                    eprintln!("Error: {}", locate(line, &input, &e));
+                    if !cli.options.keep_going {
+                        break;
+                    }
+                    Ok(())
+                }
+            },
+        };
+        written.map_err(Failure::Io)?;
+    }
+    writer.finish().map_err(Failure::Io)?;
+
+    if failed > 0 {
+        if cli.options.keep_going && total > 1 {
+            eprintln!("{} of {} inputs failed to convert", failed, total);
+        }
+        return Err(Failure::Conversion);
+    }
     Ok(())
 }
 
-fn process_input(input: &str) -> Result<()> {
-    match convert(input) {
-        Ok(mml) => {
-            println!("{}", mml);
-            Ok(())
+/// The inputs with their line numbers (`None` for the CHORDS arguments).
+type Inputs = Box<dyn Iterator<Item = (Option<usize>, Result<String>)>>;
+
+/// All of `inputs` as one input, its lines in order (for --multi-line and
+/// --chart).
+fn whole(inputs: Inputs) -> Inputs {
+    let lines: Result<Vec<String>> = inputs.map(|(_, line)| line).collect();
+    Box::new(std::iter::once((None, lines.map(|lines| lines.join("\n")))))
+}
+
+fn read_inputs(chords: Chords, input: Option<&PathBuf>) -> Result<Inputs, Failure> {
+    match (chords.chords.is_empty(), input) {
+        (false, Some(_)) => Err(Failure::Usage(anyhow!(
+            "give either CHORDS or --input, not both"
+        ))),
+        (false, None) => Ok(Box::new(std::iter::once((
+            None,
+            Ok(chords.chords.join(" ")),
+        )))),
+        (true, Some(path)) if path.as_os_str() != "-" => {
+            let text = fs::read_to_string(path)
+                .with_context(|| format!("Failed to read {}", path.display()))
+                .map_err(Failure::Io)?;
+            let lines: Vec<String> = text.lines().map(str::to_string).collect();
+            Ok(Box::new(
+                lines
+                    .into_iter()
+                    .enumerate()
+                    .map(|(i, line)| (Some(i + 1), Ok(line))),
+            ))
         }
-        Err(e) => {
-            eprintln!("Error: {}", e);
-            std::process::exit(1);
+        (true, _) => {
+            let stdin = io::stdin();
+            if stdin.is_terminal() {
+                eprintln!("chord2mml - Convert chord notation to MML");
+                eprintln!("Enter chord notation (e.g., 'C' or 'C-F-G-C'):");
+                eprintln!("Press Ctrl+D (Unix) or Ctrl+Z (Windows) to exit\n");
+            }
+            Ok(Box::new(stdin.lines().enumerate().map(|(i, line)| {
+                (Some(i + 1), line.context("Failed to read stdin"))
+            })))
         }
     }
 }
+
+/// An error with its line and column in the input, as far as they are
+/// known; shown as `line L, column C: message`.
+struct Located {
+    line: Option<usize>,
+    /// In characters, from 1
+    column: Option<usize>,
+    message: String,
+}
+
+impl std::fmt::Display for Located {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match (self.line, self.column) {
+            (Some(line), Some(column)) => write!(f, "line {}, column {}: ", line, column)?,
+            (Some(line), None) => write!(f, "line {}: ", line)?,
+            (None, Some(column)) => write!(f, "column {}: ", column)?,
+            (None, None) => {}
+        }
+        f.write_str(&self.message)
+    }
+}
+
+/// Locate `error` in `input`, which starts on line `line` of its source
+/// (`None` when it has no lines). An error of one line of a multi-line
+/// input names the line; its column is in that line.
+fn locate(line: Option<usize>, input: &str, error: &anyhow::Error) -> Located {
+    let column = |text: &str, span: Span| {
+        text.get(..span.start)
+            .map(|before| before.chars().count() + 1)
+    };
+    if let Some(e) = error.downcast_ref::<LineError>() {
+        let text = input.lines().nth(e.line - 1).unwrap_or_default();
+        return Located {
+            line: Some(line.map_or(e.line, |first| first + e.line - 1)),
+            column: e.span.and_then(|span| column(text, span)),
+            message: e.message.clone(),
+        };
+    }
+    Located {
+        line,
+        column: error
+            .downcast_ref::<InputError>()
+            .and_then(|e| column(input, e.span)),
+        message: error.to_string(),
+    }
+}
+
+fn open_output(path: Option<&PathBuf>) -> Result<Box<dyn Write>> {
+    Ok(match path {
+        Some(path) => Box::new(OutputFile {
+            path: path.clone(),
+            file: None,
+        }),
+        None => Box::new(io::stdout()),
+    })
+}
+
+/// `--output` (or render's `--wav`), created at the first write: the
+/// inputs convert before the file is truncated, and a run that converts
+/// nothing leaves it as it was.
+struct OutputFile {
+    path: PathBuf,
+    file: Option<io::BufWriter<fs::File>>,
+}
+
+impl Write for OutputFile {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let file = match &mut self.file {
+            Some(file) => file,
+            None => {
+                let file = fs::File::create(&self.path).map_err(|e| {
+                    io::Error::new(
+                        e.kind(),
+                        format!("Failed to create {}: {}", self.path.display(), e),
+                    )
+                })?;
+                self.file.insert(io::BufWriter::new(file))
+            }
+        };
+        file.write(buf)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        match &mut self.file {
+            Some(file) => file.flush(),
+            None => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_commands() {
+        let cli = Cli::try_parse_from(["chord2mml", "Dm", "G7"]).unwrap();
+        assert!(cli.command.is_none());
+        assert_eq!(cli.chords.chords, ["Dm", "G7"]);
+
+        let cli = Cli::try_parse_from(["chord2mml", "transpose", "-3", "C", "-k"]).unwrap();
+        match cli.command {
+            Some(Command::Transpose { semitones, chords }) => {
+                assert_eq!(semitones, -3);
+                assert_eq!(chords.chords, ["C"]);
+            }
+            _ => panic!("expected transpose"),
+        }
+        assert!(cli.options.keep_going);
+
+        // Options go before or after the subcommand
+        for args in [
+            ["chord2mml", "--spelling", "chord", "notes", "Fm Db7"],
+            ["chord2mml", "notes", "--spelling", "chord", "Fm Db7"],
+        ] {
+            let cli = Cli::try_parse_from(args).unwrap();
+            assert!(matches!(cli.command, Some(Command::Notes(_))));
+            assert!(matches!(cli.options.spelling, SpellingArg::Chord));
+        }
+    }
+
+    #[test]
+    fn test_output_created_at_first_write() {
+        let path = std::env::temp_dir().join(format!("chord2mml-output-{}", std::process::id()));
+        fs::write(&path, "kept").unwrap();
+        let mut output = open_output(Some(&path)).unwrap();
+        output.flush().unwrap();
+        drop(output);
+        assert_eq!(fs::read_to_string(&path).unwrap(), "kept");
+
+        let mut output = open_output(Some(&path)).unwrap();
+        writeln!(output, "v11'c1eg'").unwrap();
+        output.flush().unwrap();
+        assert_eq!(fs::read_to_string(&path).unwrap(), "v11'c1eg'\n");
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_render_options() {
+        let cli = Cli::try_parse_from([
+            "chord2mml",
+            "render",
+            "C",
+            "--wav",
+            "c.wav",
+            "--adsr",
+            "0",
+            "0.1",
+            "0.5",
+            "0.2",
+        ])
+        .unwrap();
+        let Some(Command::Render { wav, synth, .. }) = cli.command else {
+            panic!("expected render");
+        };
+        assert_eq!(wav, PathBuf::from("c.wav"));
+        let synth = synth.synth_options().unwrap();
+        assert_eq!(synth.waveform, None);
+        assert_eq!(synth.envelope.map(|envelope| envelope.sustain), Some(0.5));
+
+        let cli = Cli::try_parse_from([
+            "chord2mml",
+            "render",
+            "C",
+            "--wav",
+            "c.wav",
+            "--adsr",
+            "0",
+            "0",
+            "-1",
+            "0",
+        ])
+        .unwrap();
+        let Some(Command::Render { synth, .. }) = cli.command else {
+            panic!("expected render");
+        };
+        assert!(synth.synth_options().is_err());
+    }
+
+    #[test]
+    fn test_generate() {
+        let cli = Cli::try_parse_from([
+            "chord2mml",
+            "generate",
+            "--style",
+            "jazz",
+            "--key",
+            "F",
+            "--seed",
+            "3",
+            "--count",
+            "2",
+        ])
+        .unwrap();
+        let Some(Command::Generate(generate)) = cli.command else {
+            panic!("expected generate");
+        };
+        let progressions = generate.progressions();
+        assert_eq!(progressions.len(), 2);
+        assert!(progressions.iter().all(|text| text.starts_with("key=F ")));
+        assert_eq!(generate.progressions(), progressions);
+
+        assert!(Cli::try_parse_from(["chord2mml", "generate", "--length", "0"]).is_err());
+    }
+
+    #[test]
+    fn test_analyze_key() {
+        // C major unless --detect-key; the estimate is reported either way
+        for (detect_key, key_line) in [(false, "Key: C major"), (true, "Key: A minor")] {
+            let options = ConvertOptions {
+                detect_key,
+                ..ConvertOptions::default()
+            };
+            let output = Output::new(Kind::Analyze { key: None }, None).unwrap();
+            let mut text = Vec::new();
+            let mut writer = Writer::new(output, &options, Box::new(&mut text));
+            let conversion = Converter::with_options(options.clone())
+                .unwrap()
+                .convert_explained("Am Dm E7 Am")
+                .unwrap();
+            writer.entry(None, "Am Dm E7 Am", conversion).unwrap();
+            writer.finish().unwrap();
+            let text = String::from_utf8(text).unwrap();
+            let lines: Vec<&str> = text.lines().collect();
+            assert_eq!(lines[1..3], ["Likely key: A minor (0.90)", key_line]);
+        }
+    }
+
+    #[test]
+    fn test_multi_line_options() {
+        let cli = Cli::try_parse_from(["chord2mml", "C"]).unwrap();
+        assert_eq!(cli.options.convert_options().max_input_len, Some(1000));
+        let cli = Cli::try_parse_from(["chord2mml", "--multi-line", "--max-input-len", "0", "C"])
+            .unwrap();
+        assert!(cli.options.multi_line);
+        assert_eq!(cli.options.convert_options().max_input_len, None);
+        assert_eq!(cli.options.reading(), Reading::MultiLine);
+        let cli =
+            Cli::try_parse_from(["chord2mml", "--chart", "--line-bars", "-i", "song.txt"]).unwrap();
+        assert_eq!(cli.options.reading(), Reading::Chart { line_bars: true });
+        assert!(Cli::try_parse_from(["chord2mml", "--line-bars", "C"]).is_err());
+        assert!(Cli::try_parse_from(["chord2mml", "--chart", "--multi-line", "C"]).is_err());
+
+        let lines = ["key=D", "", "I IV"].map(|line| (None, Ok(line.to_string())));
+        let mut inputs = whole(Box::new(lines.into_iter()));
+        let (line, text) = inputs.next().unwrap();
+        assert_eq!((line, text.unwrap().as_str()), (None, "key=D\n\nI IV"));
+        assert!(inputs.next().is_none());
+    }
+
+    #[test]
+    fn test_location() {
+        let error = Converter::new().unwrap().convert("C・F $").unwrap_err();
+        let message = error.to_string();
+        assert_eq!(
+            locate(Some(2), "C・F $", &error).to_string(),
+            format!("line 2, column 5: {message}")
+        );
+        assert_eq!(
+            locate(None, "C・F $", &error).to_string(),
+            format!("column 5: {message}")
+        );
+        assert_eq!(
+            locate(Some(2), "", &anyhow!("no span")).to_string(),
+            "line 2: no span"
+        );
+
+        // A line of a multi-line input
+        let chart = "C\n\n  C・F $";
+        let error = Converter::new().unwrap().convert_lines(chart).unwrap_err();
+        let located = locate(None, chart, &error);
+        assert_eq!((located.line, located.column), (Some(3), Some(7)));
+        assert_eq!(located.to_string(), format!("line 3, column 7: {message}"));
+        assert_eq!(locate(Some(10), chart, &error).line, Some(12));
+    }
+}
@@ -0,0 +1,399 @@
+//! Output formats of the CLI.
+
+use anyhow::{anyhow, Context, Result};
+use chord2mml_core::{
+    analyze, chord_scales, chord_symbol, chord_text, detect_key, midi_schedule,
+    preprocess_rewrites, spell_notes, to_midi, to_musicxml, to_wav, ChordScales, Conversion,
+    ConvertOptions, Converter, Event, Function, Key, KeyEstimate, OutEvent, Rewrite, Spelling,
+    SynthOptions, Warning,
+};
+use clap::ValueEnum;
+use serde::Serialize;
+use std::io::Write;
+
+/// The values of `--format`.
+#[derive(Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Format {
+    /// MML, one line per input
+    Mml,
+    /// The parsed events (AST) as JSON, one line per input
+    Json,
+    /// Each chord's notes, one line per chord
+    Notes,
+    /// The chords as notation, one line per input (e.g. as --reharmonize
+    /// left them)
+    Chords,
+    /// A Standard MIDI File of all inputs in sequence
+    Midi,
+    /// A MusicXML score of all inputs in sequence
+    Musicxml,
+}
+
+impl Format {
+    /// File extension of an output written next to its input.
+    pub fn extension(self) -> &'static str {
+        match self {
+            Format::Mml => "mml",
+            Format::Json => "json",
+            Format::Notes | Format::Chords => "txt",
+            Format::Midi => "mid",
+            Format::Musicxml => "musicxml",
+        }
+    }
+}
+
+/// What a subcommand prints.
+pub enum Kind {
+    /// A conversion, in a format
+    Convert(Format),
+    Analyze {
+        key: Option<Key>,
+    },
+    Preprocess,
+    /// Audio from the built-in synthesizer
+    Render(SynthOptions),
+    /// MIDI sent to a port in real time
+    Play {
+        repeat: bool,
+    },
+}
+
+/// A subcommand's output with `--format` applied.
+#[derive(Clone, Copy, PartialEq)]
+pub enum Output {
+    Mml,
+    Ast,
+    Notes,
+    Chords,
+    Midi,
+    MusicXml,
+    Wav(SynthOptions),
+    Play { repeat: bool },
+    Analysis { json: bool, key: Option<Key> },
+    Preprocess { json: bool },
+}
+
+impl Output {
+    pub fn new(kind: Kind, format: Option<Format>) -> Result<Self> {
+        Ok(match (kind, format) {
+            (Kind::Convert(default), format) => match format.unwrap_or(default) {
+                Format::Mml => Output::Mml,
+                Format::Json => Output::Ast,
+                Format::Notes => Output::Notes,
+                Format::Chords => Output::Chords,
+                Format::Midi => Output::Midi,
+                Format::Musicxml => Output::MusicXml,
+            },
+            (Kind::Analyze { key }, None) => Output::Analysis { json: false, key },
+            (Kind::Analyze { key }, Some(Format::Json)) => Output::Analysis { json: true, key },
+            (Kind::Preprocess, None) => Output::Preprocess { json: false },
+            (Kind::Preprocess, Some(Format::Json)) => Output::Preprocess { json: true },
+            (Kind::Analyze { .. } | Kind::Preprocess, Some(_)) => {
+                return Err(anyhow!("this subcommand prints text or --format json"))
+            }
+            (Kind::Render(synth), None) => Output::Wav(synth),
+            (Kind::Render(_), Some(_)) => {
+                return Err(anyhow!("render writes WAV; --format does not apply"))
+            }
+            (Kind::Play { repeat }, None) => Output::Play { repeat },
+            (Kind::Play { .. }, Some(_)) => {
+                return Err(anyhow!("play sends MIDI; --format does not apply"))
+            }
+        })
+    }
+
+    /// Whether the output is one file for all inputs.
+    pub fn is_binary(self) -> bool {
+        matches!(self, Output::Midi | Output::MusicXml | Output::Wav(_))
+    }
+}
+
+/// Writes each input's output as it converts; MIDI, MusicXML, WAV and play collect
+/// the inputs and write them as one piece at the end.
+pub struct Writer<'a> {
+    output: Output,
+    spelling: Spelling,
+    /// Whether chords are named by their events rather than as written
+    reharmonized: bool,
+    /// Whether analysis is in the estimated key when the input sets none
+    detect_key: bool,
+    destination: Box<dyn Write + 'a>,
+    entries: usize,
+    out_events: Vec<OutEvent>,
+    /// Where the inputs come from, named in messages (batch mode)
+    origin: Option<String>,
+}
+
+impl<'a> Writer<'a> {
+    pub fn new(output: Output, options: &ConvertOptions, destination: Box<dyn Write + 'a>) -> Self {
+        Writer {
+            output,
+            spelling: options.spelling,
+            reharmonized: !options.reharmonize.is_empty(),
+            detect_key: options.detect_key,
+            destination,
+            entries: 0,
+            out_events: Vec::new(),
+            origin: None,
+        }
+    }
+
+    /// Prefix messages about an input with `origin` and its line.
+    pub fn with_origin(mut self, origin: String) -> Self {
+        self.origin = Some(origin);
+        self
+    }
+
+    /// Write the output of one converted input (from `line`, if known).
+    pub fn entry(
+        &mut self,
+        line: Option<usize>,
+        input: &str,
+        conversion: Conversion,
+    ) -> Result<()> {
+        self.entries += 1;
+        let at = match (&self.origin, line) {
+            (Some(origin), Some(line)) => format!("{}:{}: ", origin, line),
+            (Some(origin), None) => format!("{}: ", origin),
+            (None, _) => String::new(),
+        };
+        // Say how the input was read when a dialect rewrite applied
+        let rules = &conversion.rewrite.rules;
+        if !rules.is_empty() {
+            eprintln!(
+                "{}Read as: {} ({})",
+                at,
+                conversion.rewrite.text,
+                rules.join(", ")
+            );
+        }
+        for warning in &conversion.warnings {
+            eprintln!("{}Warning: {}", at, warning.message);
+        }
+
+        let out = &mut self.destination;
+        match self.output {
+            Output::Mml => writeln!(out, "{}", conversion.mml)?,
+            Output::Ast => writeln!(out, "{}", serde_json::to_string(&conversion.events)?)?,
+            Output::Chords => writeln!(out, "{}", chord_text(&conversion.events))?,
+            Output::Notes => {
+                if self.entries > 1 {
+                    writeln!(out)?;
+                }
+                for chord in chords(&conversion, self.spelling, self.reharmonized) {
+                    let numbers: Vec<String> = chord.notes.iter().map(|n| n.to_string()).collect();
+                    let names = chord.names.join(" ");
+                    writeln!(out, "{}\t{}\t{}", chord.chord, names, numbers.join(" "))?;
+                }
+            }
+            Output::Analysis { json: false, key } => {
+                if self.entries > 1 {
+                    writeln!(out)?;
+                }
+                writeln!(out, "Input: {}", input.trim())?;
+                if !rules.is_empty() {
+                    writeln!(
+                        out,
+                        "Read as: {} ({})",
+                        conversion.rewrite.text,
+                        rules.join(", ")
+                    )?;
+                }
+                let chords = chords(&conversion, self.spelling, self.reharmonized);
+                let (likely_key, harmony, scales) = harmony(&conversion, key, self.detect_key);
+                if let Some(estimate) = likely_key {
+                    writeln!(
+                        out,
+                        "Likely key: {} ({:.2})",
+                        estimate.key, estimate.correlation
+                    )?;
+                }
+                let mut current_key = None;
+                for ((chord, analysis), scales) in chords.iter().zip(&harmony.chords).zip(&scales) {
+                    if current_key != Some(analysis.key) {
+                        writeln!(out, "Key: {}", analysis.key)?;
+                        current_key = Some(analysis.key);
+                    }
+                    writeln!(
+                        out,
+                        "{}\t1/{}\t{}\t{}\t{}\t{}",
+                        chord.chord,
+                        chord.length,
+                        analysis.numeral,
+                        analysis.function.map_or("-", Function::symbol),
+                        scales.scales.first().map_or("-", |scale| scale.name),
+                        chord.names.join(" ")
+                    )?;
+                }
+                for two_five in &harmony.two_fives {
+                    let written: Vec<&str> =
+                        [Some(two_five.two), Some(two_five.five), two_five.resolution]
+                            .into_iter()
+                            .flatten()
+                            .filter_map(|i| chords.get(i))
+                            .map(|chord| chord.chord.as_str())
+                            .collect();
+                    writeln!(out, "ii-V to {}: {}", two_five.target, written.join(" "))?;
+                }
+            }
+            Output::Analysis { json: true, key } => {
+                let (likely_key, harmony, scales) = harmony(&conversion, key, self.detect_key);
+                let analysis = Analysis {
+                    input: input.trim(),
+                    reading: &conversion.rewrite,
+                    chords: chords(&conversion, self.spelling, self.reharmonized),
+                    likely_key,
+                    harmony,
+                    scales,
+                    warnings: &conversion.warnings,
+                };
+                writeln!(out, "{}", serde_json::to_string(&analysis)?)?;
+            }
+            Output::Midi | Output::MusicXml | Output::Wav(_) | Output::Play { .. } => {
+                self.out_events.extend(conversion.out_events)
+            }
+            Output::Preprocess { .. } => unreachable!("preprocess does not convert"),
+        }
+        Ok(())
+    }
+
+    /// Write the dialect reading of one input.
+    pub fn preprocess(&mut self, converter: &mut Converter, input: &str) -> Result<()> {
+        match self.output {
+            Output::Preprocess { json: true } => writeln!(
+                self.destination,
+                "{}",
+                serde_json::to_string(&preprocess_rewrites(input.trim()))?
+            )?,
+            _ => writeln!(
+                self.destination,
+                "{}",
+                converter.preprocess_chord(input.trim())
+            )?,
+        }
+        Ok(())
+    }
+
+    /// Write what was collected and flush.
+    pub fn finish(mut self) -> Result<()> {
+        if self.entries > 0 {
+            match self.output {
+                Output::Midi => self.destination.write_all(&to_midi(&self.out_events))?,
+                Output::MusicXml => self
+                    .destination
+                    .write_all(to_musicxml(&self.out_events, self.spelling).as_bytes())?,
+                Output::Wav(synth) => self
+                    .destination
+                    .write_all(&to_wav(&self.out_events, &synth))?,
+                Output::Play { repeat } => crate::play::play_realtime(
+                    &midi_schedule(&self.out_events),
+                    &mut self.destination,
+                    repeat,
+                )?,
+                _ => {}
+            }
+        }
+        self.destination
+            .flush()
+            .context("Failed to write the output")
+    }
+}
+
+/// One chord of a conversion, as `notes` and `analyze` print it.
+#[derive(Serialize)]
+struct Chord {
+    /// The chord as written (in the reading that converted)
+    chord: String,
+    /// MIDI note numbers
+    notes: Vec<i32>,
+    names: Vec<String>,
+    /// Note length (1 = whole note, 4 = quarter note)
+    length: u32,
+}
+
+#[derive(Serialize)]
+struct Analysis<'a> {
+    input: &'a str,
+    /// The dialect reading that converted
+    reading: &'a Rewrite,
+    chords: Vec<Chord>,
+    /// The key estimated from the notes, when neither the input nor --key
+    /// sets one
+    #[serde(skip_serializing_if = "Option::is_none")]
+    likely_key: Option<KeyEstimate>,
+    /// Each chord's Roman numeral and function, and the ii-Vs
+    harmony: chord2mml_core::Analysis,
+    /// Each chord's chord scales, best first
+    scales: Vec<ChordScales>,
+    warnings: &'a [Warning],
+}
+
+/// The harmonic analysis and chord scales of a conversion in `key`, or
+/// else in C major (in the key estimated from its notes with
+/// `in_likely_key`) until the input sets one, with that estimate when the
+/// input sets none.
+fn harmony(
+    conversion: &Conversion,
+    key: Option<Key>,
+    in_likely_key: bool,
+) -> (
+    Option<KeyEstimate>,
+    chord2mml_core::Analysis,
+    Vec<ChordScales>,
+) {
+    let keyless = !conversion
+        .events
+        .iter()
+        .any(|event| matches!(event, Event::Key { .. }));
+    let likely_key = match key {
+        None if keyless => detect_key(&conversion.out_events),
+        _ => None,
+    };
+    let key = match likely_key {
+        Some(estimate) if in_likely_key => Some(estimate.key),
+        _ => key,
+    };
+    (
+        likely_key,
+        analyze(&conversion.events, key),
+        chord_scales(&conversion.events, key),
+    )
+}
+
+/// The chords of a conversion; `reharmonized` names them by their events,
+/// as the chords written at their spans may have been replaced.
+fn chords(conversion: &Conversion, spelling: Spelling, reharmonized: bool) -> Vec<Chord> {
+    let lengths = conversion
+        .out_events
+        .iter()
+        .filter_map(|event| match event {
+            OutEvent::Notes(notes_event) if !notes_event.notes.is_empty() => {
+                Some(notes_event.note_length.unwrap_or(1))
+            }
+            _ => None,
+        });
+    let names = spell_notes(&conversion.out_events, spelling);
+    let mut symbols = conversion.events.iter().filter_map(chord_symbol);
+    conversion
+        .chords
+        .iter()
+        .zip(names)
+        .zip(lengths)
+        .map(|((chord, names), length)| {
+            let written = chord
+                .span
+                .and_then(|span| conversion.rewrite.text.get(span.start..span.end))
+                .map(|text| text.trim().to_string());
+            let symbol = symbols.next();
+            Chord {
+                chord: match (reharmonized, written) {
+                    (false, Some(written)) => written,
+                    (_, written) => symbol.or(written).unwrap_or_else(|| "?".to_string()),
+                },
+                notes: chord.notes.clone(),
+                names: names.iter().map(|name| name.to_string()).collect(),
+                length,
+            }
+        })
+        .collect()
+}
@@ -0,0 +1,238 @@
+//! `chord2mml play`: send the realized notes to a MIDI output port in
+//! real time.
+//!
+//! A port is a raw MIDI device: on Linux an ALSA `/dev/snd/midiC*D*`,
+//! including the virtual ones of the `snd-virmidi` module, which appear as
+//! sequencer ports that a software synth can be connected to (`aconnect`).
+//! Messages are written to it as they fall due, at the tempo and with the
+//! program changes of [`midi_schedule`](chord2mml_core::midi_schedule).
+//!
+//! Only raw devices are supported: ALSA sequencer ports of their own (a
+//! synth's input, most USB interfaces' only port), CoreMIDI and Windows
+//! MIDI ports are not, so that the CLI builds without a system MIDI
+//! library. Without a device, playing fails before converting and points
+//! to `snd-virmidi` or to writing a file with `--format midi`.
+
+use anyhow::{anyhow, Context, Result};
+use chord2mml_core::ScheduledMessage;
+use std::fs::{File, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// Where raw MIDI devices are.
+const PORTS: &str = "/dev/snd/midiC*D*";
+/// Longest sleep between checks for an interrupt.
+const TICK: Duration = Duration::from_millis(20);
+/// All Notes Off (control change 123) on channel 1.
+const ALL_NOTES_OFF: [u8; 3] = [0xB0, 123, 0];
+
+/// The raw MIDI devices, in name order.
+pub fn ports() -> Vec<PathBuf> {
+    glob::glob(PORTS)
+        .map(|paths| paths.filter_map(Result::ok).collect())
+        .unwrap_or_default()
+}
+
+/// Open `port` (default: the first of [`ports`]) for writing.
+pub fn open_port(port: Option<&PathBuf>) -> Result<File> {
+    let port = match port {
+        Some(port) => port.clone(),
+        None => first_port(ports())?,
+    };
+    OpenOptions::new()
+        .write(true)
+        .open(&port)
+        .with_context(|| format!("Failed to open MIDI port {}", port.display()))
+}
+
+/// The first of `ports`, or why there is none to play on.
+fn first_port(ports: Vec<PathBuf>) -> Result<PathBuf> {
+    ports.into_iter().next().ok_or_else(|| {
+        let directory = Path::new(PORTS).parent().unwrap_or(Path::new("/"));
+        let help = if cfg!(target_os = "linux") {
+            "load snd-virmidi for virtual ports, or give --port"
+        } else {
+            "play only supports the raw MIDI devices of Linux (ALSA), or a device given with --port"
+        };
+        anyhow!(
+            "no raw MIDI device found in {}; {}. To listen without one, write a file with --format midi",
+            directory.display(),
+            help
+        )
+    })
+}
+
+/// Play `schedule` on `sink` in real time, once or (with `repeat`) until
+/// interrupted; an interrupt (Ctrl+C) silences the notes and stops.
+pub fn play_realtime(
+    schedule: &[ScheduledMessage],
+    sink: &mut dyn Write,
+    repeat: bool,
+) -> Result<()> {
+    let stop = Arc::new(AtomicBool::new(false));
+    #[cfg(unix)]
+    for signal in [signal_hook::consts::SIGINT, signal_hook::consts::SIGTERM] {
+        signal_hook::flag::register(signal, Arc::clone(&stop))
+            .context("Failed to handle interrupts")?;
+    }
+    let start = Instant::now();
+    play(schedule, sink, repeat, &mut |at| loop {
+        if stop.load(Ordering::Relaxed) {
+            return false;
+        }
+        let now = start.elapsed();
+        if now >= at {
+            return true;
+        }
+        thread::sleep((at - now).min(TICK));
+    })
+}
+
+/// Play `schedule` on `sink`: `wait_until` returns once its time (from the
+/// start) has come, or `false` to stop, which sends All Notes Off. With
+/// `repeat`, each pass starts where the last ended.
+pub fn play(
+    schedule: &[ScheduledMessage],
+    sink: &mut dyn Write,
+    repeat: bool,
+    wait_until: &mut dyn FnMut(Duration) -> bool,
+) -> Result<()> {
+    let length = schedule.last().map(|last| last.at).unwrap_or_default();
+    let mut offset = Duration::ZERO;
+    loop {
+        for scheduled in schedule {
+            if !wait_until(offset + scheduled.at) {
+                return send(sink, &ALL_NOTES_OFF);
+            }
+            send(sink, &scheduled.message)?;
+        }
+        if !repeat || length.is_zero() {
+            return Ok(());
+        }
+        offset += length;
+    }
+}
+
+fn send(sink: &mut dyn Write, message: &[u8]) -> Result<()> {
+    sink.write_all(message)
+        .and_then(|()| sink.flush())
+        .context("Failed to send to the MIDI port")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chord2mml_core::{midi_schedule, Converter};
+    use std::cell::Cell;
+    use std::io;
+    use std::rc::Rc;
+
+    /// Records each message with the (mock) time it was sent at.
+    struct MockPort {
+        now: Rc<Cell<Duration>>,
+        sent: Vec<(Duration, Vec<u8>)>,
+    }
+
+    impl Write for MockPort {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.sent.push((self.now.get(), buf.to_vec()));
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    fn schedule(input: &str) -> Vec<ScheduledMessage> {
+        let conversion = Converter::new().unwrap().convert_explained(input).unwrap();
+        midi_schedule(&conversion.out_events)
+    }
+
+    #[test]
+    fn test_play_once() {
+        let now = Rc::new(Cell::new(Duration::ZERO));
+        let mut port = MockPort {
+            now: Rc::clone(&now),
+            sent: Vec::new(),
+        };
+        play(
+            &schedule("BPM60 organ1 C | F"),
+            &mut port,
+            false,
+            &mut |at| {
+                now.set(at);
+                true
+            },
+        )
+        .unwrap();
+        let secs = Duration::from_secs;
+        let sent: Vec<_> = port
+            .sent
+            .iter()
+            .map(|(at, message)| (*at, message[0], message.get(1).copied()))
+            .collect();
+        assert_eq!(
+            sent,
+            [
+                (secs(0), 0xC0, Some(16)),
+                (secs(0), 0x90, Some(60)),
+                (secs(0), 0x90, Some(64)),
+                (secs(0), 0x90, Some(67)),
+                (secs(4), 0x80, Some(60)),
+                (secs(4), 0x80, Some(64)),
+                (secs(4), 0x80, Some(67)),
+                (secs(4), 0x90, Some(65)),
+                (secs(4), 0x90, Some(69)),
+                (secs(4), 0x90, Some(72)),
+                (secs(8), 0x80, Some(65)),
+                (secs(8), 0x80, Some(69)),
+                (secs(8), 0x80, Some(72)),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_no_port() {
+        let error = first_port(Vec::new()).unwrap_err().to_string();
+        assert!(
+            error.starts_with("no raw MIDI device found in /dev/snd;"),
+            "{error}"
+        );
+        assert!(error.ends_with("--format midi"), "{error}");
+        let port = PathBuf::from("/dev/snd/midiC1D0");
+        assert_eq!(first_port(vec![port.clone()]).unwrap(), port);
+
+        let missing = PathBuf::from("/nonexistent/midiC0D0");
+        let error = open_port(Some(&missing)).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Failed to open MIDI port /nonexistent/midiC0D0"
+        );
+    }
+
+    #[test]
+    fn test_loop_until_stopped() {
+        let schedule = schedule("C");
+        let mut port = MockPort {
+            now: Rc::new(Cell::new(Duration::ZERO)),
+            sent: Vec::new(),
+        };
+        let mut waits = Vec::new();
+        play(&schedule, &mut port, true, &mut |at| {
+            waits.push(at);
+            waits.len() <= 2 * schedule.len()
+        })
+        .unwrap();
+        // Each pass starts a whole note (2 s) after the last; the third is
+        // stopped as it starts
+        assert_eq!(waits[schedule.len()], Duration::from_secs(2));
+        assert_eq!(*waits.last().unwrap(), Duration::from_secs(4));
+        assert_eq!(port.sent.len(), 2 * schedule.len() + 1);
+        assert_eq!(port.sent.last().unwrap().1, ALL_NOTES_OFF);
+    }
+}
@@ -0,0 +1,292 @@
+//! `chord2mml repl`: convert lines as they are typed.
+//!
+//! Each line converts in the state the lines before it left (key, scale,
+//! slash-chord, inversion, open-harmony and bass modes, octave shifts; see
+//! [`LineState`]), a line that does not convert is reported and changes
+//! nothing, and lines starting with `:` are meta-commands.
+
+use anyhow::{anyhow, Context, Result};
+use chord2mml_core::{Conversion, ConvertOptions, Converter, LineState};
+use std::io::{self, BufRead, IsTerminal, Write};
+
+use crate::output::{Output, Writer};
+use crate::{locate, Failure};
+
+const HELP: &str = "\
+Chords convert in the state earlier lines set (key, scale, modes, octave).
+  :notes [CHORDS]    each chord's notes (of CHORDS, or of the last line)
+  :ast [CHORDS]      the parsed events as JSON (of CHORDS, or of the last line)
+  :transpose [N]     move everything by N semitones (0 to stop; no N shows it)
+  :key KEY           change key, e.g. :key F or :key Em
+  :state             the directives in effect
+  :undo              undo the last line or command that changed the state
+  :reset             start again from the key of C with no modes
+  :help              this help
+  :quit              exit (or Ctrl+D)";
+
+/// What an undo restores.
+struct Snapshot {
+    /// What was undone, for the message
+    what: String,
+    state: LineState,
+    transpose: i32,
+    last: Option<(String, Conversion)>,
+}
+
+pub struct Repl {
+    options: ConvertOptions,
+    converter: Converter,
+    state: LineState,
+    /// The last line converted, for `:notes` and `:ast`
+    last: Option<(String, Conversion)>,
+    history: Vec<Snapshot>,
+}
+
+impl Repl {
+    pub fn new(options: ConvertOptions) -> Result<Self> {
+        Ok(Repl {
+            converter: Converter::with_options(options.clone())?,
+            options,
+            state: LineState::default(),
+            last: None,
+            history: Vec::new(),
+        })
+    }
+
+    /// Handle one line; `false` when it asks to quit.
+    pub fn handle(&mut self, line: &str, out: &mut dyn Write, err: &mut dyn Write) -> Result<bool> {
+        let line = line.trim();
+        if line.is_empty() {
+            return Ok(true);
+        }
+        let Some(command) = line.strip_prefix(':') else {
+            self.convert(line, out, err)?;
+            return Ok(true);
+        };
+        let (name, argument) = match command.split_once(char::is_whitespace) {
+            Some((name, argument)) => (name, argument.trim()),
+            None => (command, ""),
+        };
+        match name {
+            "notes" | "ast" => {
+                let output = if name == "notes" {
+                    Output::Notes
+                } else {
+                    Output::Ast
+                };
+                if argument.is_empty() {
+                    match &self.last {
+                        Some((input, conversion)) => {
+                            self.print(output, input, conversion.clone(), out)?
+                        }
+                        None => writeln!(err, "Nothing converted yet")?,
+                    }
+                } else {
+                    // In the current state, without changing it
+                    let mut state = self.state.clone();
+                    match self.converter.convert_line(argument, &mut state) {
+                        Ok(conversion) => self.print(output, argument, conversion, out)?,
+                        Err(e) => report(argument, &e, err)?,
+                    }
+                }
+            }
+            "transpose" => {
+                if argument.is_empty() {
+                    writeln!(out, "Transposing by {}", self.options.transpose)?;
+                } else {
+                    match argument.parse::<i32>() {
+                        Ok(semitones) => {
+                            self.save(format!(":transpose {}", semitones));
+                            self.set_transpose(semitones)?;
+                            writeln!(out, "Transposing by {}", semitones)?;
+                        }
+                        Err(_) => writeln!(err, "Error: :transpose takes a number of semitones")?,
+                    }
+                }
+            }
+            "key" if !argument.is_empty() => {
+                let directive = format!("key={}", argument);
+                let before = self.snapshot(format!(":key {}", argument));
+                match self.converter.convert_line(&directive, &mut self.state) {
+                    Ok(_) => self.history.push(before),
+                    Err(_) => writeln!(err, "Error: not a key: {}", argument)?,
+                }
+            }
+            "state" => {
+                writeln!(out, "{}", serde_json::to_string(self.state.directives())?)?;
+                if self.options.transpose != 0 {
+                    writeln!(out, "Transposing by {}", self.options.transpose)?;
+                }
+            }
+            "undo" => match self.history.pop() {
+                Some(snapshot) => {
+                    self.state = snapshot.state;
+                    self.last = snapshot.last;
+                    self.set_transpose(snapshot.transpose)?;
+                    writeln!(out, "Undid {}", snapshot.what)?;
+                }
+                None => writeln!(err, "Nothing to undo")?,
+            },
+            "reset" => {
+                self.save(":reset".to_string());
+                self.state = LineState::default();
+                self.last = None;
+            }
+            "help" => writeln!(out, "{}", HELP)?,
+            "quit" | "q" | "exit" => return Ok(false),
+            _ => writeln!(err, "Unknown command :{} (:help lists them)", name)?,
+        }
+        Ok(true)
+    }
+
+    /// Convert a line in the current state and print its MML.
+    fn convert(&mut self, line: &str, out: &mut dyn Write, err: &mut dyn Write) -> Result<()> {
+        let before = self.snapshot(line.to_string());
+        match self.converter.convert_line(line, &mut self.state) {
+            Ok(conversion) => {
+                self.history.push(before);
+                self.last = Some((line.to_string(), conversion.clone()));
+                self.print(Output::Mml, line, conversion, out)
+            }
+            Err(e) => report(line, &e, err),
+        }
+    }
+
+    fn print(
+        &self,
+        output: Output,
+        input: &str,
+        conversion: Conversion,
+        out: &mut dyn Write,
+    ) -> Result<()> {
+        let mut writer = Writer::new(output, &self.options, Box::new(out));
+        writer.entry(None, input, conversion)?;
+        writer.finish()
+    }
+
+    fn snapshot(&self, what: String) -> Snapshot {
+        Snapshot {
+            what,
+            state: self.state.clone(),
+            transpose: self.options.transpose,
+            last: self.last.clone(),
+        }
+    }
+
+    fn save(&mut self, what: String) {
+        let snapshot = self.snapshot(what);
+        self.history.push(snapshot);
+    }
+
+    fn set_transpose(&mut self, semitones: i32) -> Result<()> {
+        if semitones != self.options.transpose {
+            self.options.transpose = semitones;
+            self.converter = Converter::with_options(self.options.clone())?;
+        }
+        Ok(())
+    }
+}
+
+fn report(input: &str, error: &anyhow::Error, err: &mut dyn Write) -> Result<()> {
+    writeln!(err, "Error: {}", locate(None, input, error))?;
+    Ok(())
+}
+
+/// Run the REPL on stdin; the prompt and banner only show on a terminal.
+pub fn run(options: ConvertOptions) -> Result<(), Failure> {
+    let mut repl = Repl::new(options)
+        .map_err(|e| Failure::Io(e.context("Failed to initialize the parser")))?;
+    let interactive = io::stdin().is_terminal();
+    if interactive {
+        eprintln!("chord2mml - Convert chord notation to MML");
+        eprintln!("Enter chords (e.g. 'key=F I IV V'); :help lists commands, Ctrl+D exits.");
+    }
+    let mut lines = io::stdin().lock().lines();
+    loop {
+        if interactive {
+            print!("> ");
+            io::stdout().flush().map_err(|e| Failure::Io(e.into()))?;
+        }
+        let Some(line) = lines.next() else {
+            break;
+        };
+        let line = line.context("Failed to read stdin").map_err(Failure::Io)?;
+        let keep_going = repl
+            .handle(&line, &mut io::stdout(), &mut io::stderr())
+            .map_err(|e| Failure::Io(anyhow!("Failed to write the output: {:#}", e)))?;
+        if !keep_going {
+            break;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Feed lines to a REPL, returning what it wrote to stdout and stderr.
+    fn session(lines: &[&str]) -> (String, String) {
+        let mut repl = Repl::new(ConvertOptions::default()).unwrap();
+        let (mut out, mut err) = (Vec::new(), Vec::new());
+        for line in lines {
+            if !repl.handle(line, &mut out, &mut err).unwrap() {
+                break;
+            }
+        }
+        (
+            String::from_utf8(out).unwrap(),
+            String::from_utf8(err).unwrap(),
+        )
+    }
+
+    #[test]
+    fn test_state_persists_across_lines() {
+        let (out, err) = session(&["key=F", "octave up", "C $", "I IV", ":quit", "I"]);
+        let mml = Converter::new()
+            .unwrap()
+            .convert("key=F octave up I IV")
+            .unwrap();
+        assert_eq!(out, format!("v11\nv11\n{}\n", mml));
+        assert_eq!(
+            err,
+            "Error: column 3: Syntax error in chord notation: C $\n"
+        );
+    }
+
+    #[test]
+    fn test_meta_commands() {
+        let (out, err) = session(&[
+            ":notes",
+            ":key D",
+            ":notes I",
+            ":transpose 2",
+            "I",
+            ":undo",
+            ":undo",
+            "I",
+            ":ast",
+            ":undo",
+            ":undo",
+            ":undo",
+            ":bogus",
+        ]);
+        assert_eq!(
+            out,
+            "I\tD4 F#4 A4\t62 66 69\n\
+             Transposing by 2\n\
+             v11'e1g+b'\n\
+             Undid I\n\
+             Undid :transpose 2\n\
+             v11'd1f+a'\n\
+             [{\"chord\":{\"root\":2,\"root_letter\":1,\"quality\":\"maj\",\"inversion\":null,\
+             \"octave_offset\":0,\"note_length\":null,\"span\":{\"start\":0,\"end\":1}}}]\n\
+             Undid I\n\
+             Undid :key D\n"
+        );
+        assert_eq!(
+            err,
+            "Nothing converted yet\nNothing to undo\nUnknown command :bogus (:help lists them)\n"
+        );
+    }
+}
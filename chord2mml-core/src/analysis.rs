@@ -0,0 +1,690 @@
+//! Harmonic analysis (Rust-version extension).
+//!
+//! Labels each chord of the parsed [`Event`] stream with its Roman numeral
+//! in the active key and its function: tonic (T), subdominant (S) or
+//! dominant (D). The key is the one given to [`analyze`] until a `key=`
+//! directive changes it; a `scale=` directive changes which notes count as
+//! in the key. A chord outside the key is read, in this order, as a
+//! secondary dominant (`V7/ii`), a secondary leading-tone chord
+//! (`vii°7/V`) or a tritone substitution (`subV7`) that resolves, or a
+//! chord borrowed from the parallel mode (`bVI` in C major); anything else
+//! is chromatic and has no function. A minor chord followed by a dominant a
+//! fourth up is grouped as a ii–V, and when the dominant is a secondary one
+//! a chromatic ii is labelled as its ii (`iiø7/ii`).
+//!
+//! Numerals count from the tonic with accidentals relative to the major
+//! scale (`bIII`, `bVI`, `bVII` in a minor key), uppercase for chords with
+//! a major third or none, lowercase for minor and diminished ones. Slash
+//! chords and polychords are analyzed by their upper chord.
+
+use std::fmt;
+use std::str::FromStr;
+
+use anyhow::{anyhow, Result};
+use serde::Serialize;
+
+use crate::ast::{Event, Span};
+use crate::ast2notes::chord_intervals;
+use crate::cst_to_ast::parse_key;
+
+pub(crate) const MAJOR: [i32; 7] = [0, 2, 4, 5, 7, 9, 11];
+pub(crate) const NATURAL_MINOR: [i32; 7] = [0, 2, 3, 5, 7, 8, 10];
+pub(crate) const HARMONIC_MINOR: [i32; 7] = [0, 2, 3, 5, 7, 8, 11];
+pub(crate) const MELODIC_MINOR: [i32; 7] = [0, 2, 3, 5, 7, 9, 11];
+
+/// Numeral of the chord on each semitone above the tonic.
+const NUMERALS: [&str; 12] = [
+    "I", "bII", "II", "bIII", "III", "IV", "bV", "V", "bVI", "VI", "bVII", "VII",
+];
+/// Scale degree (I = 0 .. VII = 6) of each semitone above the tonic.
+const DEGREES: [usize; 12] = [0, 1, 1, 2, 2, 3, 4, 4, 5, 5, 6, 6];
+/// Function of a diatonic chord on each scale degree.
+const DEGREE_FUNCTIONS: [Function; 7] = [
+    Function::Tonic,
+    Function::Subdominant,
+    Function::Tonic,
+    Function::Subdominant,
+    Function::Dominant,
+    Function::Tonic,
+    Function::Dominant,
+];
+
+/// Key names, sharps or flats as the key signature has them.
+pub(crate) const MAJOR_NAMES: [&str; 12] = [
+    "C", "Db", "D", "Eb", "E", "F", "F#", "G", "Ab", "A", "Bb", "B",
+];
+pub(crate) const MINOR_NAMES: [&str; 12] = [
+    "C", "C#", "D", "Eb", "E", "F", "F#", "G", "G#", "A", "Bb", "B",
+];
+
+/// A key: its tonic in semitones above C (0-11) and its mode.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub struct Key {
+    pub tonic: i32,
+    pub minor: bool,
+}
+
+impl Key {
+    pub fn major(tonic: i32) -> Self {
+        Key {
+            tonic: tonic.rem_euclid(12),
+            minor: false,
+        }
+    }
+
+    pub fn minor(tonic: i32) -> Self {
+        Key {
+            tonic: tonic.rem_euclid(12),
+            minor: true,
+        }
+    }
+}
+
+impl fmt::Display for Key {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let tonic = self.tonic.rem_euclid(12) as usize;
+        if self.minor {
+            write!(f, "{} minor", MINOR_NAMES[tonic])
+        } else {
+            write!(f, "{} major", MAJOR_NAMES[tonic])
+        }
+    }
+}
+
+/// A key as `key=` takes it (`F`, `Bb`, `F#m`), or with the mode spelled
+/// out (`A minor`, `Eb major`).
+impl FromStr for Key {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let s = s.trim();
+        let (name, mode) = match s.rsplit_once(char::is_whitespace) {
+            Some((name, mode)) if mode.eq_ignore_ascii_case("minor") => (name, Some(true)),
+            Some((name, mode)) if mode.eq_ignore_ascii_case("major") => (name, Some(false)),
+            _ => (s, None),
+        };
+        let (offset, _, minor) =
+            parse_key(&format!("key={}", name.trim())).map_err(|_| anyhow!("Not a key: {}", s))?;
+        Ok(Key {
+            tonic: offset.rem_euclid(12),
+            minor: mode.unwrap_or(minor),
+        })
+    }
+}
+
+/// Harmonic function.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum Function {
+    #[serde(rename = "T")]
+    Tonic,
+    #[serde(rename = "S")]
+    Subdominant,
+    #[serde(rename = "D")]
+    Dominant,
+}
+
+impl Function {
+    /// `T`, `S` or `D`.
+    pub fn symbol(self) -> &'static str {
+        match self {
+            Function::Tonic => "T",
+            Function::Subdominant => "S",
+            Function::Dominant => "D",
+        }
+    }
+}
+
+/// How a chord relates to its key.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Role {
+    /// All its notes are in the key
+    Diatonic,
+    /// `V/x`: the dominant of another degree of the key
+    SecondaryDominant,
+    /// `vii°/x`: the leading-tone chord of another degree of the key
+    SecondaryLeadingTone,
+    /// `ii/x`: the ii of the ii–V of a secondary dominant
+    RelatedTwo,
+    /// `subV/x`: a dominant seventh a semitone above the chord it resolves to
+    TritoneSubstitution,
+    /// From the parallel mode (minor in a major key, major in a minor key)
+    Borrowed,
+    Chromatic,
+}
+
+/// One chord's analysis.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChordAnalysis {
+    /// Where the chord was written in the input
+    pub span: Option<Span>,
+    /// The key in effect
+    pub key: Key,
+    /// e.g. `ii7`, `V7/ii`, `subV7`, `bVI`
+    pub numeral: String,
+    /// None for chromatic chords
+    pub function: Option<Function>,
+    pub role: Role,
+}
+
+/// Consecutive chords forming a ii–V, as indexes into [`Analysis::chords`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TwoFive {
+    pub two: usize,
+    pub five: usize,
+    /// The next chord, when it is the one the V resolves to
+    pub resolution: Option<usize>,
+    /// The degree the ii–V leads to, e.g. `I` or `ii`
+    pub target: String,
+}
+
+/// What [`analyze`] finds.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Analysis {
+    /// Each chord's analysis, in input order
+    pub chords: Vec<ChordAnalysis>,
+    pub two_fives: Vec<TwoFive>,
+}
+
+/// The key and the notes in it while walking the events.
+#[derive(Clone)]
+struct Context {
+    key: Key,
+    /// Set by `scale=`, relative to the tonic
+    scale: Option<Vec<i32>>,
+}
+
+impl Context {
+    /// Whether all of `tones` (relative to the tonic) are in the key; in a
+    /// minor key, any of natural, harmonic and melodic minor.
+    fn is_diatonic(&self, tones: &[i32]) -> bool {
+        match &self.scale {
+            Some(scale) => within(tones, scale),
+            None if self.key.minor => [NATURAL_MINOR, HARMONIC_MINOR, MELODIC_MINOR]
+                .iter()
+                .any(|scale| within(tones, scale)),
+            None => within(tones, &MAJOR),
+        }
+    }
+
+    fn is_borrowed(&self, tones: &[i32]) -> bool {
+        if self.key.minor {
+            within(tones, &MAJOR)
+        } else {
+            within(tones, &NATURAL_MINOR) || within(tones, &HARMONIC_MINOR)
+        }
+    }
+
+    /// The scale the degrees of the key are built on.
+    fn degrees(&self) -> &[i32] {
+        match &self.scale {
+            Some(scale) => scale,
+            None if self.key.minor => &NATURAL_MINOR,
+            None => &MAJOR,
+        }
+    }
+
+    /// The numeral of the key's chord on `degree` (semitones above the
+    /// tonic), or None when `degree` is not in the key or its chord is
+    /// diminished (no chord to tonicize).
+    fn target(&self, degree: i32) -> Option<String> {
+        let scale = self.degrees();
+        let index = scale.iter().position(|&tone| tone == degree)?;
+        if scale.len() != 7 {
+            return Some(NUMERALS[degree as usize].to_string());
+        }
+        let above = |steps: usize| (scale[(index + steps) % 7] - degree).rem_euclid(12);
+        match (above(2), above(4)) {
+            (_, 6) => None,
+            (3, _) => Some(NUMERALS[degree as usize].to_lowercase()),
+            _ => Some(NUMERALS[degree as usize].to_string()),
+        }
+    }
+}
+
+fn within(tones: &[i32], scale: &[i32]) -> bool {
+    tones.iter().all(|tone| scale.contains(tone))
+}
+
+/// A chord's notes, relative to its root.
+struct Shape {
+    /// Root in semitones above C
+    root: i32,
+    intervals: Vec<i32>,
+    /// First part of the quality, for 9th, 11th and 13th chords
+    base: String,
+}
+
+impl Shape {
+    fn has(&self, interval: i32) -> bool {
+        self.intervals.contains(&interval)
+    }
+
+    fn is_minor(&self) -> bool {
+        self.has(3) && !self.has(4)
+    }
+
+    fn is_diminished(&self) -> bool {
+        self.is_minor() && self.has(6) && !self.has(7)
+    }
+
+    /// A major triad, or one with a minor seventh
+    fn is_dominant(&self) -> bool {
+        self.has(4) && !self.has(8) && !self.has(11)
+    }
+
+    fn is_dominant_seventh(&self) -> bool {
+        self.is_dominant() && self.has(10)
+    }
+
+    /// Notes relative to `tonic`.
+    fn tones(&self, tonic: i32) -> Vec<i32> {
+        self.intervals
+            .iter()
+            .map(|interval| (self.root + interval - tonic).rem_euclid(12))
+            .collect()
+    }
+
+    /// What follows the numeral: `7`, `maj7`, `°`, `ø7`, `sus4`, ...
+    fn suffix(&self) -> String {
+        let seventh = match self.base.as_str() {
+            extended @ ("9" | "11" | "13") => extended,
+            _ => "7",
+        };
+        if self.has(4) {
+            let mut suffix = if self.has(8) && !self.has(7) {
+                "+".to_string()
+            } else {
+                String::new()
+            };
+            if self.has(10) {
+                suffix.push_str(seventh);
+            } else if self.has(11) {
+                suffix.push_str("maj7");
+            } else if self.has(9) && suffix.is_empty() {
+                suffix.push('6');
+            }
+            suffix
+        } else if self.has(3) {
+            if self.is_diminished() {
+                match (self.has(9), self.has(10)) {
+                    (_, true) => "ø7",
+                    (true, false) => "°7",
+                    (false, false) => "°",
+                }
+                .to_string()
+            } else if self.has(10) {
+                "7".to_string()
+            } else if self.has(11) {
+                "(maj7)".to_string()
+            } else if self.has(9) {
+                "6".to_string()
+            } else {
+                String::new()
+            }
+        } else {
+            let seventh = if self.has(10) { "7" } else { "" };
+            let sus = if self.has(5) {
+                "sus4"
+            } else if self.has(2) {
+                "sus2"
+            } else {
+                ""
+            };
+            format!("{}{}", seventh, sus)
+        }
+    }
+
+    /// The numeral of the chord on `degree` semitones above the tonic.
+    fn numeral(&self, degree: i32) -> String {
+        let mut numeral = if degree == 6 && self.is_diminished() {
+            "#IV"
+        } else {
+            NUMERALS[degree as usize]
+        }
+        .to_string();
+        if self.is_minor() {
+            numeral = numeral.to_lowercase();
+        }
+        numeral + &self.suffix()
+    }
+}
+
+/// Label the chords of `events` (e.g. `Conversion::events`) in `key` (C
+/// major when None), which `key=` directives in the events change.
+///
+/// # Example
+/// ```
+/// use chord2mml_core::{analyze, Converter};
+///
+/// let conversion = Converter::new()
+///     .unwrap()
+///     .convert_explained("Dm7 G7 Cmaj7 A7 Dm7 Db7 C")
+///     .unwrap();
+/// let analysis = analyze(&conversion.events, None);
+/// let numerals: Vec<_> = analysis.chords.iter().map(|c| c.numeral.as_str()).collect();
+/// assert_eq!(numerals, ["ii7", "V7", "Imaj7", "V7/ii", "ii7", "subV7", "I"]);
+/// assert_eq!(analysis.two_fives[0].target, "I");
+/// ```
+pub fn analyze(events: &[Event], key: Option<Key>) -> Analysis {
+    let mut context = Context {
+        key: key.unwrap_or_default(),
+        scale: None,
+    };
+    let mut chords: Vec<(Shape, Option<Span>, Context)> = Vec::new();
+    for event in events {
+        let Some((root, quality, span)) = chord_of(event) else {
+            match event {
+                Event::Key { offset, minor } => {
+                    context.key = Key {
+                        tonic: offset.rem_euclid(12),
+                        minor: *minor,
+                    };
+                    context.scale = None;
+                }
+                Event::Scale { offsets } => context.scale = Some(offsets.clone()),
+                _ => {}
+            }
+            continue;
+        };
+        // Qualities come from the parser, so they are all in the table
+        let Ok(intervals) = chord_intervals(quality) else {
+            continue;
+        };
+        let shape = Shape {
+            root: root.rem_euclid(12),
+            intervals,
+            base: quality.split(',').next().unwrap_or_default().to_string(),
+        };
+        chords.push((shape, span, context.clone()));
+    }
+
+    let mut analysis = Analysis::default();
+    for (i, (shape, span, context)) in chords.iter().enumerate() {
+        let next_root = chords.get(i + 1).map(|(next, ..)| next.root);
+        let (numeral, function, role) = classify(shape, context, next_root);
+        analysis.chords.push(ChordAnalysis {
+            span: *span,
+            key: context.key,
+            numeral,
+            function,
+            role,
+        });
+    }
+
+    for (i, pair) in chords.windows(2).enumerate() {
+        let [(two, ..), (five, _, context)] = pair else {
+            unreachable!("windows of 2");
+        };
+        // The tonic chord is not a ii
+        if !two.is_minor()
+            || two.root == context.key.tonic
+            || !five.is_dominant()
+            || five.root != (two.root + 5) % 12
+        {
+            continue;
+        }
+        let target_root = (five.root + 5) % 12;
+        let resolution = chords
+            .get(i + 2)
+            .filter(|(chord, ..)| chord.root == target_root);
+        let degree = (target_root - context.key.tonic).rem_euclid(12);
+        let target = context.target(degree).unwrap_or_else(|| {
+            let numeral = NUMERALS[degree as usize];
+            match resolution {
+                Some((chord, ..)) if chord.is_minor() => numeral.to_lowercase(),
+                _ => numeral.to_string(),
+            }
+        });
+        // The ii before a secondary dominant is that dominant's ii
+        if analysis.chords[i].role == Role::Chromatic
+            && analysis.chords[i + 1].role == Role::SecondaryDominant
+        {
+            let two_analysis = &mut analysis.chords[i];
+            two_analysis.numeral = format!("ii{}/{}", two.suffix(), target);
+            two_analysis.function = Some(Function::Subdominant);
+            two_analysis.role = Role::RelatedTwo;
+        }
+        analysis.two_fives.push(TwoFive {
+            two: i,
+            five: i + 1,
+            resolution: resolution.map(|_| i + 2),
+            target,
+        });
+    }
+    analysis
+}
+
+/// The root, quality and span of the chord an event plays (the upper
+/// chord of slash chords and polychords).
+pub(crate) fn chord_of(event: &Event) -> Option<(i32, &str, Option<Span>)> {
+    match event {
+        Event::Chord(chord) => Some((chord.root, &chord.quality, chord.span)),
+        Event::SlashChord(slash)
+        | Event::ChordOverBassNote(slash)
+        | Event::Inversion(slash)
+        | Event::Polychord(slash) => Some((slash.upper_root, &slash.upper_quality, slash.span)),
+        _ => None,
+    }
+}
+
+/// The numeral, function and role of one chord; `next_root` is the root of
+/// the chord after it, which leading-tone chords and tritone substitutions
+/// must resolve to.
+fn classify(
+    shape: &Shape,
+    context: &Context,
+    next_root: Option<i32>,
+) -> (String, Option<Function>, Role) {
+    let tonic = context.key.tonic;
+    let degree = (shape.root - tonic).rem_euclid(12);
+    let tones = shape.tones(tonic);
+    if context.is_diatonic(&tones) {
+        let function = DEGREE_FUNCTIONS[DEGREES[degree as usize]];
+        return (shape.numeral(degree), Some(function), Role::Diatonic);
+    }
+
+    let secondary = |target: i32, numeral: &str| {
+        let target = (target - tonic).rem_euclid(12);
+        (target != 0)
+            .then(|| context.target(target))
+            .flatten()
+            .map(|target| format!("{}{}/{}", numeral, shape.suffix(), target))
+    };
+    let borrowed = || {
+        context.is_borrowed(&tones).then(|| {
+            // bVI and bVII in major are subdominant minor
+            let function = match degree {
+                8 | 10 if !context.key.minor => Function::Subdominant,
+                _ => DEGREE_FUNCTIONS[DEGREES[degree as usize]],
+            };
+            (shape.numeral(degree), Some(function), Role::Borrowed)
+        })
+    };
+    let dominant = |numeral: String, role| (numeral, Some(Function::Dominant), role);
+
+    // A plain major triad is more often borrowed than tonicizing
+    if shape.is_dominant() && !shape.has(10) {
+        if let Some(borrowed) = borrowed() {
+            return borrowed;
+        }
+    }
+    if shape.is_dominant() {
+        if let Some(numeral) = secondary(shape.root + 5, "V") {
+            return dominant(numeral, Role::SecondaryDominant);
+        }
+    }
+    let resolves_to = |root: i32| next_root.is_none_or(|next| next == root % 12);
+    if shape.is_diminished() && resolves_to(shape.root + 1) {
+        if let Some(numeral) = secondary(shape.root + 1, "vii") {
+            return dominant(numeral, Role::SecondaryLeadingTone);
+        }
+    }
+    if shape.is_dominant_seventh() && next_root == Some((shape.root + 11) % 12) {
+        let target = (shape.root + 11 - tonic).rem_euclid(12);
+        let numeral = if target == 0 {
+            Some(format!("subV{}", shape.suffix()))
+        } else {
+            context
+                .target(target)
+                .map(|target| format!("subV{}/{}", shape.suffix(), target))
+        };
+        if let Some(numeral) = numeral {
+            return dominant(numeral, Role::TritoneSubstitution);
+        }
+    }
+    borrowed().unwrap_or_else(|| (shape.numeral(degree), None, Role::Chromatic))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Converter;
+
+    fn labels(input: &str, key: Option<Key>) -> Vec<(String, Option<&'static str>, Role)> {
+        let conversion = Converter::new().unwrap().convert_explained(input).unwrap();
+        analyze(&conversion.events, key)
+            .chords
+            .into_iter()
+            .map(|chord| {
+                let function = chord.function.map(Function::symbol);
+                (chord.numeral, function, chord.role)
+            })
+            .collect()
+    }
+
+    fn numerals(input: &str, key: Option<Key>) -> Vec<String> {
+        labels(input, key)
+            .into_iter()
+            .map(|(numeral, ..)| numeral)
+            .collect()
+    }
+
+    #[test]
+    fn test_diatonic_numerals_and_functions() {
+        assert_eq!(
+            labels("C Dm7 Em F Gsus4 G7 Am Bm7(b5)", None)
+                .into_iter()
+                .map(|(numeral, function, _)| format!("{}:{}", numeral, function.unwrap()))
+                .collect::<Vec<_>>(),
+            ["I:T", "ii7:S", "iii:T", "IV:S", "Vsus4:D", "V7:D", "vi:T", "viiø7:D"]
+        );
+        assert_eq!(
+            numerals("Am Bdim C Dm7 E7 F G#dim(add6)", Some(Key::minor(9))),
+            ["i", "ii°", "bIII", "iv7", "V7", "bVI", "vii°7"]
+        );
+    }
+
+    #[test]
+    fn test_key_directives() {
+        assert_eq!(
+            numerals("key=F Bb C7 F key=Am Dm E7 Am", None),
+            ["IV", "V7", "I", "iv", "V7", "i"]
+        );
+        let conversion = Converter::new()
+            .unwrap()
+            .convert_explained("key=Am Am")
+            .unwrap();
+        assert_eq!(
+            analyze(&conversion.events, None).chords[0].key.to_string(),
+            "A minor"
+        );
+    }
+
+    #[test]
+    fn test_secondary_and_substitute_dominants() {
+        assert_eq!(
+            labels("C A7 Dm D7 G C7 F E7 Am Ab7 G7 C F#dim(add6) G Db7 C", None)
+                .into_iter()
+                .filter(|(_, _, role)| *role != Role::Diatonic)
+                .collect::<Vec<_>>(),
+            [
+                ("V7/ii".to_string(), Some("D"), Role::SecondaryDominant),
+                ("V7/V".to_string(), Some("D"), Role::SecondaryDominant),
+                ("V7/IV".to_string(), Some("D"), Role::SecondaryDominant),
+                ("V7/vi".to_string(), Some("D"), Role::SecondaryDominant),
+                ("subV7/V".to_string(), Some("D"), Role::TritoneSubstitution),
+                ("vii°7/V".to_string(), Some("D"), Role::SecondaryLeadingTone),
+                ("subV7".to_string(), Some("D"), Role::TritoneSubstitution),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_borrowed_and_chromatic() {
+        assert_eq!(
+            labels("C Fm Ab Bb7 Eb C F#", None)[1..],
+            [
+                ("iv".to_string(), Some("S"), Role::Borrowed),
+                ("bVI".to_string(), Some("S"), Role::Borrowed),
+                ("bVII7".to_string(), Some("S"), Role::Borrowed),
+                ("bIII".to_string(), Some("T"), Role::Borrowed),
+                ("I".to_string(), Some("T"), Role::Diatonic),
+                ("bV".to_string(), None, Role::Chromatic),
+            ]
+        );
+        // The Picardy third of a minor key (IV is diatonic in melodic minor)
+        assert_eq!(
+            labels("Am D A", Some(Key::minor(9)))[1..],
+            [
+                ("IV".to_string(), Some("S"), Role::Diatonic),
+                ("I".to_string(), Some("T"), Role::Borrowed),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_two_fives() {
+        let conversion = Converter::new()
+            .unwrap()
+            .convert_explained("Em7(b5) A7 Dm7 G7 | Cmaj7 Gm7 C7")
+            .unwrap();
+        let analysis = analyze(&conversion.events, None);
+        assert_eq!(
+            analysis.two_fives,
+            [
+                TwoFive {
+                    two: 0,
+                    five: 1,
+                    resolution: Some(2),
+                    target: "ii".to_string(),
+                },
+                TwoFive {
+                    two: 2,
+                    five: 3,
+                    resolution: Some(4),
+                    target: "I".to_string(),
+                },
+                TwoFive {
+                    two: 5,
+                    five: 6,
+                    resolution: None,
+                    target: "IV".to_string(),
+                },
+            ]
+        );
+        assert_eq!(analysis.chords[0].numeral, "iiø7/ii");
+        assert_eq!(analysis.chords[0].role, Role::RelatedTwo);
+        assert_eq!(analysis.chords[5].numeral, "v7");
+        assert_eq!(analysis.chords[5].role, Role::Borrowed);
+    }
+
+    #[test]
+    fn test_parse_key() {
+        for (text, key) in [
+            ("F", Key::major(5)),
+            ("Bb", Key::major(10)),
+            ("F#m", Key::minor(6)),
+            ("A minor", Key::minor(9)),
+            ("Eb major", Key::major(3)),
+        ] {
+            assert_eq!(text.parse::<Key>().unwrap(), key, "{}", text);
+        }
+        assert!("H".parse::<Key>().is_err());
+        assert_eq!(Key::minor(1).to_string(), "C# minor");
+        assert_eq!(Key::major(8).to_string(), "Ab major");
+    }
+}
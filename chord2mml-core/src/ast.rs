@@ -5,8 +5,11 @@
 //! roots are semitone offsets (C=0 .. B=11), and qualities are normalized
 //! comma-joinable strings ("maj", "min", "maj7,add9", ...).
 
+use serde::Serialize;
+
 /// One event in the input sequence.
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
 pub enum Event {
     /// A plain chord (JS: `{event: "chord", ...}`)
     Chord(ChordEvent),
@@ -44,8 +47,12 @@ pub enum Event {
     BarSlash,
     /// JS: `{event: "key", ...}`; the offset is the key root as semitones
     /// (degrees are resolved against it in cst_to_ast; notes2mml uses it
-    /// for sharp/flat spelling)
-    Key { offset: i32 },
+    /// for sharp/flat spelling); `minor` for `key=Xm`
+    Key {
+        offset: i32,
+        #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+        minor: bool,
+    },
     /// JS: `{event: "scale", offsets}`; used by notes2mml for sharp/flat
     /// spelling only
     Scale { offsets: Vec<i32> },
@@ -56,7 +63,8 @@ pub enum Event {
 
 /// Output of `ast2notes`, consumed by `notes2mml` (mirrors what the JS
 /// notesToMml switch handles).
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
 pub enum OutEvent {
     Notes(NotesEvent),
     Bar,
@@ -66,7 +74,8 @@ pub enum OutEvent {
 }
 
 /// Slash-chord interpretation modes (JS slashMode state in ast2ast).
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
 pub enum SlashChordMode {
     ChordOverBassNote,
     Inversion,
@@ -74,11 +83,14 @@ pub enum SlashChordMode {
 }
 
 /// A plain chord event.
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
 pub struct ChordEvent {
     /// Root as semitone offset. C=0, D=2, ... B=11, plus accidentals
     /// (not normalized mod 12, matching the JS grammar).
     pub root: i32,
+    /// Letter of the root as written (C=0 .. B=6; degrees take the key's
+    /// letter), used by chord-aware spelling. None when unknown.
+    pub root_letter: Option<u8>,
     /// Normalized quality string, e.g. "maj", "min", "min7", "dim triad".
     pub quality: String,
     /// Per-chord inversion from `^0`-`^3` ("root inv".."3rd inv");
@@ -89,27 +101,130 @@ pub struct ChordEvent {
     /// Note length digit (1=whole, 2=half, 4=quarter, ...); assigned by
     /// `ast2ast` from bar positions.
     pub note_length: Option<u32>,
+    /// Where the chord was written in the input (for source maps)
+    pub span: Option<Span>,
 }
 
 /// A slash chord (upper chord over lower chord/bass).
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
 pub struct SlashChordEvent {
     pub upper_root: i32,
+    pub upper_root_letter: Option<u8>,
     pub upper_quality: String,
     pub upper_inversion: Option<String>,
     pub lower_root: i32,
+    pub lower_root_letter: Option<u8>,
     pub lower_quality: String,
     pub lower_inversion: Option<String>,
     pub upper_octave_offset: i32,
     pub lower_octave_offset: i32,
     pub note_length: Option<u32>,
+    pub span: Option<Span>,
 }
 
 /// A resolved chord as concrete notes (output of `ast2notes`).
 /// Notes are absolute semitone offsets relative to the base octave;
 /// values may exceed 0..11 (octave up) or go negative (octave down).
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
 pub struct NotesEvent {
     pub notes: Vec<i32>,
+    /// Letter of each note spelled from its chord root (C=0 .. B=6), for
+    /// chord-aware spelling; None when a root letter was unknown.
+    pub letters: Option<Vec<u8>>,
     pub note_length: Option<u32>,
+    /// Span of the chord the notes came from
+    pub span: Option<Span>,
+}
+
+/// A range of offsets, `start..end`: bytes for native parsing and MML
+/// output, or whatever unit the CST JSON's `startIndex`/`endIndex` use.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct Span {
+    pub start: usize,
+    pub end: usize,
+}
+
+/// One chord's entry in a source map: where it was written in the input
+/// and where its `'...'` group is in the MML.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct SourceMapping {
+    pub input: Span,
+    pub output: Span,
+}
+
+/// The notes one chord sounds, as MIDI note numbers (`c` of the MML's
+/// default octave is 60), and where the chord was written.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ChordNotes {
+    pub notes: Vec<i32>,
+    pub span: Option<Span>,
+}
+
+/// A problem that did not stop the conversion, e.g. a chord voiced
+/// outside the MIDI note range.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Warning {
+    pub message: String,
+    pub span: Option<Span>,
+}
+
+/// An error located in the input: a syntax error, or an event that could
+/// not be converted (e.g. an unknown instrument name). Conversion errors
+/// carry one when the position is known; get it with
+/// `error.downcast_ref::<InputError>()`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, thiserror::Error)]
+#[error("{message}")]
+pub struct InputError {
+    pub message: String,
+    pub span: Span,
+}
+
+/// An error on one line of a multi-line conversion
+/// ([`crate::convert_lines`]): the line number, from 1, and the error's
+/// message and span in that line as written (`None` when the position is
+/// unknown or only in a dialect rewrite of the line). Get it with
+/// `error.downcast_ref::<LineError>()`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, thiserror::Error)]
+#[error("Line {line}: {message}")]
+pub struct LineError {
+    pub line: usize,
+    pub message: String,
+    pub span: Option<Span>,
+}
+
+/// A note name as the output spells it, e.g. B-flat above middle C is
+/// `Bb4` (letter `B`, alter -1, octave 4; middle C is `C4`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct SpelledNote {
+    /// `A`..`G`
+    pub letter: char,
+    /// Semitones of sharps (positive) or flats (negative)
+    pub alter: i32,
+    pub octave: i32,
+}
+
+impl SpelledNote {
+    /// From an MML name (`c+`, `b-`) and its octave relative to the MML
+    /// default octave.
+    pub(crate) fn from_mml(name: &str, octave_offset: i32) -> Self {
+        let alter = name.matches('+').count() as i32 - name.matches('-').count() as i32;
+        SpelledNote {
+            letter: name.chars().next().unwrap_or('c').to_ascii_uppercase(),
+            alter,
+            octave: 4 + octave_offset,
+        }
+    }
+}
+
+impl std::fmt::Display for SpelledNote {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let accidental = if self.alter > 0 { "#" } else { "b" };
+        write!(
+            f,
+            "{}{}{}",
+            self.letter,
+            accidental.repeat(self.alter.unsigned_abs() as usize),
+            self.octave
+        )
+    }
 }
@@ -4,7 +4,7 @@
 //! each chord's note length from bar positions. Bass-play mode and bar
 //! handling arrive in later waves.
 
-use crate::ast::{Event, SlashChordEvent, SlashChordMode};
+use crate::ast::{ChordEvent, Event, SlashChordEvent, SlashChordMode};
 
 pub(crate) fn ast_to_ast(events: Vec<Event>) -> Vec<Event> {
     let mut slash_mode = SlashChordMode::ChordOverBassNote;
@@ -30,14 +30,17 @@ pub(crate) fn ast_to_ast(events: Vec<Event>) -> Vec<Event> {
             Event::Chord(chord) if bass_play_mode == "root" => {
                 result.push(Event::ChordOverBassNote(SlashChordEvent {
                     upper_root: chord.root,
+                    upper_root_letter: chord.root_letter,
                     upper_quality: chord.quality.clone(),
                     upper_inversion: chord.inversion.clone(),
                     lower_root: chord.root,
+                    lower_root_letter: chord.root_letter,
                     lower_quality: chord.quality,
                     lower_inversion: chord.inversion,
                     upper_octave_offset: chord.octave_offset,
                     lower_octave_offset: chord.octave_offset,
                     note_length: None,
+                    span: chord.span,
                 }));
             }
             other => result.push(other),
@@ -52,13 +55,36 @@ pub(crate) fn ast_to_ast(events: Vec<Event>) -> Vec<Event> {
 /// divides the whole note among its chords (4 chords → quarter notes);
 /// a bar slash (`/ `) halves the bar, so each side's chords divide a half
 /// note. Without any bar events, every chord keeps note length 1.
-fn bar_to_note_length(events: &mut [Event]) {
+///
+/// Chords whose length is already set (by reharmonization, which splits a
+/// chord's time with the chords it inserts) keep it and are not counted.
+pub(crate) fn bar_to_note_length(events: &mut [Event]) {
     let mut bar_count = 0;
     let mut total_note_length: u32 = 1;
     let mut chord_indexes: Vec<usize> = Vec::new();
 
     for i in 0..events.len() {
         match &events[i] {
+            Event::Chord(ChordEvent {
+                note_length: Some(_),
+                ..
+            })
+            | Event::ChordOverBassNote(SlashChordEvent {
+                note_length: Some(_),
+                ..
+            })
+            | Event::Inversion(SlashChordEvent {
+                note_length: Some(_),
+                ..
+            })
+            | Event::Polychord(SlashChordEvent {
+                note_length: Some(_),
+                ..
+            })
+            | Event::SlashChord(SlashChordEvent {
+                note_length: Some(_),
+                ..
+            }) => {}
             Event::Chord(_)
             | Event::ChordOverBassNote(_)
             | Event::Inversion(_)
@@ -98,7 +124,7 @@ fn update_note_lengths(events: &mut [Event], chord_indexes: &[usize], total_note
     }
 }
 
-fn set_note_length(event: &mut Event, note_length: u32) {
+pub(crate) fn set_note_length(event: &mut Event, note_length: u32) {
     match event {
         Event::Chord(chord) => chord.note_length = Some(note_length),
         Event::ChordOverBassNote(slash)
@@ -112,19 +138,21 @@ fn set_note_length(event: &mut Event, note_length: u32) {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::ast::{ChordEvent, SlashChordEvent};
 
     fn slash() -> SlashChordEvent {
         SlashChordEvent {
             upper_root: 5,
+            upper_root_letter: Some(3),
             upper_quality: "maj".to_string(),
             upper_inversion: None,
             lower_root: 0,
+            lower_root_letter: Some(0),
             lower_quality: "maj".to_string(),
             lower_inversion: None,
             upper_octave_offset: 0,
             lower_octave_offset: 0,
             note_length: None,
+            span: None,
         }
     }
 
@@ -132,10 +160,12 @@ mod tests {
     fn test_chord_gets_whole_note_length() {
         let events = vec![Event::Chord(ChordEvent {
             root: 0,
+            root_letter: Some(0),
             quality: "maj".to_string(),
             inversion: None,
             octave_offset: 0,
             note_length: None,
+            span: None,
         })];
         let result = ast_to_ast(events);
         match &result[0] {
@@ -7,96 +7,160 @@
 
 use anyhow::{anyhow, Result};
 
-use crate::ast::{Event, NotesEvent, OutEvent};
+use crate::ast::{Event, NotesEvent, OutEvent, SlashChordEvent};
+use crate::spelling::spell_chord;
 
 pub(crate) fn ast_to_notes(events: Vec<Event>) -> Result<Vec<OutEvent>> {
+    let mut state = NotesState::default();
     let mut result = Vec::new();
-    // JS state: inversionMode / openHarmonyMode / bassPlayMode /
-    // octaveOffsetUpper / octaveOffsetLower
-    let mut inversion_mode = "root inv".to_string();
-    let mut open_harmony_mode = "close".to_string();
-    let mut bass_play_mode = "no bass".to_string();
-    let mut octave_offset_upper = 0;
-    let mut octave_offset_lower = 0;
-
     for event in events {
-        match event {
+        result.extend(state.notes(event)?);
+    }
+    Ok(result)
+}
+
+/// The modes and octave shifts ast2notes carries from one event to the
+/// next (JS state: inversionMode / openHarmonyMode / bassPlayMode /
+/// octaveOffsetUpper / octaveOffsetLower), so a session can resume the
+/// pass at any event.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct NotesState {
+    inversion_mode: String,
+    open_harmony_mode: String,
+    bass_play_mode: String,
+    octave_offset_upper: i32,
+    octave_offset_lower: i32,
+}
+
+impl Default for NotesState {
+    fn default() -> Self {
+        NotesState {
+            inversion_mode: "root inv".to_string(),
+            open_harmony_mode: "close".to_string(),
+            bass_play_mode: "no bass".to_string(),
+            octave_offset_upper: 0,
+            octave_offset_lower: 0,
+        }
+    }
+}
+
+impl NotesState {
+    /// The out-event of one ast2ast event (None for a mode change).
+    pub(crate) fn notes(&mut self, event: Event) -> Result<Option<OutEvent>> {
+        let out = match event {
             Event::Chord(chord) => {
-                let inversion = chord.inversion.as_deref().unwrap_or(&inversion_mode);
+                let inversion = chord.inversion.as_deref().unwrap_or(&self.inversion_mode);
                 let notes = get_notes_by_chord(
                     chord.root,
                     &chord.quality,
                     inversion,
-                    &open_harmony_mode,
-                    octave_offset_upper + chord.octave_offset,
+                    &self.open_harmony_mode,
+                    self.octave_offset_upper + chord.octave_offset,
                 )?;
-                result.push(OutEvent::Notes(NotesEvent {
+                let letters = chord
+                    .root_letter
+                    .map(|letter| spell_chord(&notes, chord.root, letter, &chord.quality));
+                Some(OutEvent::Notes(NotesEvent {
                     notes,
+                    letters,
                     note_length: chord.note_length,
-                }));
+                    span: chord.span,
+                }))
             }
             Event::ChordOverBassNote(slash) => {
-                let inversion = slash.upper_inversion.as_deref().unwrap_or(&inversion_mode);
+                let inversion = slash
+                    .upper_inversion
+                    .as_deref()
+                    .unwrap_or(&self.inversion_mode);
                 let notes = get_notes_by_chord_over_bass_note(
                     slash.upper_root,
                     &slash.upper_quality,
                     slash.lower_root,
                     inversion,
-                    &open_harmony_mode,
-                    octave_offset_upper + slash.upper_octave_offset,
-                    octave_offset_lower + slash.lower_octave_offset,
+                    &self.open_harmony_mode,
+                    self.octave_offset_upper + slash.upper_octave_offset,
+                    self.octave_offset_lower + slash.lower_octave_offset,
                 )?;
-                result.push(OutEvent::Notes(NotesEvent {
+                // The first note is the bass
+                let letters = slash_letters(&notes, 1, &slash);
+                Some(OutEvent::Notes(NotesEvent {
                     notes,
+                    letters,
                     note_length: slash.note_length,
-                }));
+                    span: slash.span,
+                }))
             }
             Event::Inversion(slash) => {
                 let notes = get_notes_by_inversion_chord(
                     slash.upper_root,
                     &slash.upper_quality,
                     slash.lower_root,
-                    &bass_play_mode,
-                    octave_offset_upper + slash.upper_octave_offset,
+                    &self.bass_play_mode,
+                    self.octave_offset_upper + slash.upper_octave_offset,
                 )?;
-                result.push(OutEvent::Notes(NotesEvent {
+                // Every note (including a bass-is-root bass) is a chord tone
+                let letters = slash.upper_root_letter.map(|letter| {
+                    spell_chord(&notes, slash.upper_root, letter, &slash.upper_quality)
+                });
+                Some(OutEvent::Notes(NotesEvent {
                     notes,
+                    letters,
                     note_length: slash.note_length,
-                }));
+                    span: slash.span,
+                }))
             }
             Event::Polychord(slash) => {
-                let upper_inversion = slash.upper_inversion.as_deref().unwrap_or(&inversion_mode);
-                let lower_inversion = slash.lower_inversion.as_deref().unwrap_or(&inversion_mode);
-                let notes = get_notes_by_polychord(
+                let upper_inversion = slash
+                    .upper_inversion
+                    .as_deref()
+                    .unwrap_or(&self.inversion_mode);
+                let lower_inversion = slash
+                    .lower_inversion
+                    .as_deref()
+                    .unwrap_or(&self.inversion_mode);
+                let (notes, lower_len) = get_notes_by_polychord(
                     slash.upper_root,
                     &slash.upper_quality,
                     upper_inversion,
                     slash.lower_root,
                     &slash.lower_quality,
                     lower_inversion,
-                    octave_offset_upper + slash.upper_octave_offset,
-                    octave_offset_lower + slash.lower_octave_offset,
+                    self.octave_offset_upper + slash.upper_octave_offset,
+                    self.octave_offset_lower + slash.lower_octave_offset,
                 )?;
-                result.push(OutEvent::Notes(NotesEvent {
+                let letters = slash_letters(&notes, lower_len, &slash);
+                Some(OutEvent::Notes(NotesEvent {
                     notes,
+                    letters,
                     note_length: slash.note_length,
-                }));
+                    span: slash.span,
+                }))
             }
-            Event::Bar => result.push(OutEvent::Bar),
+            Event::Bar => Some(OutEvent::Bar),
             // Bar slashes were consumed by ast2ast's note-length pass
-            Event::BarSlash => {}
-            Event::Key { offset } => result.push(OutEvent::Key { offset }),
-            Event::Scale { offsets } => result.push(OutEvent::Scale { offsets }),
-            Event::InlineMml(mml) => result.push(OutEvent::InlineMml(mml)),
-            Event::ChangeInversionMode(mode) => inversion_mode = mode,
-            Event::ChangeOpenHarmonyMode(mode) => open_harmony_mode = mode,
-            Event::ChangeBassPlayMode(mode) => bass_play_mode = mode,
+            Event::BarSlash => None,
+            Event::Key { offset, .. } => Some(OutEvent::Key { offset }),
+            Event::Scale { offsets } => Some(OutEvent::Scale { offsets }),
+            Event::InlineMml(mml) => Some(OutEvent::InlineMml(mml)),
+            Event::ChangeInversionMode(mode) => {
+                self.inversion_mode = mode;
+                None
+            }
+            Event::ChangeOpenHarmonyMode(mode) => {
+                self.open_harmony_mode = mode;
+                None
+            }
+            Event::ChangeBassPlayMode(mode) => {
+                self.bass_play_mode = mode;
+                None
+            }
             Event::OctaveShift {
                 upper_delta,
                 lower_delta,
             } => {
-                octave_offset_upper += upper_delta;
-                octave_offset_lower += lower_delta;
+                self.octave_offset_upper += upper_delta;
+                self.octave_offset_lower += lower_delta;
+                None
             }
             Event::ChangeSlashChordMode(_) => {
                 return Err(anyhow!(
@@ -108,10 +172,26 @@ pub(crate) fn ast_to_notes(events: Vec<Event>) -> Result<Vec<OutEvent>> {
                     "SlashChord must be resolved by ast2ast before ast2notes"
                 ));
             }
-        }
+        };
+        Ok(out)
     }
+}
 
-    Ok(result)
+/// Spell a lower + upper voicing: the first `lower_len` notes from the
+/// lower root, the rest from the upper root (None if either letter is
+/// unknown).
+fn slash_letters(notes: &[i32], lower_len: usize, slash: &SlashChordEvent) -> Option<Vec<u8>> {
+    let lower_letter = slash.lower_root_letter?;
+    let upper_letter = slash.upper_root_letter?;
+    let (lower, upper) = notes.split_at(lower_len.min(notes.len()));
+    let mut letters = spell_chord(lower, slash.lower_root, lower_letter, &slash.lower_quality);
+    letters.extend(spell_chord(
+        upper,
+        slash.upper_root,
+        upper_letter,
+        &slash.upper_quality,
+    ));
+    Some(letters)
 }
 
 /// Port of getNotesByChord.
@@ -198,7 +278,8 @@ fn get_notes_by_inversion_chord(
 }
 
 /// Port of getNotesByPolychord: two full chords stacked, the lower
-/// structure shifted down an octave.
+/// structure shifted down an octave. Also returns how many of the notes
+/// belong to the lower chord.
 #[allow(clippy::too_many_arguments)]
 fn get_notes_by_polychord(
     upper_root: i32,
@@ -209,7 +290,7 @@ fn get_notes_by_polychord(
     lower_inversion: &str,
     octave_offset_upper: i32,
     octave_offset_lower: i32,
-) -> Result<Vec<i32>> {
+) -> Result<(Vec<i32>, usize)> {
     let mut upper_notes = get_notes_without_omit(upper_root, upper_quality)?;
     let mut lower_notes = get_notes_without_omit(lower_root, lower_quality)?;
 
@@ -219,6 +300,7 @@ fn get_notes_by_polychord(
     key_shift_upper_notes(&mut upper_notes, &lower_notes);
     apply_omit(&mut upper_notes, upper_quality, upper_root);
     apply_omit(&mut lower_notes, lower_quality, lower_root);
+    let lower_len = lower_notes.len();
 
     let mut notes = concat_lower_and_upper(
         upper_notes,
@@ -230,7 +312,7 @@ fn get_notes_by_polychord(
     // Shift the register down to make room for the lower structure
     key_shift_notes(&mut notes, -12);
 
-    Ok(notes)
+    Ok((notes, lower_len))
 }
 
 /// Port of inversionAndOpenHarmony.
@@ -340,6 +422,14 @@ fn adjust_notes_octave(notes: &mut [i32]) {
     }
 }
 
+/// The pitch classes of `quality` above its root, as written (omits
+/// applied, before inversion and voicing), for harmonic analysis.
+pub(crate) fn chord_intervals(quality: &str) -> Result<Vec<i32>> {
+    let mut notes = get_notes_without_omit(0, quality)?;
+    apply_omit(&mut notes, quality, 0);
+    Ok(notes.into_iter().map(|n| n.rem_euclid(12)).collect())
+}
+
 /// Port of getNotesWithoutOmit: base quality → intervals, then add /
 /// altered-fifth modifiers, then shift to the root.
 fn get_notes_without_omit(root: i32, quality: &str) -> Result<Vec<i32>> {
@@ -361,6 +451,9 @@ fn get_notes_without_omit(root: i32, quality: &str) -> Result<Vec<i32>> {
         "9" => vec![0, 4, 7, 10, 14],
         "11" => vec![0, 4, 7, 10, 14, 17],
         "13" => vec![0, 4, 7, 10, 14, 17, 21],
+        // Rust-version qualities, produced by diatonic-quality inference
+        "dim7" => vec![0, 3, 6, 9],
+        "minmaj7" => vec![0, 3, 7, 11],
         other => {
             // Quartal harmony: "4.N" stacks N notes in fourths.
             // Only the first digit counts (JS: parseInt(q[0][2])).
@@ -383,6 +476,9 @@ fn get_notes_without_omit(root: i32, quality: &str) -> Result<Vec<i32>> {
             "add4" => add_note(&mut notes, 5),
             "add11" => add_note(&mut notes, 5 + 12),
             "add6" => add_note(&mut notes, 9),
+            // Rust-version: the major seventh of a minor-major seventh
+            // chord (`Cmadd7`, the preprocess form of `Cm(maj7)`)
+            "add7" => add_note(&mut notes, 11),
             "add13" => add_note(&mut notes, 9 + 12),
             "flatted fifth" => notes.iter_mut().for_each(|n| {
                 if *n == 7 {
@@ -394,6 +490,12 @@ fn get_notes_without_omit(root: i32, quality: &str) -> Result<Vec<i32>> {
                     *n = 8
                 }
             }),
+            // Rust-version altered tensions take the place of the natural
+            // one (the ninth of a 9 chord) or are added
+            "flatted ninth" => alter_tension(&mut notes, 14, 13),
+            "augmented ninth" => alter_tension(&mut notes, 14, 15),
+            "augmented eleventh" => alter_tension(&mut notes, 17, 18),
+            "flatted thirteenth" => alter_tension(&mut notes, 21, 20),
             _ => {}
         }
     }
@@ -426,6 +528,11 @@ fn add_note(notes: &mut Vec<i32>, n: i32) {
     }
 }
 
+fn alter_tension(notes: &mut Vec<i32>, natural: i32, altered: i32) {
+    notes.retain(|&n| n != natural);
+    add_note(notes, altered);
+}
+
 fn key_shift_notes(notes: &mut [i32], v: i32) {
     for note in notes.iter_mut() {
         *note += v;
@@ -486,10 +593,12 @@ mod tests {
     fn chord(root: i32, quality: &str) -> Event {
         Event::Chord(ChordEvent {
             root,
+            root_letter: None,
             quality: quality.to_string(),
             inversion: None,
             octave_offset: 0,
             note_length: Some(1),
+            span: None,
         })
     }
 
@@ -501,14 +610,17 @@ mod tests {
     ) -> SlashChordEvent {
         SlashChordEvent {
             upper_root,
+            upper_root_letter: None,
             upper_quality: upper_quality.to_string(),
             upper_inversion: None,
             lower_root,
+            lower_root_letter: None,
             lower_quality: lower_quality.to_string(),
             lower_inversion: None,
             upper_octave_offset: 0,
             lower_octave_offset: 0,
             note_length: Some(1),
+            span: None,
         }
     }
 
@@ -537,6 +649,19 @@ mod tests {
         assert_eq!(*notes_of(&result[0]), vec![7, 11, 14]);
     }
 
+    #[test]
+    fn test_altered_tensions() {
+        let result = ast_to_notes(vec![
+            chord(4, "7,augmented ninth"),
+            chord(7, "9,flatted ninth,augmented eleventh"),
+            chord(0, "13,flatted thirteenth"),
+        ])
+        .unwrap();
+        assert_eq!(*notes_of(&result[0]), vec![4, 8, 11, 14, 19]);
+        assert_eq!(*notes_of(&result[1]), vec![7, 11, 14, 17, 20, 25]);
+        assert_eq!(*notes_of(&result[2]), vec![0, 4, 7, 10, 14, 17, 20]);
+    }
+
     #[test]
     fn test_chord_over_bass_note() {
         // JS: F/C → [-12+0, -12+5, -12+9, -12+12]
@@ -585,10 +710,12 @@ mod tests {
             Event::ChangeInversionMode("1st inv".to_string()),
             Event::Chord(ChordEvent {
                 root: 0,
+                root_letter: None,
                 quality: "maj".to_string(),
                 inversion: Some("root inv".to_string()),
                 octave_offset: 0,
                 note_length: Some(1),
+                span: None,
             }),
         ])
         .unwrap();
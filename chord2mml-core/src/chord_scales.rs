@@ -0,0 +1,348 @@
+//! Chord-scale suggestions (Rust-version extension).
+//!
+//! For each chord, the scales to improvise over it, best first. A chord in
+//! the key takes the mode of the key's scale on its root (`Dm7` in C is
+//! dorian, `Fmaj7` lydian); a chord outside it, what its [`analyze`] role
+//! implies (a secondary dominant of a minor chord is phrygian dominant, a
+//! tritone substitution lydian dominant, a borrowed chord the mode of the
+//! parallel key). The usual scales of the chord's type follow, or lead for
+//! a dominant with an altered fifth. Every scale holds all the chord's
+//! notes, altered tensions included: `Cmaj7(#11)` is lydian even as I
+//! and `G7(b9)(#9)` half-whole even as V.
+
+use serde::Serialize;
+
+use crate::analysis::{
+    analyze, chord_of, Key, Role, HARMONIC_MINOR, MAJOR, MELODIC_MINOR, NATURAL_MINOR,
+};
+use crate::ast::{Event, NotesEvent, OutEvent, Span};
+use crate::ast2notes::chord_intervals;
+
+/// Chord scales by name, as offsets from the root like `scale=` takes
+/// them (the names `scale=` knows have the same offsets).
+const SCALES: &[(&str, &[i32])] = &[
+    ("ionian", &[0, 2, 4, 5, 7, 9, 11]),
+    ("dorian", &[0, 2, 3, 5, 7, 9, 10]),
+    ("phrygian", &[0, 1, 3, 5, 7, 8, 10]),
+    ("lydian", &[0, 2, 4, 6, 7, 9, 11]),
+    ("mixolydian", &[0, 2, 4, 5, 7, 9, 10]),
+    ("aeolian", &[0, 2, 3, 5, 7, 8, 10]),
+    ("locrian", &[0, 1, 3, 5, 6, 8, 10]),
+    ("harmonicminor", &[0, 2, 3, 5, 7, 8, 11]),
+    ("melodicminor", &[0, 2, 3, 5, 7, 9, 11]),
+    ("harmonicmajor", &[0, 2, 4, 5, 7, 8, 11]),
+    ("phrygiandominant", &[0, 1, 4, 5, 7, 8, 10]),
+    ("lydiandominant", &[0, 2, 4, 6, 7, 9, 10]),
+    ("lydianaugmented", &[0, 2, 4, 6, 8, 9, 11]),
+    ("locriannatural2", &[0, 2, 3, 5, 6, 8, 10]),
+    ("altered", &[0, 1, 3, 4, 6, 8, 10]),
+    ("wholetone", &[0, 2, 4, 6, 8, 10]),
+    ("wholehalf", &[0, 2, 3, 5, 6, 8, 9, 11]),
+    ("halfwhole", &[0, 1, 3, 4, 6, 7, 9, 10]),
+    ("majorpentatonic", &[0, 2, 4, 7, 9]),
+    ("minorpentatonic", &[0, 3, 5, 7, 10]),
+    ("blues", &[0, 3, 5, 6, 7, 10]),
+];
+
+/// Length of each note of a scale run (an eighth note).
+const RUN_NOTE_LENGTH: u32 = 8;
+
+/// A scale: its name and its offsets from the chord root.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ChordScale {
+    pub name: &'static str,
+    pub offsets: Vec<i32>,
+}
+
+/// The scales of one chord.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ChordScales {
+    /// Where the chord was written in the input
+    pub span: Option<Span>,
+    /// Root in semitones above C (0-11)
+    pub root: i32,
+    /// Best first; empty when no scale here holds the chord
+    pub scales: Vec<ChordScale>,
+}
+
+/// The chord scales of each chord of `events` (e.g. `Conversion::events`)
+/// in `key`, as for [`analyze`].
+///
+/// # Example
+/// ```
+/// use chord2mml_core::{chord_scales, Converter};
+///
+/// let conversion = Converter::new().unwrap().convert_explained("Dm7 G7alt Cmaj7").unwrap();
+/// let scales = chord_scales(&conversion.events, None);
+/// let names: Vec<_> = scales.iter().map(|chord| chord.scales[0].name).collect();
+/// assert_eq!(names, ["dorian", "altered", "ionian"]);
+/// assert_eq!(scales[0].scales[0].offsets, [0, 2, 3, 5, 7, 9, 10]);
+/// ```
+pub fn chord_scales(events: &[Event], key: Option<Key>) -> Vec<ChordScales> {
+    let analysis = analyze(events, key);
+    events
+        .iter()
+        .filter_map(chord_of)
+        .filter_map(|(root, quality, span)| {
+            // Skipped by analyze too
+            let intervals = chord_intervals(quality).ok()?;
+            Some((root.rem_euclid(12), intervals, span))
+        })
+        .zip(&analysis.chords)
+        .map(|((root, intervals, span), chord)| {
+            let degree = (root - chord.key.tonic).rem_euclid(12);
+            let mut names = match chord.role {
+                Role::Diatonic if chord.key.minor => modes(
+                    &[NATURAL_MINOR, HARMONIC_MINOR, MELODIC_MINOR],
+                    degree,
+                    &intervals,
+                ),
+                Role::Diatonic => modes(&[MAJOR], degree, &intervals),
+                Role::Borrowed if chord.key.minor => modes(&[MAJOR], degree, &intervals),
+                Role::Borrowed => modes(&[NATURAL_MINOR, HARMONIC_MINOR], degree, &intervals),
+                Role::SecondaryDominant if resolves_to_minor(&chord.numeral) => {
+                    vec!["phrygiandominant", "altered"]
+                }
+                Role::SecondaryDominant => vec!["mixolydian"],
+                Role::TritoneSubstitution => vec!["lydiandominant"],
+                Role::SecondaryLeadingTone => vec!["wholehalf"],
+                Role::RelatedTwo | Role::Chromatic => Vec::new(),
+            };
+            // An altered dominant's own scales come before its role's
+            let has = |interval| intervals.contains(&interval);
+            if has(4) && has(10) && !has(7) {
+                names.splice(0..0, scales_of_type(&intervals));
+            } else {
+                names.extend(scales_of_type(&intervals));
+            }
+            let mut scales: Vec<ChordScale> = Vec::new();
+            for (name, offsets) in names.into_iter().filter_map(named) {
+                let holds_chord = intervals.iter().all(|i| offsets.contains(i));
+                if holds_chord && scales.iter().all(|scale| scale.name != name) {
+                    scales.push(ChordScale {
+                        name,
+                        offsets: offsets.to_vec(),
+                    });
+                }
+            }
+            ChordScales { span, root, scales }
+        })
+        .collect()
+}
+
+/// `note_events` with each chord of `scales` followed by a run up its
+/// first scale to the octave, in eighth notes from the root in octave 4.
+pub(crate) fn with_scale_runs(note_events: &[OutEvent], scales: &[ChordScales]) -> Vec<OutEvent> {
+    let mut events = Vec::with_capacity(note_events.len());
+    for event in note_events {
+        events.push(event.clone());
+        let OutEvent::Notes(notes_event) = event else {
+            continue;
+        };
+        let Some(chord) = notes_event
+            .span
+            .and_then(|span| scales.iter().find(|chord| chord.span == Some(span)))
+        else {
+            continue;
+        };
+        let Some(scale) = chord.scales.first() else {
+            continue;
+        };
+        for offset in scale.offsets.iter().chain([&12]) {
+            events.push(OutEvent::Notes(NotesEvent {
+                notes: vec![chord.root + offset],
+                letters: None,
+                note_length: Some(RUN_NOTE_LENGTH),
+                span: None,
+            }));
+        }
+    }
+    events
+}
+
+/// The names of the modes on `degree` of the first of `parents` that holds
+/// the chord (`intervals` above `degree`).
+fn modes(parents: &[[i32; 7]], degree: i32, intervals: &[i32]) -> Vec<&'static str> {
+    let parent = parents.iter().find(|parent| {
+        intervals
+            .iter()
+            .all(|i| parent.contains(&((degree + i) % 12)))
+    });
+    let Some(parent) = parent else {
+        return Vec::new();
+    };
+    let mut mode: Vec<i32> = parent.iter().map(|t| (t - degree).rem_euclid(12)).collect();
+    mode.sort_unstable();
+    SCALES
+        .iter()
+        .filter(|(_, offsets)| *offsets == mode.as_slice())
+        .map(|(name, _)| *name)
+        .collect()
+}
+
+/// Whether a secondary dominant's numeral (`V7/ii`) targets a minor chord.
+fn resolves_to_minor(numeral: &str) -> bool {
+    numeral.rsplit_once('/').is_some_and(|(_, target)| {
+        target
+            .trim_start_matches(['b', '#'])
+            .starts_with(|c: char| c.is_lowercase())
+    })
+}
+
+/// The usual scales of a chord with `intervals`, whatever its key.
+fn scales_of_type(intervals: &[i32]) -> Vec<&'static str> {
+    let has = |interval| intervals.contains(&interval);
+    let scales: &[&str] = if has(4) {
+        if has(8) && !has(7) {
+            if has(10) {
+                &["altered", "wholetone"]
+            } else if has(11) {
+                &["lydianaugmented"]
+            } else {
+                &["wholetone", "lydianaugmented"]
+            }
+        } else if has(10) {
+            if has(6) && !has(7) {
+                &["lydiandominant", "wholetone", "altered", "halfwhole"]
+            } else {
+                &[
+                    "mixolydian",
+                    "lydiandominant",
+                    "halfwhole",
+                    "phrygiandominant",
+                ]
+            }
+        } else if has(11) {
+            &["ionian", "lydian"]
+        } else {
+            &["ionian", "lydian", "majorpentatonic", "mixolydian"]
+        }
+    } else if has(3) {
+        if has(6) && !has(7) {
+            if has(10) {
+                &["locriannatural2", "locrian"]
+            } else if has(9) {
+                &["wholehalf"]
+            } else {
+                &["locrian", "wholehalf"]
+            }
+        } else if has(11) {
+            &["melodicminor", "harmonicminor"]
+        } else if has(9) {
+            &["dorian", "melodicminor"]
+        } else {
+            &["dorian", "aeolian", "phrygian", "minorpentatonic", "blues"]
+        }
+    } else if has(10) {
+        &["mixolydian", "dorian"]
+    } else {
+        &["ionian", "mixolydian", "majorpentatonic", "dorian"]
+    };
+    let mut scales = scales.to_vec();
+    if scales.iter().all(|name| named(name).is_none()) {
+        scales = SCALES.iter().map(|(name, _)| *name).collect();
+    }
+    scales
+}
+
+fn named(name: &str) -> Option<(&'static str, &'static [i32])> {
+    SCALES.iter().find(|(n, _)| *n == name).copied()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cst_to_ast::parse_scale_offsets;
+    use crate::Converter;
+
+    fn first_scales(input: &str, key: Option<Key>) -> Vec<&'static str> {
+        let conversion = Converter::new().unwrap().convert_explained(input).unwrap();
+        chord_scales(&conversion.events, key)
+            .iter()
+            .map(|chord| chord.scales.first().map_or("-", |scale| scale.name))
+            .collect()
+    }
+
+    #[test]
+    fn test_scales_match_scale_directives() {
+        for (name, offsets) in SCALES {
+            if let Ok(parsed) = parse_scale_offsets(name) {
+                assert_eq!(parsed, *offsets, "{}", name);
+            }
+        }
+    }
+
+    #[test]
+    fn test_diatonic_modes() {
+        assert_eq!(
+            first_scales("Cmaj7 Dm7 Em7 Fmaj7 G7 Am7 Bm7(b5)", None),
+            [
+                "ionian",
+                "dorian",
+                "phrygian",
+                "lydian",
+                "mixolydian",
+                "aeolian",
+                "locrian"
+            ]
+        );
+        assert_eq!(
+            first_scales("Am7 Bm7(b5) E7 AmM7", Some(Key::minor(9))),
+            ["aeolian", "locrian", "phrygiandominant", "harmonicminor"]
+        );
+        assert_eq!(
+            first_scales("Cmaj7(#11) G7(b9) G7(b9)(#9) Cmaj7", Some(Key::major(0))),
+            ["lydian", "phrygiandominant", "halfwhole", "ionian"]
+        );
+    }
+
+    #[test]
+    fn test_chromatic_chords() {
+        assert_eq!(
+            first_scales("C E7 Am D7 G7 Db7 C Fm Bb7 G7alt Caug Cdim(add6)", None),
+            [
+                "ionian",
+                "phrygiandominant",
+                "aeolian",
+                "mixolydian",
+                "mixolydian",
+                "lydiandominant",
+                "ionian",
+                "dorian",
+                "mixolydian",
+                "altered",
+                "wholetone",
+                "wholehalf",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_scales_hold_the_chord() {
+        let conversion = Converter::new()
+            .unwrap()
+            .convert_explained("C7 Cm6 Csus4 C7(b5) CmM7 C6 C9")
+            .unwrap();
+        for chord in chord_scales(&conversion.events, None) {
+            assert!(!chord.scales.is_empty());
+            for scale in &chord.scales {
+                assert_eq!(scale.offsets, named(scale.name).unwrap().1);
+            }
+        }
+    }
+
+    #[test]
+    fn test_scale_runs() {
+        let conversion = Converter::new().unwrap().convert_explained("Dm7").unwrap();
+        let scales = chord_scales(&conversion.events, None);
+        let events = with_scale_runs(&conversion.out_events, &scales);
+        let run: Vec<i32> = events[1..]
+            .iter()
+            .map(|event| match event {
+                OutEvent::Notes(notes_event) => notes_event.notes[0],
+                _ => panic!("expected notes"),
+            })
+            .collect();
+        assert_eq!(run, [2, 4, 5, 7, 9, 11, 12, 14]);
+    }
+}
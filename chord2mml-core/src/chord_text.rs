@@ -0,0 +1,175 @@
+//! Chord text from parsed events (Rust-version extension).
+//!
+//! Writes events back as notation the parser reads as the same events:
+//! the root from its letter as written (with flats when unknown), the
+//! quality as base + `(modifier)`s (`min7,flatted fifth` → `m7(b5)`,
+//! `maj7,add9` → `maj7(add9)`), then `^N` and the octave marks. For chords
+//! that were never written, e.g. those [`crate::reharmonize`] inserts.
+
+use crate::analysis::{MAJOR_NAMES, MINOR_NAMES};
+use crate::ast::Event;
+
+const LETTERS: [char; 7] = ['C', 'D', 'E', 'F', 'G', 'A', 'B'];
+/// Semitones of each natural letter above C.
+pub(crate) const NATURALS: [i32; 7] = [0, 2, 4, 5, 7, 9, 11];
+const FLAT_NAMES: [&str; 12] = [
+    "C", "Db", "D", "Eb", "E", "F", "Gb", "G", "Ab", "A", "Bb", "B",
+];
+
+/// The notation of a chord event, e.g. `Dbm7(b5)` or `C/E`; None for
+/// other events.
+pub fn chord_symbol(event: &Event) -> Option<String> {
+    match event {
+        Event::Chord(chord) => Some(symbol(
+            chord.root,
+            chord.root_letter,
+            &chord.quality,
+            chord.inversion.as_deref(),
+            chord.octave_offset,
+        )),
+        Event::SlashChord(slash)
+        | Event::ChordOverBassNote(slash)
+        | Event::Inversion(slash)
+        | Event::Polychord(slash) => Some(format!(
+            "{}/{}",
+            symbol(
+                slash.upper_root,
+                slash.upper_root_letter,
+                &slash.upper_quality,
+                slash.upper_inversion.as_deref(),
+                slash.upper_octave_offset,
+            ),
+            symbol(
+                slash.lower_root,
+                slash.lower_root_letter,
+                &slash.lower_quality,
+                slash.lower_inversion.as_deref(),
+                slash.lower_octave_offset,
+            )
+        )),
+        _ => None,
+    }
+}
+
+/// The chords, bars and keys of `events` as one line of notation, e.g.
+/// `key=F Gm7 C7 | F`. Other directives and inline MML are left out.
+///
+/// # Example
+/// ```
+/// use chord2mml_core::{chord_text, Converter};
+///
+/// let conversion = Converter::new().unwrap().convert_explained("Dm7-G7-CM7").unwrap();
+/// assert_eq!(chord_text(&conversion.events), "Dm7 G7 Cmaj7");
+/// ```
+pub fn chord_text(events: &[Event]) -> String {
+    let words: Vec<String> = events
+        .iter()
+        .filter_map(|event| match event {
+            Event::Bar => Some("|".to_string()),
+            Event::BarSlash => Some("/".to_string()),
+            Event::Key { offset, minor } => {
+                let tonic = offset.rem_euclid(12) as usize;
+                Some(if *minor {
+                    format!("key={}m", MINOR_NAMES[tonic])
+                } else {
+                    format!("key={}", MAJOR_NAMES[tonic])
+                })
+            }
+            _ => chord_symbol(event),
+        })
+        .collect();
+    words.join(" ")
+}
+
+fn symbol(
+    root: i32,
+    root_letter: Option<u8>,
+    quality: &str,
+    inversion: Option<&str>,
+    octave_offset: i32,
+) -> String {
+    let mut text = root_name(root, root_letter);
+    let mut parts = quality.split(',');
+    text.push_str(match parts.next().unwrap_or_default() {
+        "maj" => "",
+        "min" => "m",
+        "min7" => "m7",
+        "dim triad" => "dim",
+        // No notation of their own: the same notes as `dim(add6)` and
+        // `m(add7)`
+        "dim7" => "dim(add6)",
+        "minmaj7" => "m(add7)",
+        base => base,
+    });
+    for part in parts {
+        match part {
+            "flatted fifth" => text.push_str("(b5)"),
+            "augmented fifth" => text.push_str("(#5)"),
+            "flatted ninth" => text.push_str("(b9)"),
+            "augmented ninth" => text.push_str("(#9)"),
+            "augmented eleventh" => text.push_str("(#11)"),
+            "flatted thirteenth" => text.push_str("(b13)"),
+            modifier => text.push_str(&format!("({})", modifier)),
+        }
+    }
+    match inversion {
+        Some("root inv") => text.push_str("^0"),
+        Some("1st inv") => text.push_str("^1"),
+        Some("2nd inv") => text.push_str("^2"),
+        Some("3rd inv") => text.push_str("^3"),
+        _ => {}
+    }
+    if octave_offset > 0 {
+        text.push_str(&"'".repeat(octave_offset as usize));
+    } else {
+        text.push_str(&",".repeat(octave_offset.unsigned_abs() as usize));
+    }
+    text
+}
+
+/// `root` spelled from `letter`, e.g. 1 from D is `Db`.
+fn root_name(root: i32, letter: Option<u8>) -> String {
+    match letter {
+        Some(letter) => {
+            let letter = letter as usize % 7;
+            let alter = (root - NATURALS[letter] + 6).rem_euclid(12) - 6;
+            let accidental = if alter > 0 { "#" } else { "b" };
+            format!(
+                "{}{}",
+                LETTERS[letter],
+                accidental.repeat(alter.unsigned_abs() as usize)
+            )
+        }
+        None => FLAT_NAMES[root.rem_euclid(12) as usize].to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Converter;
+
+    #[test]
+    fn test_chord_text_round_trips() {
+        let mut converter = Converter::new().unwrap();
+        for input in [
+            "key=Ebm Cm7(b5) F7(#5) | Bbm(add7) / Ebm(add9)(omit5)",
+            "C^1' Dbmaj7(add9),, E#m C/E Dm/G7 Cdim(add6) Csus4 C4.3",
+            "key=F#m Fb Cb9 G13",
+            "E7(#9) G7(b9)(#11) Cmaj7(#11) Ab13(b13)",
+        ] {
+            let events = converter.convert_explained(input).unwrap().events;
+            let text = chord_text(&events);
+            assert_eq!(text, input);
+            let again = converter.convert_explained(&text).unwrap().events;
+            assert_eq!(chord_text(&again), text);
+        }
+    }
+
+    #[test]
+    fn test_unknown_letters_are_flats() {
+        assert_eq!(root_name(6, None), "Gb");
+        assert_eq!(root_name(1, Some(0)), "C#");
+        assert_eq!(root_name(-1, Some(0)), "Cb");
+    }
+}
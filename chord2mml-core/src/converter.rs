@@ -0,0 +1,630 @@
+//! Reusable converters.
+//!
+//! The free functions ([`crate::convert`] and friends) build a fresh
+//! parser per call. A [`Converter`] keeps one parser (with the grammar
+//! already loaded) across calls and reuses it for every preprocess
+//! candidate, which is what batch conversion spends its time on. A
+//! [`ConverterPool`] shares converters between threads.
+//!
+//! Converters parse with tree-sitter when the `tree-sitter` feature is on
+//! and with the pure-Rust parser otherwise (WASM builds);
+//! [`Converter::pure_rust`] picks the latter explicitly.
+
+use anyhow::{anyhow, Result};
+use std::sync::Mutex;
+
+use crate::ast::{Event, InputError, LineError, Span};
+use crate::cst_to_ast::{cst_to_ast_with_context, DegreeContext, KeyState};
+use crate::options::ConvertOptions;
+#[cfg(feature = "tree-sitter")]
+use crate::parser::ChordParser;
+use crate::preprocess::Rewrite;
+use crate::pure_parser;
+use crate::transpose;
+use crate::{events_to_output, preprocess, Conversion};
+
+/// A parser and options, reused across conversions.
+///
+/// # Example
+/// ```
+/// use chord2mml_core::Converter;
+///
+/// let mut converter = Converter::new().unwrap();
+/// for input in ["C", "Dm G7 C"] {
+///     println!("{}", converter.convert(input).unwrap());
+/// }
+/// assert_eq!(converter.convert("C").unwrap(), "v11'c1eg'");
+/// ```
+pub struct Converter {
+    parser: Backend,
+    options: ConvertOptions,
+}
+
+impl Converter {
+    /// A converter with the default (JS-compatible) options.
+    pub fn new() -> Result<Self> {
+        Self::with_options(ConvertOptions::default())
+    }
+
+    #[cfg(feature = "tree-sitter")]
+    pub fn with_options(options: ConvertOptions) -> Result<Self> {
+        Ok(Converter {
+            parser: Backend::TreeSitter(ChordParser::new()?),
+            options,
+        })
+    }
+
+    #[cfg(not(feature = "tree-sitter"))]
+    pub fn with_options(options: ConvertOptions) -> Result<Self> {
+        Ok(Self::pure_rust(options))
+    }
+
+    /// A converter using the pure-Rust parser, which builds the same tree
+    /// as tree-sitter without the C dependency (the parser of WASM builds).
+    pub fn pure_rust(options: ConvertOptions) -> Self {
+        Converter {
+            parser: Backend::PureRust,
+            options,
+        }
+    }
+
+    pub fn options(&self) -> &ConvertOptions {
+        &self.options
+    }
+
+    /// See [`crate::convert_with_options`].
+    pub fn convert(&mut self, input: &str) -> Result<String> {
+        self.convert_explained(input)
+            .map(|conversion| conversion.mml)
+    }
+
+    /// See [`crate::convert_explained`].
+    pub fn convert_explained(&mut self, input: &str) -> Result<Conversion> {
+        self.convert_from(input, &LineState::default())
+            .map(|(conversion, _)| conversion)
+    }
+
+    /// Convert one line of an interactive or incremental input, starting
+    /// from the state the lines before it left in `state` (key, scale,
+    /// slash-chord, inversion, open-harmony and bass modes, octave shifts)
+    /// and updating it. A line that does not convert leaves `state` as it
+    /// was.
+    ///
+    /// # Example
+    /// ```
+    /// use chord2mml_core::{Converter, LineState};
+    ///
+    /// let mut converter = Converter::new().unwrap();
+    /// let mut state = LineState::default();
+    /// converter.convert_line("key=D octave up", &mut state).unwrap();
+    /// let conversion = converter.convert_line("I", &mut state).unwrap();
+    /// assert_eq!(conversion.mml, "v11'<d1f+a'");
+    /// ```
+    pub fn convert_line(&mut self, input: &str, state: &mut LineState) -> Result<Conversion> {
+        let (conversion, next) = self.convert_from(input, state)?;
+        *state = next;
+        Ok(conversion)
+    }
+
+    /// Convert `input` after the lines that left `state`, returning the
+    /// state after it.
+    fn convert_from(&mut self, input: &str, state: &LineState) -> Result<(Conversion, LineState)> {
+        let leading = input.len() - input.trim_start().len();
+        let input = input.trim();
+
+        if input.is_empty() {
+            return Err(empty_input_error());
+        }
+        check_input_len(input, &self.options)?;
+
+        // The directives of earlier lines were transposed as they were
+        // then; move them to the current transposition
+        let mut directives = state.directives.clone();
+        transpose::transpose(&mut directives, self.options.transpose - state.transpose);
+
+        // Try the dialect readings in order (the input as written first,
+        // after any eager respelling; JS chord2mml.parse's contract); if
+        // nothing converts, report the error of the input as written.
+        let mut written_error = None;
+        for rewrite in preprocess::preprocess_rewrites(input) {
+            let mut context = DegreeContext::resume(&self.options, state.key_state.clone());
+            let converted = self
+                .parser
+                .parse(&rewrite.text, &mut context)
+                .and_then(|events| {
+                    let mut all = directives.clone();
+                    all.extend(events);
+                    let mut output = events_to_output(all, &self.options)?;
+                    // The events of this line, as reharmonization left them
+                    // (it keeps the directives before the first chord)
+                    output.events = output.events.split_off(directives.len());
+                    Ok(output)
+                });
+            match converted {
+                Ok(output) => {
+                    let mut next = LineState {
+                        key_state: context.key_state(),
+                        directives,
+                        transpose: self.options.transpose,
+                    };
+                    next.directives.extend(
+                        output
+                            .events
+                            .iter()
+                            .filter(|event| is_directive(event))
+                            .cloned(),
+                    );
+                    return Ok((Conversion::new(output, rewrite), next));
+                }
+                Err(e) => {
+                    if rewrite.rules.is_empty() {
+                        written_error = Some(e);
+                    }
+                }
+            }
+        }
+        let mut error = written_error.expect("the input as written is a candidate");
+        if let Some(input_error) = error.downcast_mut::<InputError>() {
+            input_error.span = Span {
+                start: input_error.span.start + leading,
+                end: input_error.span.end + leading,
+            };
+        }
+        Err(error)
+    }
+
+    /// See [`crate::convert_lines`].
+    pub fn convert_lines(&mut self, input: &str) -> Result<String> {
+        self.convert_lines_explained(input)
+            .map(|conversion| conversion.mml)
+    }
+
+    /// [`crate::convert_lines`], also reporting how the lines were read:
+    /// `rewrite.text` is the reading of each non-blank line, one per line,
+    /// and the spans are in it; `rewrite.rules` are the rules any line
+    /// needed. Errors on a line are [`LineError`]s.
+    pub fn convert_lines_explained(&mut self, input: &str) -> Result<Conversion> {
+        let mut context = DegreeContext::new(&self.options);
+        let mut events = Vec::new();
+        let mut reading = Rewrite {
+            text: String::new(),
+            rules: Vec::new(),
+        };
+        for (index, line) in input.lines().enumerate() {
+            let leading = line.len() - line.trim_start().len();
+            let line = line.trim();
+            if line.is_empty() {
+                continue;
+            }
+            let (mut line_events, rewrite) = check_input_len(line, &self.options)
+                .and_then(|()| parse_line(&mut self.parser, line, &mut context))
+                .map_err(|e| line_error(index + 1, e, leading))?;
+            if !reading.text.is_empty() {
+                reading.text.push('\n');
+            }
+            shift_spans(&mut line_events, reading.text.len());
+            events.extend(line_events);
+            reading.text.push_str(&rewrite.text);
+            for rule in rewrite.rules {
+                if !reading.rules.contains(&rule) {
+                    reading.rules.push(rule);
+                }
+            }
+        }
+
+        if events.is_empty() {
+            return Err(empty_input_error());
+        }
+        Ok(Conversion::new(
+            events_to_output(events, &self.options)?,
+            reading,
+        ))
+    }
+
+    /// See [`crate::convert_chart`].
+    pub fn convert_chart(&mut self, text: &str, line_bars: bool) -> Result<String> {
+        self.convert_chart_explained(text, line_bars)
+            .map(|conversion| conversion.mml)
+    }
+
+    /// [`crate::convert_chart`], also reporting how the chart was read:
+    /// `rewrite.text` is the reading of the chords taken out of it (the
+    /// spans are in it), with `chord-chart` first in `rewrite.rules`.
+    pub fn convert_chart_explained(&mut self, text: &str, line_bars: bool) -> Result<Conversion> {
+        let chords = preprocess::extract_chord_chart(text, line_bars).ok_or_else(|| {
+            anyhow!("Not a song chart: no [chord] brackets and no chord line next to a lyric line")
+        })?;
+        // The error spans index the chords, not the chart, so they are
+        // dropped and the chords quoted instead
+        let mut conversion = self
+            .convert_explained(&chords)
+            .map_err(|e| anyhow!("{} (chords of the chart: {})", e, chords))?;
+        if !conversion.rewrite.rules.contains(&"chord-chart") {
+            conversion.rewrite.rules.insert(0, "chord-chart");
+        }
+        Ok(conversion)
+    }
+
+    /// See [`crate::preprocess_chord`].
+    pub fn preprocess_chord(&mut self, input: &str) -> String {
+        for rewrite in preprocess::preprocess_rewrites(input) {
+            let mut context = DegreeContext::new(&self.options);
+            if !rewrite.rules.is_empty() && self.parser.parse(&rewrite.text, &mut context).is_ok() {
+                return rewrite.text;
+            }
+        }
+        input.to_string()
+    }
+}
+
+/// What [`Converter::convert_line`] carries from one line to the next.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LineState {
+    /// The key and scale degrees are read in
+    key_state: KeyState,
+    /// The directives of the lines so far, replayed before each line
+    directives: Vec<Event>,
+    /// The transposition `directives` were converted with
+    transpose: i32,
+}
+
+impl LineState {
+    /// The directives in effect (key, scale and mode changes, octave
+    /// shifts), in the order they were given.
+    pub fn directives(&self) -> &[Event] {
+        &self.directives
+    }
+}
+
+/// Whether an event changes the state later chords are converted in.
+fn is_directive(event: &Event) -> bool {
+    matches!(
+        event,
+        Event::Key { .. }
+            | Event::Scale { .. }
+            | Event::ChangeSlashChordMode(_)
+            | Event::ChangeInversionMode(_)
+            | Event::ChangeOpenHarmonyMode(_)
+            | Event::ChangeBassPlayMode(_)
+            | Event::OctaveShift { .. }
+    )
+}
+
+/// The parser behind a [`Converter`].
+enum Backend {
+    #[cfg(feature = "tree-sitter")]
+    TreeSitter(ChordParser),
+    PureRust,
+}
+
+impl Backend {
+    /// Parse one input into events, starting from (and updating) the key
+    /// state in `context`.
+    fn parse(&mut self, input: &str, context: &mut DegreeContext) -> Result<Vec<Event>> {
+        match self {
+            #[cfg(feature = "tree-sitter")]
+            Backend::TreeSitter(parser) => parser.parse(input, context),
+            Backend::PureRust => cst_to_ast_with_context(&pure_parser::parse_cst(input)?, context),
+        }
+    }
+}
+
+/// Parse one line of [`Converter::convert_lines`]: the first dialect
+/// reading that parses wins, and only its key changes are kept in
+/// `context`; when none does, the error of the line as written.
+fn parse_line(
+    parser: &mut Backend,
+    line: &str,
+    context: &mut DegreeContext,
+) -> Result<(Vec<Event>, Rewrite)> {
+    let mut written_error = None;
+    for rewrite in preprocess::preprocess_rewrites(line) {
+        let mut attempt = context.clone();
+        match parser.parse(&rewrite.text, &mut attempt) {
+            Ok(events) => {
+                *context = attempt;
+                return Ok((events, rewrite));
+            }
+            Err(e) => {
+                if rewrite.rules.is_empty() {
+                    written_error = Some(e);
+                }
+            }
+        }
+    }
+    Err(written_error.expect("the line as written is a candidate"))
+}
+
+/// `error` on line `line`, whose span (if any) indexes the line after
+/// `leading` bytes of whitespace.
+fn line_error(line: usize, error: anyhow::Error, leading: usize) -> anyhow::Error {
+    let span = error.downcast_ref::<InputError>().map(|e| Span {
+        start: e.span.start + leading,
+        end: e.span.end + leading,
+    });
+    LineError {
+        line,
+        message: error.to_string(),
+        span,
+    }
+    .into()
+}
+
+/// Move the spans of `events` `by` bytes on.
+fn shift_spans(events: &mut [Event], by: usize) {
+    for event in events {
+        let span = match event {
+            Event::Chord(chord) => &mut chord.span,
+            Event::SlashChord(slash)
+            | Event::ChordOverBassNote(slash)
+            | Event::Inversion(slash)
+            | Event::Polychord(slash) => &mut slash.span,
+            _ => continue,
+        };
+        if let Some(span) = span {
+            span.start += by;
+            span.end += by;
+        }
+    }
+}
+
+pub(crate) fn empty_input_error() -> anyhow::Error {
+    anyhow!("Empty input. Please provide a chord notation (e.g., 'C', 'Dm G7 C').")
+}
+
+/// Reject input over `options.max_input_len` characters.
+pub(crate) fn check_input_len(input: &str, options: &ConvertOptions) -> Result<()> {
+    match options.max_input_len {
+        Some(max) if input.chars().count() > max => {
+            Err(anyhow!("Input too long (max {} characters).", max))
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Converters shared between threads: each call checks out an idle
+/// converter (creating one when all are busy) and returns it afterwards,
+/// so a pool grows to the number of threads converting at once.
+///
+/// # Example
+/// ```
+/// use chord2mml_core::{ConvertOptions, ConverterPool};
+///
+/// let pool = ConverterPool::new(ConvertOptions::default());
+/// let inputs = ["C", "F", "G7"];
+/// let outputs: Vec<String> = std::thread::scope(|scope| {
+///     let handles: Vec<_> = inputs
+///         .iter()
+///         .map(|input| scope.spawn(|| pool.convert(input).unwrap()))
+///         .collect();
+///     handles.into_iter().map(|h| h.join().unwrap()).collect()
+/// });
+/// assert_eq!(outputs[0], "v11'c1eg'");
+/// ```
+pub struct ConverterPool {
+    options: ConvertOptions,
+    idle: Mutex<Vec<Converter>>,
+}
+
+impl ConverterPool {
+    pub fn new(options: ConvertOptions) -> Self {
+        ConverterPool {
+            options,
+            idle: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// See [`Converter::convert`].
+    pub fn convert(&self, input: &str) -> Result<String> {
+        self.with_converter(|converter| converter.convert(input))
+    }
+
+    /// See [`Converter::convert_explained`].
+    pub fn convert_explained(&self, input: &str) -> Result<Conversion> {
+        self.with_converter(|converter| converter.convert_explained(input))
+    }
+
+    /// See [`Converter::convert_lines`].
+    pub fn convert_lines(&self, input: &str) -> Result<String> {
+        self.with_converter(|converter| converter.convert_lines(input))
+    }
+
+    /// See [`Converter::convert_lines_explained`].
+    pub fn convert_lines_explained(&self, input: &str) -> Result<Conversion> {
+        self.with_converter(|converter| converter.convert_lines_explained(input))
+    }
+
+    /// See [`Converter::convert_chart_explained`].
+    pub fn convert_chart_explained(&self, text: &str, line_bars: bool) -> Result<Conversion> {
+        self.with_converter(|converter| converter.convert_chart_explained(text, line_bars))
+    }
+
+    /// Run `f` on a checked-out converter.
+    pub fn with_converter<T>(&self, f: impl FnOnce(&mut Converter) -> Result<T>) -> Result<T> {
+        let idle = self.lock_idle().pop();
+        let mut converter = match idle {
+            Some(converter) => converter,
+            None => Converter::with_options(self.options.clone())?,
+        };
+        let result = f(&mut converter);
+        self.lock_idle().push(converter);
+        result
+    }
+
+    fn lock_idle(&self) -> std::sync::MutexGuard<'_, Vec<Converter>> {
+        // A panic while converting cannot leave the list half-updated
+        self.idle
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_converter_reuse_matches_free_functions() {
+        let mut converter = Converter::new().unwrap();
+        for input in ["C", "ii-V-I", "key=D I IV", "Em7-5 A7", "C/E"] {
+            assert_eq!(
+                converter.convert(input).unwrap(),
+                crate::convert(input).unwrap(),
+                "input {input}"
+            );
+        }
+        // A failed candidate does not leave state behind
+        assert!(converter.convert("H").is_err());
+        assert_eq!(converter.convert("C").unwrap(), "v11'c1eg'");
+        // Chord spellings are respelled before the input as written is read
+        for (input, expected) in [
+            ("Cm7b5", "v11'c1d+f+a+'"),
+            ("Cdim7", "v11'c1d+f+a'"),
+            ("Em7-5", "v11'e1ga+<d'"),
+            ("C / F / G", "v11'c1eg''f1a<c''g1b<d'"),
+            ("Cmaj7#11", "v11'c1egb<f+'"),
+            ("Bm7b5 E7 Am", "v11'b1<dfa''e1g+b<d''a1<ce'"),
+        ] {
+            let conversion = converter.convert_explained(input).unwrap();
+            assert_eq!(conversion.mml, expected, "input {input}");
+            assert_eq!(
+                conversion.rewrite.rules,
+                ["chord-spellings"],
+                "input {input}"
+            );
+        }
+        // No bracketed chord is lost: it is respelled or reported
+        assert_eq!(
+            converter.convert("[Am]Hello [G7alt]world [Co7]").unwrap(),
+            "v11'a1<ce''g1b<d+fg+a+''c1d+f+a'"
+        );
+        assert!(converter.convert("[Am]Hello [Xyz]world").is_err());
+        // Rules that only apply after an earlier rewrite are chained
+        for (input, expected) in [
+            ("[ドm]Hello [ソ7]world", "v11'c1d+g''g1b<df'"),
+            ("ii-V7alt-I", "v11'd1fa''g1b<d+fg+a+''c1eg'"),
+        ] {
+            assert_eq!(converter.convert(input).unwrap(), expected, "input {input}");
+        }
+        let conversion = converter.convert_explained("G7alt").unwrap();
+        assert_eq!(conversion.rewrite.text, "G7(#5)(b9)(#9)");
+    }
+
+    #[test]
+    fn test_convert_chart_explained() {
+        let mut converter = Converter::new().unwrap();
+        let song = "{key: G}\nG      Em\nHello there\n[C]again [D7]now";
+        let conversion = converter.convert_chart_explained(song, true).unwrap();
+        assert_eq!(conversion.rewrite.text, "key=G | G Em | C D7");
+        assert_eq!(conversion.rewrite.rules, ["chord-chart"]);
+        assert_eq!(
+            conversion.mml,
+            converter.convert("key=G | G Em | C D7").unwrap()
+        );
+        assert_eq!(
+            converter.convert_chart(song, false).unwrap(),
+            converter.convert("key=G G Em C D7").unwrap()
+        );
+        // Not a chart, and a chart whose chords do not convert
+        let error = converter.convert_chart("C G\nAm F", false).unwrap_err();
+        assert!(error.to_string().starts_with("Not a song chart"), "{error}");
+        let error = converter
+            .convert_chart("[C]Hello [Xyz]world", false)
+            .unwrap_err();
+        assert!(
+            error.to_string().ends_with("(chords of the chart: C Xyz)"),
+            "{error}"
+        );
+    }
+
+    #[test]
+    fn test_convert_lines_explained() {
+        let mut converter = Converter::new().unwrap();
+        let conversion = converter
+            .convert_lines_explained("key=D\n\n  ii-V-I\nEm7(b5) | A7")
+            .unwrap();
+        assert_eq!(
+            conversion.mml,
+            converter
+                .convert_lines("key=D\nii-V-I\nEm7(b5) | A7")
+                .unwrap()
+        );
+        assert_eq!(conversion.rewrite.text, "key=D\nIIm-V-I\nEm7(b5) | A7");
+        assert_eq!(conversion.rewrite.rules, ["minor-roman-numerals"]);
+        let written: Vec<&str> = conversion
+            .source_map
+            .iter()
+            .map(|mapping| &conversion.rewrite.text[mapping.input.start..mapping.input.end])
+            .collect();
+        assert_eq!(written, ["IIm", "V", "I", "Em7(b5)", "A7"]);
+
+        // A line's error keeps its number and its span in the line
+        let error = converter.convert_lines("C\n\n  C $").unwrap_err();
+        let line_error = error.downcast_ref::<LineError>().unwrap();
+        assert_eq!(line_error.line, 3);
+        assert_eq!(line_error.span, Some(Span { start: 4, end: 5 }));
+        assert!(error.to_string().starts_with("Line 3: "), "{error}");
+        let mut short = Converter::with_options(ConvertOptions {
+            max_input_len: Some(3),
+            ..ConvertOptions::default()
+        })
+        .unwrap();
+        // The limit counts characters, not bytes
+        assert!(short.convert_lines("C♯\nD♭").is_ok());
+        let error = short.convert_lines("C\nC F G C").unwrap_err();
+        let line_error = error.downcast_ref::<LineError>().unwrap();
+        assert_eq!((line_error.line, line_error.span), (2, None));
+    }
+
+    #[test]
+    fn test_convert_line_carries_state() {
+        let mut converter = Converter::new().unwrap();
+        let mut state = LineState::default();
+        for line in [
+            "key=Bb",
+            "drop2 octave down",
+            "slash chord inversion 1st inv",
+        ] {
+            converter.convert_line(line, &mut state).unwrap();
+        }
+        assert_eq!(state.directives().len(), 5);
+        let line = converter.convert_line("I7 F/A", &mut state).unwrap();
+        let whole = converter
+            .convert("key=Bb drop2 octave down slash chord inversion 1st inv I7 F/A")
+            .unwrap();
+        assert_eq!(line.mml, whole);
+
+        // A failed line keeps the state; the next line converts in it
+        let before = state.clone();
+        assert!(converter.convert_line("key=F H", &mut state).is_err());
+        assert_eq!(state, before);
+
+        // Earlier directives follow a change of transposition
+        let mut converter = Converter::with_options(ConvertOptions {
+            transpose: 2,
+            ..ConvertOptions::default()
+        })
+        .unwrap();
+        assert_eq!(
+            converter.convert_line("I", &mut state).unwrap().mml,
+            converter
+                .convert("key=Bb drop2 octave down slash chord inversion 1st inv I")
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn test_pool_across_threads() {
+        let pool = ConverterPool::new(ConvertOptions::default());
+        std::thread::scope(|scope| {
+            for _ in 0..4 {
+                scope.spawn(|| {
+                    for _ in 0..10 {
+                        assert_eq!(pool.convert("Dm G7 C").unwrap(), "v11'd1fa''g1b<df''c1eg'");
+                    }
+                });
+            }
+        });
+        assert!(pool.lock_idle().len() <= 4);
+    }
+}
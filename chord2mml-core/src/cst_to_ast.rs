@@ -4,6 +4,8 @@
 //! `tonejs-mml-to-json`:
 //! - Native: the `tree-sitter` Rust crate parses the input, and `parser.rs`
 //!   serializes the CST into [`CSTNode`] (feature `tree-sitter`).
+//! - Pure Rust: `pure_parser.rs` builds the same [`CSTNode`] tree without
+//!   the C dependency (default features, e.g. the WASM `convert`).
 //! - WASM/browser: web-tree-sitter (JavaScript) parses the input and passes
 //!   the CST as JSON, avoiding tree-sitter's C dependency entirely on
 //!   `wasm32-unknown-unknown`.
@@ -17,18 +19,30 @@ use anyhow::{anyhow, Result};
 use serde::Deserialize;
 use std::collections::HashMap;
 
-use crate::ast::{ChordEvent, Event, SlashChordEvent, SlashChordMode};
+use crate::ast::{ChordEvent, Event, InputError, SlashChordEvent, SlashChordMode, Span};
+use crate::options::{ConvertOptions, Degrees, DiatonicQuality};
+use crate::spelling::letter_of;
+use crate::transpose;
+
+const IONIAN: [i32; 7] = [0, 2, 4, 5, 7, 9, 11];
+const AEOLIAN: [i32; 7] = [0, 2, 3, 5, 7, 8, 10];
 
 /// A Tree-sitter CST node in the JSON shape emitted by web-tree-sitter
 /// serialization (same shape as tonejs-mml-to-json's `nodeToCSTJson`):
 /// named children without a field name go to `children`, field-bound
 /// children go to `fields`.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
 pub struct CSTNode {
     #[serde(rename = "type")]
     pub node_type: String,
     #[serde(default)]
     pub text: Option<String>,
+    /// Start of the node in the input (web-tree-sitter `startIndex`);
+    /// optional, used only for source maps
+    #[serde(default, rename = "startIndex")]
+    pub start_index: Option<usize>,
+    #[serde(default, rename = "endIndex")]
+    pub end_index: Option<usize>,
     #[serde(default)]
     pub children: Vec<CSTNode>,
     #[serde(default)]
@@ -37,13 +51,103 @@ pub struct CSTNode {
 
 /// Convert a CST serialized as JSON into the event-array AST.
 pub fn cst_json_to_ast(cst_json: &str) -> Result<Vec<Event>> {
+    cst_json_to_ast_with_options(cst_json, &ConvertOptions::default())
+}
+
+/// [`cst_json_to_ast`] honoring the degree options in `options`.
+pub fn cst_json_to_ast_with_options(
+    cst_json: &str,
+    options: &ConvertOptions,
+) -> Result<Vec<Event>> {
     let root: CSTNode =
         serde_json::from_str(cst_json).map_err(|e| anyhow!("Failed to parse CST JSON: {}", e))?;
-    cst_to_ast(&root)
+    cst_to_ast_with_options(&root, options)
 }
 
 /// Convert a CST node tree into the event-array AST.
 pub fn cst_to_ast(root: &CSTNode) -> Result<Vec<Event>> {
+    cst_to_ast_with_options(root, &ConvertOptions::default())
+}
+
+/// The key state degree roots are resolved against (JS gKey, mutated
+/// while parsing; here resolved sequentially over the event stream, and
+/// carried from line to line by [`crate::convert_lines`]).
+#[derive(Clone)]
+pub(crate) struct DegreeContext<'a> {
+    key: i32,
+    /// The key's letter, which degree roots are spelled from (C=0 .. B=6)
+    key_letter: u8,
+    /// The active scale for `Degrees::Scale` and diatonic qualities: a
+    /// key directive resets it to ionian (or aeolian for `key=Xm`), a
+    /// scale directive replaces it
+    scale: Vec<i32>,
+    options: &'a ConvertOptions,
+}
+
+impl<'a> DegreeContext<'a> {
+    /// The state at the start of an input: key of C, ionian.
+    pub(crate) fn new(options: &'a ConvertOptions) -> Self {
+        Self::resume(options, KeyState::default())
+    }
+
+    /// The state after `key_state` (e.g. where a previous line ended).
+    pub(crate) fn resume(options: &'a ConvertOptions, key_state: KeyState) -> Self {
+        DegreeContext {
+            key: key_state.key,
+            key_letter: key_state.key_letter,
+            scale: key_state.scale,
+            options,
+        }
+    }
+
+    /// The key state to resume from.
+    pub(crate) fn key_state(&self) -> KeyState {
+        KeyState {
+            key: self.key,
+            key_letter: self.key_letter,
+            scale: self.scale.clone(),
+        }
+    }
+
+    /// Semitone offsets the seven degrees count from: the major scale, or
+    /// the active scale under `Degrees::Scale` when it has seven notes.
+    fn degree_offsets(&self) -> &[i32] {
+        match self.options.degrees {
+            Degrees::Scale if self.scale.len() == 7 => &self.scale,
+            _ => &IONIAN,
+        }
+    }
+}
+
+/// The key state of a [`DegreeContext`] without its options, kept between
+/// inputs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct KeyState {
+    key: i32,
+    key_letter: u8,
+    scale: Vec<i32>,
+}
+
+impl Default for KeyState {
+    fn default() -> Self {
+        KeyState {
+            key: 0,
+            key_letter: 0,
+            scale: IONIAN.to_vec(),
+        }
+    }
+}
+
+/// [`cst_to_ast`] honoring the degree options in `options`.
+pub fn cst_to_ast_with_options(root: &CSTNode, options: &ConvertOptions) -> Result<Vec<Event>> {
+    cst_to_ast_with_context(root, &mut DegreeContext::new(options))
+}
+
+/// [`cst_to_ast`] starting from, and updating, the key state in `context`.
+pub(crate) fn cst_to_ast_with_context(
+    root: &CSTNode,
+    context: &mut DegreeContext,
+) -> Result<Vec<Event>> {
     if root.node_type != "source_file" {
         return Err(anyhow!(
             "Expected source_file root node, got: {}",
@@ -52,22 +156,26 @@ pub fn cst_to_ast(root: &CSTNode) -> Result<Vec<Event>> {
     }
 
     let mut events = Vec::new();
-    // The key state transposes subsequent degree roots (JS gKey, mutated
-    // while parsing; here resolved sequentially over the event stream)
-    let mut g_key: i32 = 0;
     for child in &root.children {
         match child.node_type.as_str() {
-            "chord" => events.push(parse_chord_node(child, g_key)?),
+            "chord" => events.push(located(parse_chord_node(child, context), child)?),
             "separator" => {} // progression separators carry no meaning
             "bar" => events.push(Event::Bar),
             "bar_slash" => events.push(Event::BarSlash),
             "key" => {
-                let offset = parse_key_offset(child.text.as_deref().unwrap_or(""))?;
-                g_key = offset;
-                events.push(Event::Key { offset });
+                let (offset, letter, minor) =
+                    located(parse_key(child.text.as_deref().unwrap_or("")), child)?;
+                context.key = offset;
+                context.key_letter = letter;
+                context.scale = if minor { AEOLIAN } else { IONIAN }.to_vec();
+                events.push(Event::Key { offset, minor });
             }
             "scale" => {
-                let offsets = parse_scale_offsets(child.text.as_deref().unwrap_or(""))?;
+                let offsets = located(
+                    parse_scale_offsets(child.text.as_deref().unwrap_or("")),
+                    child,
+                )?;
+                context.scale = offsets.clone();
                 events.push(Event::Scale { offsets });
             }
             "mode_chord_over_bass_note" => events.push(Event::ChangeSlashChordMode(
@@ -134,7 +242,7 @@ pub fn cst_to_ast(root: &CSTNode) -> Result<Vec<Event>> {
                 events.push(Event::InlineMml(format!("t{}", digits)));
             }
             "midi_pc" => {
-                let mml = parse_midi_pc(child.text.as_deref().unwrap_or(""))?;
+                let mml = located(parse_midi_pc(child.text.as_deref().unwrap_or("")), child)?;
                 events.push(Event::InlineMml(mml));
             }
             other => return Err(anyhow!("Unexpected node type: {}", other)),
@@ -145,21 +253,38 @@ pub fn cst_to_ast(root: &CSTNode) -> Result<Vec<Event>> {
         return Err(anyhow!("No chords found in input"));
     }
 
+    transpose::transpose(&mut events, context.options.transpose);
     Ok(events)
 }
 
+/// Locate an error converting `node` at the node's range, when the CST
+/// has ranges.
+fn located<T>(result: Result<T>, node: &CSTNode) -> Result<T> {
+    result.map_err(|e| match node.start_index.zip(node.end_index) {
+        Some((start, end)) => InputError {
+            message: e.to_string(),
+            span: Span { start, end },
+        }
+        .into(),
+        None => e,
+    })
+}
+
 fn field_first<'a>(node: &'a CSTNode, name: &str) -> Option<&'a CSTNode> {
     node.fields.get(name).and_then(|nodes| nodes.first())
 }
 
-fn parse_chord_node(chord_node: &CSTNode, g_key: i32) -> Result<Event> {
+fn parse_chord_node(chord_node: &CSTNode, context: &DegreeContext) -> Result<Event> {
     let root_node = field_first(chord_node, "root").ok_or_else(|| anyhow!("No root node found"))?;
-    let root = parse_root_node(root_node, g_key)?;
+    let (root, root_letter) = parse_root_node(root_node, context)?;
 
     let quality = match field_first(chord_node, "quality") {
         Some(quality_node) => parse_quality_node(quality_node)?,
-        // Default to major when no explicit quality is present
-        None => "maj".to_string(),
+        // Default to major when no explicit quality is present, unless a
+        // bare degree takes its diatonic quality
+        None => diatonic_quality_of(root_node, context)
+            .unwrap_or("maj")
+            .to_string(),
     };
 
     let inversion = match field_first(chord_node, "inversion") {
@@ -171,6 +296,10 @@ fn parse_chord_node(chord_node: &CSTNode, g_key: i32) -> Result<Event> {
         .and_then(|n| n.text.as_deref())
         .map(parse_octave_offset)
         .unwrap_or(0);
+    let span = chord_node
+        .start_index
+        .zip(chord_node.end_index)
+        .map(|(start, end)| Span { start, end });
 
     // Slash chord (`/`, resolved to a mode by ast2ast) or on-chord
     // (`on`/`over`, always chord-over-bass-note), like the JS version.
@@ -179,9 +308,9 @@ fn parse_chord_node(chord_node: &CSTNode, g_key: i32) -> Result<Event> {
     // in the PEG grammar CHORD_QUALITY matches the empty string as "maj",
     // so JS's `lowerQuality ??= upperQuality` never fires for quality.
     if let Some(bass_node) = field_first(chord_node, "bass") {
-        let lower_root = match field_first(bass_node, "root") {
-            Some(node) => parse_root_node(node, g_key)?,
-            None => root,
+        let (lower_root, lower_root_letter) = match field_first(bass_node, "root") {
+            Some(node) => parse_root_node(node, context)?,
+            None => (root, root_letter),
         };
         let lower_quality = match field_first(bass_node, "quality") {
             Some(node) => parse_quality_node(node)?,
@@ -197,10 +326,13 @@ fn parse_chord_node(chord_node: &CSTNode, g_key: i32) -> Result<Event> {
             .unwrap_or(0);
 
         let slash = SlashChordEvent {
+            span,
             upper_root: root,
+            upper_root_letter: Some(root_letter),
             upper_quality: quality,
             upper_inversion: inversion,
             lower_root,
+            lower_root_letter: Some(lower_root_letter),
             lower_quality,
             lower_inversion,
             upper_octave_offset: octave_offset,
@@ -216,10 +348,12 @@ fn parse_chord_node(chord_node: &CSTNode, g_key: i32) -> Result<Event> {
 
     Ok(Event::Chord(ChordEvent {
         root,
+        root_letter: Some(root_letter),
         quality,
         inversion,
         octave_offset,
         note_length: None,
+        span,
     }))
 }
 
@@ -245,27 +379,21 @@ fn parse_inversion(text: &str) -> Result<String> {
 }
 
 /// Resolve a root node to a semitone offset (not normalized mod 12,
-/// matching the JS grammar):
+/// matching the JS grammar) and the letter it is spelled with:
 /// - Note token ("C#", "B♭", ...): C=0, D=2, E=4, F=5, G=7, A=9, B=11,
 ///   +1 per sharp / -1 per flat
-/// - Degree token ("bII", "#IV", "1", ...): Ionian offset of the numeral
-///   + accidentals + the key (JS ROOT_DEGREE — degrees always use the
-///     Ionian offsets; the scale only affects sharp/flat spelling downstream)
-fn parse_root_node(root_node: &CSTNode, g_key: i32) -> Result<i32> {
+/// - Degree token ("bII", "#IV", "1", ...): offset of the numeral
+///   + accidentals + the key (JS ROOT_DEGREE — by default degrees always
+///     use the Ionian offsets and the scale only affects sharp/flat
+///     spelling downstream; `Degrees::Scale` counts in the active scale);
+///     its letter counts up from the key's letter
+fn parse_root_node(root_node: &CSTNode, context: &DegreeContext) -> Result<(i32, u8)> {
     if let Some(degree_text) = field_first(root_node, "degree").and_then(|n| n.text.as_deref()) {
-        let (accidentals, numeral) = split_accidentals(degree_text);
-        let index = match numeral {
-            "I" | "1" => 0,
-            "II" | "2" => 1,
-            "III" | "3" => 2,
-            "IV" | "4" => 3,
-            "V" | "5" => 4,
-            "VI" | "6" => 5,
-            "VII" | "7" => 6,
-            other => return Err(anyhow!("Unknown degree: {}", other)),
-        };
-        const IONIAN: [i32; 7] = [0, 2, 4, 5, 7, 9, 11];
-        return Ok(IONIAN[index] + accidentals + g_key);
+        let (accidentals, index) = parse_degree(degree_text)?;
+        return Ok((
+            context.degree_offsets()[index] + accidentals + context.key,
+            (context.key_letter + index as u8) % 7,
+        ));
     }
 
     let note_text = field_first(root_node, "note")
@@ -273,7 +401,8 @@ fn parse_root_node(root_node: &CSTNode, g_key: i32) -> Result<i32> {
         .ok_or_else(|| anyhow!("No note found in root"))?;
 
     let mut chars = note_text.chars();
-    let base = match chars.next() {
+    let first = chars.next();
+    let base = match first {
         Some('C') => 0,
         Some('D') => 2,
         Some('E') => 4,
@@ -288,7 +417,62 @@ fn parse_root_node(root_node: &CSTNode, g_key: i32) -> Result<i32> {
         return Err(anyhow!("Unexpected note suffix: {}", note_text));
     }
 
-    Ok(base + accidentals)
+    let letter = first
+        .and_then(letter_of)
+        .expect("matched a note letter above");
+    Ok((base + accidentals, letter))
+}
+
+/// Split a degree token into (net accidentals, 0-based degree index).
+fn parse_degree(text: &str) -> Result<(i32, usize)> {
+    let (accidentals, numeral) = split_accidentals(text);
+    let index = match numeral {
+        "I" | "1" => 0,
+        "II" | "2" => 1,
+        "III" | "3" => 2,
+        "IV" | "4" => 3,
+        "V" | "5" => 4,
+        "VI" | "6" => 5,
+        "VII" | "7" => 6,
+        other => return Err(anyhow!("Unknown degree: {}", other)),
+    };
+    Ok((accidentals, index))
+}
+
+/// The diatonic quality of a bare degree root under
+/// `options.diatonic_quality`: thirds stacked from the degree within the
+/// active scale. None for note roots, altered degrees (`bVII` is not
+/// diatonic), non-heptatonic scales and stacks with no quality name.
+fn diatonic_quality_of(root_node: &CSTNode, context: &DegreeContext) -> Option<&'static str> {
+    let size = match context.options.diatonic_quality {
+        DiatonicQuality::None => return None,
+        DiatonicQuality::Triad => 3,
+        DiatonicQuality::Seventh => 4,
+    };
+    let degree_text = field_first(root_node, "degree")?.text.as_deref()?;
+    let (accidentals, index) = parse_degree(degree_text).ok()?;
+    if accidentals != 0 || context.scale.len() != 7 {
+        return None;
+    }
+
+    let scale = &context.scale;
+    let intervals: Vec<i32> = (1..size)
+        .map(|i| (scale[(index + 2 * i) % 7] - scale[index]).rem_euclid(12))
+        .collect();
+    Some(match intervals[..] {
+        [4, 7] => "maj",
+        [3, 7] => "min",
+        [3, 6] => "dim triad",
+        [4, 8] => "aug",
+        [4, 7, 11] => "maj7",
+        [3, 7, 10] => "min7",
+        [4, 7, 10] => "7",
+        [3, 6, 10] => "min7,flatted fifth",
+        [3, 6, 9] => "dim7",
+        [3, 7, 11] => "minmaj7",
+        [4, 8, 11] => "maj7,augmented fifth",
+        _ => return None,
+    })
 }
 
 /// Split leading accidentals off a token text; returns (net offset, rest).
@@ -307,9 +491,10 @@ fn split_accidentals(text: &str) -> (i32, &str) {
 }
 
 /// Parse a key directive token ("key"i [ =:]? [A-G] SHARP* FLAT*
-/// ("minor"i/"m")? [,.]?) into the key's semitone offset. The minor
-/// suffix is accepted but does not change the offset (JS KEY_EVENT).
-fn parse_key_offset(text: &str) -> Result<i32> {
+/// ("minor"i/"m")? [,.]?) into the key's semitone offset, root letter and
+/// whether it is minor. The minor suffix does not change the offset (JS
+/// KEY_EVENT); it only matters to `Degrees::Scale` and diatonic qualities.
+pub(crate) fn parse_key(text: &str) -> Result<(i32, u8, bool)> {
     // Skip "key" (case-insensitive) and the optional delimiter
     let rest = &text[3..];
     let rest = rest.strip_prefix([' ', '=', ':']).unwrap_or(rest);
@@ -329,17 +514,12 @@ fn parse_key_offset(text: &str) -> Result<i32> {
         other => return Err(anyhow!("Unknown key root: {}", other)),
     };
 
-    let mut offset = base;
-    for c in chars {
-        match c {
-            '#' | '＃' | '♯' => offset += 1,
-            'b' | '♭' => offset -= 1,
-            // minor suffix and trailing punctuation are ignored
-            _ => break,
-        }
-    }
+    // Trailing punctuation after the accidentals is ignored
+    let (accidentals, suffix) = split_accidentals(chars.as_str());
+    let minor = suffix.starts_with(['m', 'M']);
 
-    Ok(offset)
+    let letter = letter_of(root).expect("matched a key root above");
+    Ok((base + accidentals, letter, minor))
 }
 
 /// GM instrument aliases → MML program change, in JS PC000-PC127 order
@@ -525,8 +705,9 @@ fn parse_midi_pc(text: &str) -> Result<String> {
 }
 
 /// Parse a scale directive token into its interval offsets (JS
-/// getOffsetsByScale over the seven church modes).
-fn parse_scale_offsets(text: &str) -> Result<Vec<i32>> {
+/// getOffsetsByScale over the seven church modes, plus the Rust-version
+/// non-diatonic scales; spaces and hyphens in the name are ignored).
+pub(crate) fn parse_scale_offsets(text: &str) -> Result<Vec<i32>> {
     let word: String = text
         .chars()
         .filter(|c| c.is_ascii_alphabetic())
@@ -540,6 +721,16 @@ fn parse_scale_offsets(text: &str) -> Result<Vec<i32>> {
         "mixolydian" => &[0, 2, 4, 5, 7, 9, 10],
         "aeolian" => &[0, 2, 3, 5, 7, 8, 10],
         "locrian" => &[0, 1, 3, 5, 6, 8, 10],
+        "harmonicminor" => &[0, 2, 3, 5, 7, 8, 11],
+        "melodicminor" => &[0, 2, 3, 5, 7, 9, 11],
+        "harmonicmajor" => &[0, 2, 4, 5, 7, 8, 11],
+        "wholetone" => &[0, 2, 4, 6, 8, 10],
+        // Diminished defaults to whole-half (the scale of a dim7 chord)
+        "diminished" | "wholehalf" => &[0, 2, 3, 5, 6, 8, 9, 11],
+        "halfwhole" => &[0, 1, 3, 4, 6, 7, 9, 10],
+        "pentatonic" | "majorpentatonic" => &[0, 2, 4, 7, 9],
+        "minorpentatonic" => &[0, 3, 5, 7, 10],
+        "blues" => &[0, 3, 5, 6, 7, 10],
         other => return Err(anyhow!("Unknown scale: {}", other)),
     };
     Ok(offsets.to_vec())
@@ -571,6 +762,10 @@ fn normalize_modifier(modifier_str: &str) -> Result<String> {
     match inner {
         "b5" | "-5" => return Ok(",flatted fifth".to_string()),
         "+5" | "#5" => return Ok(",augmented fifth".to_string()),
+        "b9" | "-9" => return Ok(",flatted ninth".to_string()),
+        "#9" | "+9" => return Ok(",augmented ninth".to_string()),
+        "#11" | "+11" => return Ok(",augmented eleventh".to_string()),
+        "b13" | "-13" => return Ok(",flatted thirteenth".to_string()),
         _ => {}
     }
     if let Some(n) = inner.strip_prefix("add") {
@@ -628,6 +823,7 @@ mod tests {
             text: Some(text.to_string()),
             children: Vec::new(),
             fields: HashMap::new(),
+            ..Default::default()
         }
     }
 
@@ -640,6 +836,7 @@ mod tests {
             text: Some(text),
             children: Vec::new(),
             fields,
+            ..Default::default()
         }
     }
 
@@ -662,6 +859,7 @@ mod tests {
             text: None,
             children: Vec::new(),
             fields,
+            ..Default::default()
         }
     }
 
@@ -676,6 +874,7 @@ mod tests {
             text: None,
             children: Vec::new(),
             fields,
+            ..Default::default()
         }
     }
 
@@ -685,6 +884,7 @@ mod tests {
             text: None,
             children,
             fields: HashMap::new(),
+            ..Default::default()
         }
     }
 
@@ -695,10 +895,12 @@ mod tests {
             events,
             vec![Event::Chord(ChordEvent {
                 root: 0,
+                root_letter: Some(0),
                 quality: "maj".to_string(),
                 inversion: None,
                 octave_offset: 0,
                 note_length: None,
+                span: None,
             })]
         );
     }
@@ -781,6 +983,7 @@ mod tests {
                 text: Some("/C".to_string()),
                 children: Vec::new(),
                 fields: bass_fields,
+                ..Default::default()
             }],
         );
         let events = cst_to_ast(&source(vec![chord])).unwrap();
@@ -788,14 +991,17 @@ mod tests {
             events,
             vec![Event::SlashChord(SlashChordEvent {
                 upper_root: 5,
+                upper_root_letter: Some(3),
                 upper_quality: "maj".to_string(),
                 upper_inversion: None,
                 lower_root: 0,
+                lower_root_letter: Some(0),
                 lower_quality: "maj".to_string(),
                 lower_inversion: None,
                 upper_octave_offset: 0,
                 lower_octave_offset: 0,
                 note_length: None,
+                span: None,
             })]
         );
     }
@@ -839,6 +1045,20 @@ mod tests {
         assert_eq!(events.len(), 2);
     }
 
+    #[test]
+    fn test_scale_offsets() {
+        for (text, expected) in [
+            ("dorian", vec![0, 2, 3, 5, 7, 9, 10]),
+            ("Harmonic Minor", vec![0, 2, 3, 5, 7, 8, 11]),
+            ("melodic-minor,", vec![0, 2, 3, 5, 7, 9, 11]),
+            ("wholetone", vec![0, 2, 4, 6, 8, 10]),
+            ("minor pentatonic", vec![0, 3, 5, 7, 10]),
+        ] {
+            let events = cst_to_ast(&source(vec![leaf("scale", text)])).unwrap();
+            assert_eq!(events, vec![Event::Scale { offsets: expected }], "{}", text);
+        }
+    }
+
     #[test]
     fn test_empty_input_fails() {
         assert!(cst_to_ast(&source(vec![])).is_err());
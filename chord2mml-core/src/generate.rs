@@ -0,0 +1,426 @@
+//! Progression generator (Rust-version extension).
+//!
+//! Makes progressions for ear training from a model in a key:
+//! - [`Style::Functional`]: a Markov chain over the diatonic triads that
+//!   moves tonic → subdominant → dominant → tonic, from the tonic, ending
+//!   with an authentic cadence (`V I`).
+//! - [`Style::Pop`]: common four-chord loops (`I V vi IV`, `vi IV I V`,
+//!   ...), repeated, with a one-in-four chance of a new loop each time.
+//! - [`Style::Jazz`]: seventh-chord turnarounds (`Imaj7 vi7 ii7 V7`,
+//!   `iii7 VI7 ii7 V7`, tritone substitutions, ...) resolving to the tonic.
+//!
+//! Chords are picked by numeral as [`crate::analyze`] writes them and
+//! spelled from the key's letter names, then written with [`crate::chord_text`],
+//! so the text converts back to the same chords. The same seed makes the
+//! same progression.
+
+use serde::Serialize;
+
+use crate::analysis::{Key, MAJOR, MAJOR_NAMES, MINOR_NAMES};
+use crate::ast::{ChordEvent, Event};
+use crate::chord_text::chord_text;
+
+/// Which model makes the progression.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Style {
+    /// Functional harmony: diatonic triads ending `V I`
+    #[default]
+    Functional,
+    /// Common pop loops
+    Pop,
+    /// Jazz turnarounds of seventh chords
+    Jazz,
+}
+
+/// Options for [`generate`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GenerateOptions {
+    pub style: Style,
+    pub key: Key,
+    /// Number of chords (at least 1)
+    pub length: usize,
+    /// Chords between bar lines; 0 for none
+    pub chords_per_bar: usize,
+    pub seed: u64,
+}
+
+impl Default for GenerateOptions {
+    fn default() -> Self {
+        GenerateOptions {
+            style: Style::default(),
+            key: Key::default(),
+            length: 8,
+            chords_per_bar: 1,
+            seed: 0,
+        }
+    }
+}
+
+/// A generated progression.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Progression {
+    /// Each chord's Roman numeral in the key, e.g. `ii7`, `bVII`
+    pub numerals: Vec<String>,
+    /// The progression as notation, starting with its `key=` directive,
+    /// e.g. `key=F F | Dm | C7 | F`
+    pub text: String,
+}
+
+/// Markov chain of functional harmony in major: each numeral's successors
+/// with their weights.
+const FUNCTIONAL_MAJOR: &[(&str, &[(&str, u32)])] = &[
+    (
+        "I",
+        &[("IV", 3), ("ii", 2), ("vi", 2), ("V", 2), ("iii", 1)],
+    ),
+    ("ii", &[("V", 4), ("vii°", 1)]),
+    ("iii", &[("vi", 3), ("IV", 2)]),
+    ("IV", &[("V", 3), ("I", 2), ("ii", 2), ("vii°", 1)]),
+    ("V", &[("I", 4), ("vi", 2)]),
+    ("vi", &[("ii", 3), ("IV", 3), ("V", 1), ("iii", 1)]),
+    ("vii°", &[("I", 3), ("iii", 1)]),
+];
+const FUNCTIONAL_MINOR: &[(&str, &[(&str, u32)])] = &[
+    (
+        "i",
+        &[
+            ("iv", 3),
+            ("bVI", 2),
+            ("V", 2),
+            ("ii°", 1),
+            ("bIII", 1),
+            ("bVII", 1),
+        ],
+    ),
+    ("ii°", &[("V", 4)]),
+    ("bIII", &[("bVI", 2), ("iv", 2)]),
+    ("iv", &[("V", 3), ("i", 1), ("ii°", 1)]),
+    ("V", &[("i", 4), ("bVI", 2)]),
+    ("bVI", &[("iv", 2), ("ii°", 2), ("V", 2)]),
+    ("bVII", &[("bIII", 3), ("i", 1)]),
+];
+
+const POP_MAJOR: &[[&str; 4]] = &[
+    ["I", "V", "vi", "IV"],
+    ["vi", "IV", "I", "V"],
+    ["I", "vi", "IV", "V"],
+    ["I", "IV", "vi", "V"],
+    ["IV", "V", "iii", "vi"],
+    ["I", "bVII", "IV", "I"],
+];
+const POP_MINOR: &[[&str; 4]] = &[
+    ["i", "bVI", "bIII", "bVII"],
+    ["i", "bVII", "bVI", "bVII"],
+    ["i", "iv", "bVII", "bIII"],
+    ["i", "bVI", "iv", "V"],
+    ["i", "iv", "i", "V"],
+];
+
+/// Turnarounds: each leads back to the tonic.
+const JAZZ_MAJOR: &[[&str; 4]] = &[
+    ["Imaj7", "vi7", "ii7", "V7"],
+    ["Imaj7", "VI7", "ii7", "V7"],
+    ["iii7", "VI7", "ii7", "V7"],
+    ["Imaj7", "VI7", "ii7", "bII7"],
+    ["iii7", "bIII7", "ii7", "bII7"],
+    ["Imaj7", "#i°7", "ii7", "V7"],
+];
+const JAZZ_MINOR: &[[&str; 4]] = &[
+    ["i7", "bVImaj7", "iiø7", "V7"],
+    ["i7", "iv7", "iiø7", "V7"],
+    ["i7", "bVI7", "iiø7", "V7"],
+    ["iiø7", "V7", "i7", "V7"],
+];
+
+/// Make a progression.
+///
+/// # Example
+/// ```
+/// use chord2mml_core::{generate, Converter, GenerateOptions, Key, Style};
+///
+/// let options = GenerateOptions {
+///     style: Style::Jazz,
+///     key: Key::major(5),
+///     length: 5,
+///     seed: 7,
+///     ..GenerateOptions::default()
+/// };
+/// let progression = generate(&options);
+/// assert_eq!(progression, generate(&options));
+/// assert!(progression.text.starts_with("key=F "));
+/// assert_eq!(progression.numerals.last().unwrap(), "Imaj7");
+/// assert!(Converter::new().unwrap().convert(&progression.text).is_ok());
+/// ```
+pub fn generate(options: &GenerateOptions) -> Progression {
+    let mut rng = Rng(options.seed);
+    let length = options.length.max(1);
+    let minor = options.key.minor;
+    let numerals: Vec<&str> = match options.style {
+        Style::Functional => {
+            let (chain, cadence) = if minor {
+                (FUNCTIONAL_MINOR, ["V", "i"])
+            } else {
+                (FUNCTIONAL_MAJOR, ["V", "I"])
+            };
+            let mut numerals = vec![chain[0].0];
+            while numerals.len() + 2 < length {
+                let current = numerals[numerals.len() - 1];
+                let mut successors: Vec<(&str, u32)> = chain
+                    .iter()
+                    .find(|(numeral, _)| *numeral == current)
+                    .map_or(chain[0].1, |(_, successors)| successors)
+                    .to_vec();
+                // Lead into the cadence's V rather than repeat it, where
+                // the chain allows
+                let before_cadence = numerals.len() + 3 == length;
+                if before_cadence && successors.iter().any(|(numeral, _)| *numeral != cadence[0]) {
+                    successors.retain(|(numeral, _)| *numeral != cadence[0]);
+                }
+                numerals.push(rng.weighted(&successors));
+            }
+            // The cadence takes the last two chords (a single chord is its
+            // tonic)
+            numerals.truncate(length.saturating_sub(2));
+            numerals.extend(&cadence[2 - length.min(2)..]);
+            numerals
+        }
+        Style::Pop => {
+            let loops = if minor { POP_MINOR } else { POP_MAJOR };
+            let mut current = rng.pick(loops);
+            let mut numerals = Vec::new();
+            while numerals.len() < length {
+                numerals.extend(current);
+                if rng.below(4) == 0 {
+                    current = rng.pick(loops);
+                }
+            }
+            numerals.truncate(length);
+            numerals
+        }
+        Style::Jazz => {
+            let (turnarounds, tonic) = if minor {
+                (JAZZ_MINOR, "i7")
+            } else {
+                (JAZZ_MAJOR, "Imaj7")
+            };
+            let mut numerals = Vec::new();
+            while numerals.len() + 1 < length {
+                numerals.extend(rng.pick(turnarounds));
+            }
+            // Keep the ends of the turnarounds, which lead to the tonic
+            numerals.drain(..(numerals.len() + 1).saturating_sub(length));
+            numerals.push(tonic);
+            numerals
+        }
+    };
+
+    let mut events = vec![Event::Key {
+        offset: options.key.tonic,
+        minor,
+    }];
+    for (i, numeral) in numerals.iter().enumerate() {
+        if options.chords_per_bar > 0 && i > 0 && i % options.chords_per_bar == 0 {
+            events.push(Event::Bar);
+        }
+        events.push(Event::Chord(
+            numeral_chord(numeral, options.key).expect("the models' numerals are valid"),
+        ));
+    }
+    Progression {
+        numerals: numerals.iter().map(|numeral| numeral.to_string()).collect(),
+        text: chord_text(&events),
+    }
+}
+
+/// The chord of `numeral` in `key`: accidentals are relative to the major
+/// scale and the case gives the third, as [`crate::analyze`] writes them.
+fn numeral_chord(numeral: &str, key: Key) -> Option<ChordEvent> {
+    let accidentals = numeral.len() - numeral.trim_start_matches(['b', '#']).len();
+    let (accidentals, rest) = numeral.split_at(accidentals);
+    let alter: i32 = accidentals
+        .chars()
+        .map(|c| if c == '#' { 1 } else { -1 })
+        .sum();
+    let roman_len = rest.len() - rest.trim_start_matches(['I', 'V', 'i', 'v']).len();
+    let (roman, suffix) = rest.split_at(roman_len);
+    let degree = ["I", "II", "III", "IV", "V", "VI", "VII"]
+        .iter()
+        .position(|name| name.eq_ignore_ascii_case(roman))?;
+    let lower = roman.chars().all(|c| c.is_ascii_lowercase());
+    let quality = match (lower, suffix) {
+        (false, "") => "maj",
+        (false, "7") => "7",
+        (false, "maj7") => "maj7",
+        (false, "+") => "aug",
+        (true, "") => "min",
+        (true, "7") => "min7",
+        (true, "°") => "dim triad",
+        (true, "°7") => "dim triad,add6",
+        (true, "ø7") => "min7,flatted fifth",
+        _ => return None,
+    };
+
+    let names = if key.minor { MINOR_NAMES } else { MAJOR_NAMES };
+    let key_letter = "CDEFGAB".find(names[key.tonic.rem_euclid(12) as usize].chars().next()?)?;
+    let root = (key.tonic + MAJOR[degree] + alter).rem_euclid(12);
+    // The numeral's letter name in the key, whatever accidentals it takes
+    // (bVI of Eb minor is Cb, bII of Db major Ebb)
+    let letter = (key_letter + degree) % 7;
+    Some(ChordEvent {
+        root,
+        root_letter: Some(letter as u8),
+        quality: quality.to_string(),
+        inversion: None,
+        octave_offset: 0,
+        note_length: None,
+        span: None,
+    })
+}
+
+/// splitmix64: small, seedable (0 included) and the same everywhere.
+struct Rng(u64);
+
+impl Rng {
+    fn next(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// A number in `0..n`.
+    fn below(&mut self, n: u64) -> u64 {
+        self.next() % n
+    }
+
+    fn pick<T: Copy>(&mut self, choices: &[T]) -> T {
+        choices[self.below(choices.len() as u64) as usize]
+    }
+
+    fn weighted<T: Copy>(&mut self, choices: &[(T, u32)]) -> T {
+        let total: u32 = choices.iter().map(|(_, weight)| weight).sum();
+        let mut roll = self.below(total as u64) as u32;
+        for &(choice, weight) in choices {
+            if roll < weight {
+                return choice;
+            }
+            roll -= weight;
+        }
+        unreachable!("roll is below the total weight")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Converter;
+
+    fn all_numerals() -> Vec<&'static str> {
+        let chains = FUNCTIONAL_MAJOR.iter().chain(FUNCTIONAL_MINOR);
+        chains
+            .flat_map(|(numeral, successors)| {
+                std::iter::once(*numeral).chain(successors.iter().map(|(numeral, _)| *numeral))
+            })
+            .chain(POP_MAJOR.iter().chain(POP_MINOR).flatten().copied())
+            .chain(JAZZ_MAJOR.iter().chain(JAZZ_MINOR).flatten().copied())
+            .collect()
+    }
+
+    #[test]
+    fn test_numeral_chords() {
+        for numeral in all_numerals() {
+            assert!(
+                numeral_chord(numeral, Key::default()).is_some(),
+                "{}",
+                numeral
+            );
+        }
+        // Spelled from the key's letter names
+        for (numeral, key, name) in [
+            ("bII7", Key::major(7), "Ab7"),
+            ("bVI", Key::major(0), "Ab"),
+            ("bVI", Key::minor(3), "Cb"),
+            ("bII7", Key::major(3), "Fb7"),
+            ("bII7", Key::major(1), "Ebb7"),
+            ("#i°7", Key::major(6), "F##dim(add6)"),
+            ("V7", Key::minor(8), "D#7"),
+        ] {
+            let chord = numeral_chord(numeral, key).unwrap();
+            assert_eq!(
+                chord_text(&[Event::Chord(chord)]),
+                name,
+                "{numeral} in {key}"
+            );
+        }
+        assert_eq!(numeral_chord("#iv°7", Key::major(0)).unwrap().root, 6);
+        assert!(numeral_chord("IIx", Key::major(0)).is_none());
+    }
+
+    #[test]
+    fn test_seeds_reproduce() {
+        let options = GenerateOptions {
+            seed: 42,
+            ..GenerateOptions::default()
+        };
+        assert_eq!(generate(&options), generate(&options));
+        assert_eq!(
+            generate(&options).text,
+            "key=C C | Dm | G | C | Dm | Bdim | G | C"
+        );
+    }
+
+    #[test]
+    fn test_styles_and_lengths() {
+        let mut converter = Converter::new().unwrap();
+        for style in [Style::Functional, Style::Pop, Style::Jazz] {
+            for key in [Key::major(0), Key::major(1), Key::minor(6), Key::minor(3)] {
+                for length in [1, 2, 3, 5, 8, 13] {
+                    for seed in 0..8 {
+                        let options = GenerateOptions {
+                            style,
+                            key,
+                            length,
+                            chords_per_bar: 2,
+                            seed,
+                        };
+                        let progression = generate(&options);
+                        assert_eq!(progression.numerals.len(), length);
+                        // The text converts back to itself
+                        let events = converter
+                            .convert_explained(&progression.text)
+                            .unwrap()
+                            .events;
+                        assert_eq!(chord_text(&events), progression.text);
+                        if style != Style::Pop {
+                            let tonic = if key.minor { "i" } else { "I" };
+                            assert!(progression.numerals[length - 1].starts_with(tonic));
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_functional_cadence() {
+        for seed in 0..20 {
+            let progression = generate(&GenerateOptions {
+                seed,
+                length: 6,
+                ..GenerateOptions::default()
+            });
+            assert_eq!(progression.numerals[0], "I");
+            assert_eq!(progression.numerals[4..], ["V", "I"]);
+            assert_ne!(progression.numerals[3], "V");
+        }
+        let progression = generate(&GenerateOptions {
+            length: 2,
+            key: Key::minor(9),
+            chords_per_bar: 0,
+            ..GenerateOptions::default()
+        });
+        assert_eq!(progression.text, "key=Am E Am");
+    }
+}
@@ -0,0 +1,180 @@
+//! Key estimation (Rust-version extension).
+//!
+//! Krumhansl–Kessler: the pitch classes of the realized chords, each
+//! weighted by its chord's length (the last chord's doubled, as a cadence
+//! tends to end on the tonic) and the bass counted twice, form a
+//! histogram that is correlated with the major and minor key profiles
+//! rotated to every tonic. The key whose profile correlates best is the
+//! likely one. Used for [`crate::ConvertOptions::detect_key`] spelling and
+//! the CLI `analyze` when the input sets no key.
+
+use serde::Serialize;
+
+use crate::analysis::Key;
+use crate::ast::{NotesEvent, OutEvent};
+
+/// Krumhansl–Kessler probe-tone ratings, from the tonic up.
+const MAJOR_PROFILE: [f32; 12] = [
+    6.35, 2.23, 3.48, 2.33, 4.38, 4.09, 2.52, 5.19, 2.39, 3.66, 2.29, 2.88,
+];
+const MINOR_PROFILE: [f32; 12] = [
+    6.33, 2.68, 3.52, 5.38, 2.60, 3.53, 2.54, 4.75, 3.98, 2.69, 3.34, 3.17,
+];
+const AEOLIAN: [i32; 7] = [0, 2, 3, 5, 7, 8, 10];
+
+/// A candidate key and how well the chords fit it.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct KeyEstimate {
+    pub key: Key,
+    /// Correlation of the chords' pitch classes with the key's profile,
+    /// from -1 to 1
+    pub correlation: f32,
+}
+
+/// How long each pitch class sounds in `events`, in whole notes, with the
+/// bass of each chord counted twice and the last chord, unless it is a
+/// dominant, held twice as long.
+pub fn pitch_class_histogram(events: &[OutEvent]) -> [f32; 12] {
+    let mut histogram = [0.0; 12];
+    let chords: Vec<&NotesEvent> = events
+        .iter()
+        .filter_map(|event| match event {
+            OutEvent::Notes(notes_event) => Some(notes_event),
+            _ => None,
+        })
+        .collect();
+    for (i, notes_event) in chords.iter().enumerate() {
+        let mut weight = 1.0 / notes_event.note_length.unwrap_or(1).max(1) as f32;
+        // Progressions tend to end on the tonic, unless they end on a
+        // dominant (a turnaround), whose tritone gives it away
+        if i + 1 == chords.len() && !has_tritone(&notes_event.notes) {
+            weight *= 2.0;
+        }
+        for note in &notes_event.notes {
+            histogram[note.rem_euclid(12) as usize] += weight;
+        }
+        if let Some(bass) = notes_event.notes.iter().min() {
+            histogram[bass.rem_euclid(12) as usize] += weight;
+        }
+    }
+    histogram
+}
+
+/// All 24 keys, the likeliest first; empty when `events` have no notes.
+pub fn estimate_keys(events: &[OutEvent]) -> Vec<KeyEstimate> {
+    let histogram = pitch_class_histogram(events);
+    if histogram.iter().all(|weight| *weight == 0.0) {
+        return Vec::new();
+    }
+    let mut estimates: Vec<KeyEstimate> = (0..12)
+        .flat_map(|tonic| {
+            [
+                (Key::major(tonic), &MAJOR_PROFILE),
+                (Key::minor(tonic), &MINOR_PROFILE),
+            ]
+        })
+        .map(|(key, profile)| {
+            let rotated: Vec<f32> = (0..12)
+                .map(|pc| profile[(pc - key.tonic).rem_euclid(12) as usize])
+                .collect();
+            KeyEstimate {
+                key,
+                correlation: correlation(&histogram, &rotated),
+            }
+        })
+        .collect();
+    // Stable, so ties keep C-first, major-first order
+    estimates.sort_by(|a, b| b.correlation.total_cmp(&a.correlation));
+    estimates
+}
+
+/// The likeliest key of `events` (e.g. `Conversion::out_events`).
+///
+/// # Example
+/// ```
+/// use chord2mml_core::{detect_key, Converter, Key};
+///
+/// let conversion = Converter::new().unwrap().convert_explained("Dm Gm A7 Dm").unwrap();
+/// assert_eq!(detect_key(&conversion.out_events).unwrap().key, Key::minor(2));
+/// ```
+pub fn detect_key(events: &[OutEvent]) -> Option<KeyEstimate> {
+    estimate_keys(events).into_iter().next()
+}
+
+/// The events that make notes2mml spell in `key`: a minor key also sets
+/// aeolian, so that D minor spells flats like F major.
+pub(crate) fn spelling_events(key: Key) -> Vec<OutEvent> {
+    let mut events = vec![OutEvent::Key { offset: key.tonic }];
+    if key.minor {
+        events.push(OutEvent::Scale {
+            offsets: AEOLIAN.to_vec(),
+        });
+    }
+    events
+}
+
+fn has_tritone(notes: &[i32]) -> bool {
+    notes
+        .iter()
+        .any(|a| notes.iter().any(|b| (a - b).rem_euclid(12) == 6))
+}
+
+/// Pearson correlation coefficient.
+fn correlation(xs: &[f32], ys: &[f32]) -> f32 {
+    let n = xs.len() as f32;
+    let mean_x = xs.iter().sum::<f32>() / n;
+    let mean_y = ys.iter().sum::<f32>() / n;
+    let (mut covariance, mut variance_x, mut variance_y) = (0.0, 0.0, 0.0);
+    for (x, y) in xs.iter().zip(ys) {
+        covariance += (x - mean_x) * (y - mean_y);
+        variance_x += (x - mean_x).powi(2);
+        variance_y += (y - mean_y).powi(2);
+    }
+    if variance_x == 0.0 || variance_y == 0.0 {
+        0.0
+    } else {
+        covariance / (variance_x * variance_y).sqrt()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Converter;
+
+    fn likely_key(input: &str) -> String {
+        let conversion = Converter::new().unwrap().convert_explained(input).unwrap();
+        detect_key(&conversion.out_events).unwrap().key.to_string()
+    }
+
+    #[test]
+    fn test_detect_key() {
+        for (input, key) in [
+            ("C F G7 C", "C major"),
+            ("Dm7 G7 Cmaj7", "C major"),
+            ("Bb Eb F7 Bb", "Bb major"),
+            ("Am Dm E7 Am", "A minor"),
+            ("E A B7 E", "E major"),
+            ("F#m Bm C#7 F#m", "F# minor"),
+            // A turnaround ends on its dominant
+            ("Cmaj7 Am7 Dm7 G7", "C major"),
+        ] {
+            assert_eq!(likely_key(input), key, "{}", input);
+        }
+    }
+
+    #[test]
+    fn test_estimates_are_ranked() {
+        let conversion = Converter::new()
+            .unwrap()
+            .convert_explained("G C D7 G")
+            .unwrap();
+        let estimates = estimate_keys(&conversion.out_events);
+        assert_eq!(estimates.len(), 24);
+        assert!(estimates
+            .windows(2)
+            .all(|pair| pair[0].correlation >= pair[1].correlation));
+        assert!(estimate_keys(&[OutEvent::Bar]).is_empty());
+    }
+}
@@ -9,34 +9,189 @@
 //! parse → cst_to_ast (events) → ast2ast → ast2notes → notes2mml
 //! ```
 //!
-//! Two parsing paths converge on the shared `cst_to_ast` semantic layer
+//! Three parsing paths converge on the shared `cst_to_ast` semantic layer
 //! (the architecture proven in tonejs-mml-to-json):
 //! - Native (feature `tree-sitter`, enabled by the CLI): `convert` parses
 //!   with the tree-sitter Rust crate.
-//! - WASM/browser (default features, no C dependency): web-tree-sitter (JS)
-//!   parses the input and `convert_cst` receives the CST as JSON.
+//! - Default features (no C dependency, e.g. WASM): `convert` parses with
+//!   a pure-Rust port of the grammar that builds the same tree.
+//! - Browser with web-tree-sitter (JS): the page parses the input and
+//!   `convert_cst` receives the CST as JSON.
 
 use anyhow::{anyhow, Result};
+use serde::Serialize;
+use std::borrow::Cow;
 
 // Module declarations
+mod analysis;
 mod ast;
 mod ast2ast;
 mod ast2notes;
+mod chord_scales;
+mod chord_text;
+mod converter;
 pub mod cst_to_ast;
+mod generate;
+mod key_detection;
+mod midi;
+mod musicxml;
 mod notes2mml;
+mod options;
 #[cfg(feature = "tree-sitter")]
 mod parser;
 pub mod preprocess;
+mod pure_parser;
+mod reharmonize;
+mod session;
+mod spelling;
+mod synth;
+#[cfg(test)]
+mod test_util;
+pub mod timeline;
+mod transpose;
 
 // Re-export public types for external use
-pub use ast::{ChordEvent, Event, NotesEvent, OutEvent, SlashChordEvent};
-pub use preprocess::preprocess_candidates;
+pub use analysis::{analyze, Analysis, ChordAnalysis, Function, Key, Role, TwoFive};
+pub use ast::{
+    ChordEvent, ChordNotes, Event, InputError, LineError, NotesEvent, OutEvent, SlashChordEvent,
+    SlashChordMode, SourceMapping, Span, SpelledNote, Warning,
+};
+pub use chord_scales::{chord_scales, ChordScale, ChordScales};
+pub use chord_text::{chord_symbol, chord_text};
+pub use converter::{Converter, ConverterPool, LineState};
+pub use generate::{generate, GenerateOptions, Progression, Style};
+pub use key_detection::{detect_key, estimate_keys, pitch_class_histogram, KeyEstimate};
+pub use midi::{midi_schedule, to_midi, ScheduledMessage};
+pub use musicxml::to_musicxml;
+pub use notes2mml::spell_notes;
+pub use options::{ConvertOptions, Degrees, DiatonicQuality, MmlProfile, Spelling};
+pub use preprocess::{preprocess_candidates, preprocess_rewrites, Rewrite};
+pub use reharmonize::{reharmonize, Reharmonization};
+pub use session::CstSession;
+#[cfg(feature = "tree-sitter")]
+pub use session::Session;
+pub use synth::{render, to_wav, Envelope, Instrument, SynthOptions, Waveform};
 
 /// Run the shared pipeline stages after parsing.
-fn events_to_mml(events: Vec<Event>) -> Result<String> {
-    let events = ast2ast::ast_to_ast(events);
-    let note_events = ast2notes::ast_to_notes(events)?;
-    notes2mml::notes_to_mml(&note_events)
+fn events_to_mml(events: Vec<Event>, options: &ConvertOptions) -> Result<String> {
+    let events = reharmonized(events, options)?;
+    let parsed = options.scale_runs.then(|| events.clone());
+    let note_events = events_to_notes(events, options)?;
+    let mml_events = match parsed {
+        Some(parsed) => Cow::Owned(with_scale_runs(&parsed, &note_events, options)),
+        None => Cow::Borrowed(note_events.as_slice()),
+    };
+    notes2mml::notes_to_mml(&mml_events, options.spelling, options.mml_profile)
+}
+
+/// ast2ast and ast2notes; with `detect_key`, an input that sets no key is
+/// spelled in the key estimated from its notes.
+fn events_to_notes(events: Vec<Event>, options: &ConvertOptions) -> Result<Vec<OutEvent>> {
+    let mut note_events = ast2notes::ast_to_notes(ast2ast::ast_to_ast(events))?;
+    if options.detect_key
+        && !note_events
+            .iter()
+            .any(|event| matches!(event, OutEvent::Key { .. }))
+    {
+        if let Some(estimate) = detect_key(&note_events) {
+            note_events.splice(0..0, key_detection::spelling_events(estimate.key));
+        }
+    }
+    Ok(note_events)
+}
+
+/// `events` with the `reharmonize` operations applied, in the key that
+/// `detect_key` estimates when it is set and the input has no key.
+fn reharmonized(events: Vec<Event>, options: &ConvertOptions) -> Result<Vec<Event>> {
+    if options.reharmonize.is_empty() {
+        return Ok(events);
+    }
+    let keyless = !events
+        .iter()
+        .any(|event| matches!(event, Event::Key { .. }));
+    let key = if options.detect_key && keyless {
+        let note_events = ast2notes::ast_to_notes(ast2ast::ast_to_ast(events.clone()))?;
+        detect_key(&note_events).map(|estimate| estimate.key)
+    } else {
+        None
+    };
+    Ok(reharmonize(events, &options.reharmonize, key))
+}
+
+/// `note_events` with each chord followed by a run up its chord scale in
+/// the key of `events` (or the one `detect_key` estimated), for the MML.
+fn with_scale_runs(
+    events: &[Event],
+    note_events: &[OutEvent],
+    options: &ConvertOptions,
+) -> Vec<OutEvent> {
+    let keyless = !events
+        .iter()
+        .any(|event| matches!(event, Event::Key { .. }));
+    let key = if options.detect_key && keyless {
+        detect_key(note_events).map(|estimate| estimate.key)
+    } else {
+        None
+    };
+    chord_scales::with_scale_runs(note_events, &chord_scales(events, key))
+}
+
+/// What the pipeline stages report for one parse besides the MML.
+struct Output {
+    mml: String,
+    source_map: Vec<SourceMapping>,
+    chords: Vec<ChordNotes>,
+    warnings: Vec<Warning>,
+    events: Vec<Event>,
+    out_events: Vec<OutEvent>,
+}
+
+/// [`events_to_mml`], also returning the source map, notes and warnings
+/// of the chords and the events of each stage.
+fn events_to_output(events: Vec<Event>, options: &ConvertOptions) -> Result<Output> {
+    let events = reharmonized(events, options)?;
+    let note_events = events_to_notes(events.clone(), options)?;
+    let mml_events = if options.scale_runs {
+        Cow::Owned(with_scale_runs(&events, &note_events, options))
+    } else {
+        Cow::Borrowed(note_events.as_slice())
+    };
+    let (mml, source_map) = notes2mml::notes_to_mml_with_source_map(
+        &mml_events,
+        options.spelling,
+        options.mml_profile,
+    )?;
+
+    let mut chords = Vec::new();
+    let mut warnings = Vec::new();
+    for event in &note_events {
+        let OutEvent::Notes(notes_event) = event else {
+            continue;
+        };
+        if notes_event.notes.is_empty() {
+            continue;
+        }
+        let notes: Vec<i32> = notes_event.notes.iter().map(|note| note + 60).collect();
+        if notes.iter().any(|note| !(0..=127).contains(note)) {
+            warnings.push(Warning {
+                message: format!("Notes outside the MIDI range (0-127): {:?}", notes),
+                span: notes_event.span,
+            });
+        }
+        chords.push(ChordNotes {
+            notes,
+            span: notes_event.span,
+        });
+    }
+
+    Ok(Output {
+        mml,
+        source_map,
+        chords,
+        warnings,
+        events,
+        out_events: note_events,
+    })
 }
 
 /// Convert a chord notation or chord progression to MML (Music Macro
@@ -56,61 +211,218 @@ fn events_to_mml(events: Vec<Event>) -> Result<String> {
 /// let mml = convert("Dm G7 C").unwrap();
 /// assert_eq!(mml, "v11'd1fa''g1b<df''c1eg'");
 /// ```
-#[cfg(feature = "tree-sitter")]
 pub fn convert(input: &str) -> Result<String> {
-    let input = input.trim();
+    convert_with_options(input, &ConvertOptions::default())
+}
 
-    if input.is_empty() {
-        return Err(anyhow!(
-            "Empty input. Please provide a chord notation (e.g., 'C', 'Dm G7 C')."
-        ));
-    }
+/// [`convert`] with Rust-version extensions switched on by `options`.
+///
+/// # Example
+/// ```
+/// use chord2mml_core::{convert_with_options, ConvertOptions, Spelling};
+///
+/// let options = ConvertOptions {
+///     spelling: Spelling::Chord,
+///     ..Default::default()
+/// };
+/// assert_eq!(convert_with_options("Fm", &options).unwrap(), "v11'f1a-<c'");
+/// ```
+pub fn convert_with_options(input: &str, options: &ConvertOptions) -> Result<String> {
+    convert_explained(input, options).map(|conversion| conversion.mml)
+}
 
-    // Validate input length to prevent potential issues
-    if input.len() > 1000 {
-        return Err(anyhow!("Input too long (max 1000 characters)."));
-    }
+/// The MML for an input and the dialect reading it was converted from.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Conversion {
+    pub mml: String,
+    /// The candidate that converted; `rewrite.rules` is empty when the
+    /// input parsed as written.
+    pub rewrite: Rewrite,
+    /// Each chord's byte span in `rewrite.text` and the byte range of its
+    /// `'...'` group in `mml`, in output order.
+    pub source_map: Vec<SourceMapping>,
+    /// The notes of each chord, in output order (spans as in `source_map`).
+    pub chords: Vec<ChordNotes>,
+    pub warnings: Vec<Warning>,
+    /// The parsed events (the AST, before slash-chord and bar resolution).
+    #[serde(skip)]
+    pub events: Vec<Event>,
+    /// The realized events the MML was written from, for the other
+    /// outputs ([`to_midi`], [`to_musicxml`], [`spell_notes`]).
+    #[serde(skip)]
+    pub out_events: Vec<OutEvent>,
+}
 
-    // Try the input as written first, then every dialect rewrite
-    // (JS chord2mml.parse's brute-force contract); if nothing converts,
-    // report the original input's error.
-    let mut first_error = None;
-    for candidate in preprocess::preprocess_candidates(input) {
-        match parser::parse_to_ast(&candidate).and_then(events_to_mml) {
-            Ok(mml) => return Ok(mml),
-            Err(e) => {
-                if first_error.is_none() {
-                    first_error = Some(e);
-                }
-            }
+impl Conversion {
+    fn new(output: Output, rewrite: Rewrite) -> Self {
+        Conversion {
+            mml: output.mml,
+            rewrite,
+            source_map: output.source_map,
+            chords: output.chords,
+            warnings: output.warnings,
+            events: output.events,
+            out_events: output.out_events,
         }
     }
-    Err(first_error.expect("candidates are never empty"))
+}
+
+/// [`convert_with_options`], also reporting which dialect rewrites were
+/// applied to the input.
+///
+/// # Example
+/// ```
+/// use chord2mml_core::{convert_explained, ConvertOptions};
+///
+/// let conversion = convert_explained("ii V I", &ConvertOptions::default()).unwrap();
+/// assert_eq!(conversion.rewrite.text, "IIm V I");
+/// assert_eq!(conversion.rewrite.rules, ["minor-roman-numerals"]);
+/// ```
+pub fn convert_explained(input: &str, options: &ConvertOptions) -> Result<Conversion> {
+    Converter::with_options(options.clone())?.convert_explained(input)
+}
+
+/// [`convert_with_options`], also returning a source map from each
+/// chord's byte span in the input to the byte range of its `'...'` group
+/// in the MML (e.g. to highlight the chord playing). The map is `None`
+/// when the input only converts through a dialect rewrite, whose chords
+/// are not where the input wrote them; [`convert_explained`] maps those
+/// into the rewritten text.
+///
+/// # Example
+/// ```
+/// use chord2mml_core::{convert_with_source_map, ConvertOptions, Span};
+///
+/// let (mml, source_map) = convert_with_source_map("C G7", &ConvertOptions::default()).unwrap();
+/// let source_map = source_map.unwrap();
+/// assert_eq!(mml, "v11'c1eg''g1b<df'");
+/// assert_eq!(source_map[1].input, Span { start: 2, end: 4 });
+/// assert_eq!(&mml[source_map[1].output.start..source_map[1].output.end], "'g1b<df'");
+///
+/// let (_, source_map) = convert_with_source_map("ii V I", &ConvertOptions::default()).unwrap();
+/// assert_eq!(source_map, None);
+/// ```
+pub fn convert_with_source_map(
+    input: &str,
+    options: &ConvertOptions,
+) -> Result<(String, Option<Vec<SourceMapping>>)> {
+    let conversion = convert_explained(input, options)?;
+    if !conversion.rewrite.rules.is_empty() {
+        return Ok((conversion.mml, None));
+    }
+    // The conversion's spans index the trimmed input
+    let leading = input.len() - input.trim_start().len();
+    let source_map = conversion
+        .source_map
+        .into_iter()
+        .map(|mapping| SourceMapping {
+            input: Span {
+                start: mapping.input.start + leading,
+                end: mapping.input.end + leading,
+            },
+            output: mapping.output,
+        })
+        .collect();
+    Ok((conversion.mml, Some(source_map)))
+}
+
+/// Convert a multi-line chart (a full song) in one call: each non-blank
+/// line is dialect-preprocessed and parsed on its own, then all lines run
+/// through the pipeline as one progression, so key, scale, slash /
+/// inversion / voicing modes, octave shifts and tempo carry over from
+/// line to line. `max_input_len` limits each line, not the whole chart.
+///
+/// # Example
+/// ```
+/// use chord2mml_core::{convert, convert_lines, ConvertOptions};
+///
+/// let chart = "key=D\nI IV\nii-V-I";
+/// let mml = convert_lines(chart, &ConvertOptions::default()).unwrap();
+/// assert_eq!(mml, convert("key=D I IV IIm V I").unwrap());
+/// ```
+pub fn convert_lines(input: &str, options: &ConvertOptions) -> Result<String> {
+    Converter::with_options(options.clone())?.convert_lines(input)
+}
+
+/// Convert a song chart (ChordPro `[Am]Hello` text or chords over lyrics)
+/// in one call: the chords are taken out of the whole text (see
+/// [`preprocess::extract_chord_chart`]) and converted as one progression,
+/// each chart line one bar with `line_bars`. Fails when the text is not a
+/// chart.
+///
+/// # Example
+/// ```
+/// use chord2mml_core::{convert, convert_chart, ConvertOptions};
+///
+/// let song = "C        G\nHello world\nAm       F\nGoodbye now";
+/// let mml = convert_chart(song, true, &ConvertOptions::default()).unwrap();
+/// assert_eq!(mml, convert("C G | Am F").unwrap());
+/// ```
+pub fn convert_chart(text: &str, line_bars: bool, options: &ConvertOptions) -> Result<String> {
+    Converter::with_options(options.clone())?.convert_chart(text, line_bars)
 }
 
 /// Return the dialect rewrite of the input that parses, or the input
 /// unchanged if none does. Port of the JS `preprocessChord` API: the
 /// original is never tried, only rewritten candidates.
-#[cfg(feature = "tree-sitter")]
 pub fn preprocess_chord(input: &str) -> String {
-    for candidate in preprocess::preprocess_candidates(input).into_iter().skip(1) {
-        if parser::parse_to_ast(&candidate).is_ok() {
-            return candidate;
-        }
+    match Converter::new() {
+        Ok(mut converter) => converter.preprocess_chord(input),
+        Err(_) => input.to_string(),
     }
-    input.to_string()
 }
 
 /// Convert a CST serialized as JSON (produced by web-tree-sitter in the
 /// browser) to MML. This is the entry point for WASM builds, where the
 /// parsing happens in JavaScript.
 pub fn convert_cst(cst_json: &str) -> Result<String> {
+    convert_cst_with_options(cst_json, &ConvertOptions::default())
+}
+
+/// [`convert_cst`] with a source map (see [`convert_with_source_map`];
+/// a CST is never rewritten, so the map is always there); input spans
+/// are in the units of the CST's `startIndex`/`endIndex`
+/// (UTF-16 code units from web-tree-sitter) and are absent from the map
+/// when the CST has no indices.
+pub fn convert_cst_with_source_map(
+    cst_json: &str,
+    options: &ConvertOptions,
+) -> Result<(String, Vec<SourceMapping>)> {
+    if cst_json.trim().is_empty() {
+        return Err(anyhow!("Empty CST JSON input."));
+    }
+
+    convert_cst_explained(cst_json, options)
+        .map(|conversion| (conversion.mml, conversion.source_map))
+}
+
+/// [`convert_cst`] returning a [`Conversion`]. The page chose the text to
+/// parse, so `rewrite` is the CST's text with no rules; spans are in the
+/// units of the CST's `startIndex`/`endIndex`.
+pub fn convert_cst_explained(cst_json: &str, options: &ConvertOptions) -> Result<Conversion> {
     if cst_json.trim().is_empty() {
         return Err(anyhow!("Empty CST JSON input."));
     }
 
-    let events = cst_to_ast::cst_json_to_ast(cst_json)?;
-    events_to_mml(events)
+    let root: cst_to_ast::CSTNode =
+        serde_json::from_str(cst_json).map_err(|e| anyhow!("Failed to parse CST JSON: {}", e))?;
+    let events = cst_to_ast::cst_to_ast_with_options(&root, options)?;
+    let rewrite = Rewrite {
+        text: root.text.unwrap_or_default(),
+        rules: Vec::new(),
+    };
+    Ok(Conversion::new(events_to_output(events, options)?, rewrite))
+}
+
+/// [`convert_cst`] with Rust-version extensions switched on by `options`.
+pub fn convert_cst_with_options(cst_json: &str, options: &ConvertOptions) -> Result<String> {
+    if cst_json.trim().is_empty() {
+        return Err(anyhow!("Empty CST JSON input."));
+    }
+
+    let events = cst_to_ast::cst_json_to_ast_with_options(cst_json, options)?;
+    events_to_mml(events, options)
 }
 
 #[cfg(test)]
@@ -168,6 +480,154 @@ mod tests {
             assert_eq!(convert("IIm-V-I").unwrap(), convert("IIm・V・I").unwrap());
         }
 
+        #[test]
+        fn test_input_length_limit() {
+            let song = "C F G C ".repeat(200);
+            assert!(convert(&song).is_err());
+            let options = ConvertOptions {
+                max_input_len: None,
+                ..Default::default()
+            };
+            assert!(convert_with_options(&song, &options).is_ok());
+        }
+
+        #[test]
+        fn test_convert_lines() {
+            let options = ConvertOptions::default();
+            // Key, modes and octave shifts carry over to later lines
+            assert_eq!(
+                convert_lines("key=G\n\nI IV\nV I", &options).unwrap(),
+                convert("key=G I IV V I").unwrap()
+            );
+            assert_eq!(
+                convert_lines("drop2 octave up\nC/E F", &options).unwrap(),
+                convert("drop2 octave up C/E F").unwrap()
+            );
+            // Each line is preprocessed on its own
+            assert_eq!(
+                convert_lines("ii-V-I\nNashville 1 4", &options).unwrap(),
+                convert("IIm V I 1 | 4").unwrap()
+            );
+            // The length limit applies per line
+            let song = "C F G C\n".repeat(200);
+            assert!(convert_lines(&song, &options).is_ok());
+            let error = convert_lines("C\nC H", &options).unwrap_err();
+            assert!(error.to_string().starts_with("Line 2:"), "{error}");
+        }
+
+        #[test]
+        fn test_convert_with_source_map() {
+            let options = ConvertOptions::default();
+            let input = "  C F/A G7";
+            let (mml, source_map) = convert_with_source_map(input, &options).unwrap();
+            assert_eq!(mml, convert(input).unwrap());
+            let pairs: Vec<(&str, &str)> = source_map
+                .unwrap()
+                .iter()
+                .map(|m| {
+                    (
+                        &input[m.input.start..m.input.end],
+                        &mml[m.output.start..m.output.end],
+                    )
+                })
+                .collect();
+            assert_eq!(
+                pairs,
+                [("C", "'c1eg'"), ("F/A", "'>a1<fa<c'"), ("G7", "'g1b<df'")]
+            );
+
+            // No map when the chords are not where the input wrote them
+            for (input, mapped) in [
+                ("ii V I", false),
+                ("Eø7 A7 Dm", false),
+                ("Em7-5 A7 Dm", false),
+            ] {
+                let (mml, source_map) = convert_with_source_map(input, &options).unwrap();
+                assert_eq!(mml, convert(input).unwrap());
+                assert_eq!(source_map.is_some(), mapped, "{input}");
+            }
+
+            // Spans index the rewritten text when a dialect rule applied
+            let conversion = convert_explained("ii-V", &options).unwrap();
+            let text = &conversion.rewrite.text;
+            let inputs: Vec<&str> = conversion
+                .source_map
+                .iter()
+                .map(|m| &text[m.input.start..m.input.end])
+                .collect();
+            assert_eq!(inputs, ["IIm", "V"]);
+        }
+
+        #[test]
+        fn test_chord_spelling() {
+            let options = ConvertOptions {
+                spelling: Spelling::Chord,
+                ..Default::default()
+            };
+            let convert = |input| convert_with_options(input, &options).unwrap();
+            // Letters follow the chord root as written
+            assert_eq!(convert("Fm"), "v11'f1a-<c'");
+            assert_eq!(convert("Gb"), "v11'g-1b-<d-'");
+            assert_eq!(convert("F#"), "v11'f+1a+<c+'");
+            assert_eq!(convert("Cdim"), "v11'c1e-g-'");
+            // Degrees are spelled from the key letter
+            assert_eq!(convert("key=Eb IV"), "v11'a-1<ce-'");
+            // Slash chords: bass letter from the lower root
+            assert_eq!(convert("Ab/Gb"), "v11'>g-1a-<ce-'");
+            // The default stays JS-compatible
+            assert_eq!(crate::convert("Fm").unwrap(), "v11'f1g+<c'");
+        }
+
+        #[test]
+        fn test_scale_degrees() {
+            let options = ConvertOptions {
+                degrees: Degrees::Scale,
+                ..Default::default()
+            };
+            let convert = |input| convert_with_options(input, &options).unwrap();
+            // III of A aeolian is C (degrees sit above the key root); the
+            // default counts from A major (C#)
+            assert_eq!(convert("key=A Aeolian III"), "v11'<c1eg'");
+            assert_eq!(crate::convert("key=A Aeolian III").unwrap(), "v11'<c+1fg+'");
+            // A minor key implies aeolian; accidentals apply on top
+            assert_eq!(convert("key=Am VI"), "v11'<f1a<c'");
+            assert_eq!(convert("key=Am #VI"), "v11'<f+1a+<c+'");
+            assert_eq!(convert("Dorian bIII"), crate::convert("Dorian D").unwrap());
+            // A later major key resets the scale
+            assert_eq!(convert("key=Am key=C III"), crate::convert("E").unwrap());
+        }
+
+        #[test]
+        fn test_diatonic_quality() {
+            let triads = ConvertOptions {
+                diatonic_quality: DiatonicQuality::Triad,
+                ..Default::default()
+            };
+            let sevenths = ConvertOptions {
+                diatonic_quality: DiatonicQuality::Seventh,
+                ..Default::default()
+            };
+            let expected = |input| crate::convert(input).unwrap();
+            assert_eq!(
+                convert_with_options("II V I", &triads).unwrap(),
+                expected("IIm V I")
+            );
+            assert_eq!(
+                convert_with_options("II V I VII", &sevenths).unwrap(),
+                expected("IIm7 V7 IM7 VIIm7(b5)")
+            );
+            // Written qualities and altered degrees are kept
+            assert_eq!(
+                convert_with_options("II7 bVII", &sevenths).unwrap(),
+                expected("II7 bVII")
+            );
+            // Minor key: v is minor in aeolian
+            assert_eq!(
+                convert_with_options("key=Am I IV V", &triads).unwrap(),
+                expected("key=Am Im IVm Vm")
+            );
+        }
+
         #[test]
         fn test_convert_whitespace_trimmed() {
             let result = convert("  C  ").unwrap();
@@ -175,6 +635,126 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_convert_explained_chords_and_warnings() {
+        let options = ConvertOptions::default();
+        let conversion = convert_explained("C G7", &options).unwrap();
+        let chords: Vec<(&[i32], Option<Span>)> = conversion
+            .chords
+            .iter()
+            .map(|c| (c.notes.as_slice(), c.span))
+            .collect();
+        assert_eq!(
+            chords,
+            [
+                (&[60, 64, 67][..], Some(Span { start: 0, end: 1 })),
+                (&[67, 71, 74, 77][..], Some(Span { start: 2, end: 4 })),
+            ]
+        );
+        assert!(conversion.warnings.is_empty());
+
+        let input = "octave up ".repeat(6) + "C";
+        let conversion = convert_explained(&input, &options).unwrap();
+        assert_eq!(conversion.chords[0].notes, [132, 136, 139]);
+        assert_eq!(conversion.warnings.len(), 1);
+        assert_eq!(conversion.warnings[0].span, conversion.chords[0].span);
+    }
+
+    #[test]
+    fn test_transpose_option() {
+        let options = ConvertOptions {
+            transpose: 2,
+            ..ConvertOptions::default()
+        };
+        assert_eq!(
+            convert_with_options("key=C Dm G7 I", &options).unwrap(),
+            convert("key=D Em A7 I").unwrap()
+        );
+    }
+
+    #[test]
+    fn test_detect_key_option() {
+        let options = ConvertOptions {
+            detect_key: true,
+            ..ConvertOptions::default()
+        };
+        // Estimated as Bb major, which spells flats
+        assert_eq!(
+            convert_with_options("Bb Eb F7 Bb", &options).unwrap(),
+            convert("key=Bb Bb Eb F7 Bb").unwrap()
+        );
+        // A key the input sets is kept
+        assert_eq!(
+            convert_with_options("key=E Bb Eb F7 Bb", &options).unwrap(),
+            convert("key=E Bb Eb F7 Bb").unwrap()
+        );
+        assert_ne!(convert("key=E Bb").unwrap(), convert("key=Bb Bb").unwrap());
+    }
+
+    #[test]
+    fn test_scale_runs_option() {
+        let options = ConvertOptions {
+            scale_runs: true,
+            ..ConvertOptions::default()
+        };
+        let conversion = convert_explained("Dm7 G7", &options).unwrap();
+        assert_eq!(
+            conversion.mml,
+            "v11'd1fa<c''d8''e8''f8''g8''a8''b8''<c8''<d8'\
+             'g1b<df''g8''a8''b8''<c8''<d8''<e8''<f8''<g8'"
+        );
+        // The source map and notes have the chords alone
+        assert_eq!(conversion.chords.len(), 2);
+        let output = conversion.source_map[1].output;
+        assert_eq!(&conversion.mml[output.start..output.end], "'g1b<df'");
+    }
+
+    #[test]
+    fn test_reharmonize_option() {
+        let options = ConvertOptions {
+            reharmonize: vec![Reharmonization::TritoneSubstitution],
+            ..ConvertOptions::default()
+        };
+        let conversion = convert_explained("Dm7 G7 | C", &options).unwrap();
+        assert_eq!(conversion.mml, "v11'd2fa<c''c+2fg+b'/*|*/'c1eg'");
+        assert_eq!(chord_text(&conversion.events), "Dm7 Db7 | C");
+
+        // Later lines keep their own events, in the key of earlier ones
+        let options = ConvertOptions {
+            reharmonize: vec![Reharmonization::SecondaryDominants],
+            ..ConvertOptions::default()
+        };
+        let mut converter = Converter::with_options(options).unwrap();
+        let mut state = LineState::default();
+        converter.convert_line("key=Am", &mut state).unwrap();
+        let conversion = converter.convert_line("Dm E Am", &mut state).unwrap();
+        assert_eq!(chord_text(&conversion.events), "Dm E7 Am");
+    }
+
+    #[test]
+    fn test_convert_error_span() {
+        let options = ConvertOptions::default();
+        let error = convert_explained("C $ F", &options).unwrap_err();
+        let input_error = error.downcast_ref::<InputError>().unwrap();
+        assert_eq!(input_error.span, Span { start: 2, end: 3 });
+        assert_eq!(error.to_string(), input_error.message);
+
+        // Spans index the input as passed, leading whitespace included
+        let error = convert_explained("  C $", &options).unwrap_err();
+        let input_error = error.downcast_ref::<InputError>().unwrap();
+        assert_eq!(input_error.span, Span { start: 4, end: 5 });
+
+        // ...and not a dialect rewrite of it ("C  $" reads as "C $")
+        let error = convert_explained("C  $", &options).unwrap_err();
+        let input_error = error.downcast_ref::<InputError>().unwrap();
+        assert_eq!(input_error.span, Span { start: 3, end: 4 });
+
+        assert!(convert_explained("", &options)
+            .unwrap_err()
+            .downcast_ref::<InputError>()
+            .is_none());
+    }
+
     #[test]
     fn test_convert_cst_c_major() {
         let cst_json = r#"{
@@ -0,0 +1,217 @@
+//! Standard MIDI File output (Rust-version extension).
+//!
+//! A format-0 file on channel 1 with the [`crate::timeline`] resolution:
+//! the tempo and programs the timeline reads from inline MML, and each
+//! chord's notes held for its length at the velocity of the MML's `v11`.
+//! Notes outside the MIDI range are left out (see `Conversion::warnings`).
+//! [`midi_schedule`] times the same messages for playing in real time.
+
+use std::time::Duration;
+
+use crate::ast::OutEvent;
+use crate::timeline::{timeline, TimedKind, DEFAULT_TEMPO, TICKS_PER_QUARTER};
+
+/// `v11` of the MML's 0-15 volume scale.
+const VELOCITY: u8 = (11 * 127 / 15) as u8;
+
+/// Render `events` (e.g. `Conversion::out_events`) as a Standard MIDI File.
+///
+/// # Example
+/// ```
+/// use chord2mml_core::{to_midi, Converter};
+///
+/// let conversion = Converter::new().unwrap().convert_explained("C G7").unwrap();
+/// let smf = to_midi(&conversion.out_events);
+/// assert_eq!(&smf[..4], b"MThd");
+/// ```
+pub fn to_midi(events: &[OutEvent]) -> Vec<u8> {
+    let mut track = Vec::new();
+    let mut last_tick = 0;
+    for (tick, message) in messages(events) {
+        write_vlq(&mut track, tick - last_tick);
+        track.extend(message);
+        last_tick = tick;
+    }
+    track.extend([0x00, 0xFF, 0x2F, 0x00]); // end of track
+
+    let mut smf = Vec::with_capacity(22 + track.len());
+    smf.extend(b"MThd");
+    smf.extend(6u32.to_be_bytes());
+    smf.extend(0u16.to_be_bytes()); // format 0
+    smf.extend(1u16.to_be_bytes()); // one track
+    smf.extend((TICKS_PER_QUARTER as u16).to_be_bytes());
+    smf.extend(b"MTrk");
+    smf.extend((track.len() as u32).to_be_bytes());
+    smf.extend(track);
+    smf
+}
+
+/// A channel message of [`midi_schedule`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScheduledMessage {
+    /// When the message is due, from the start of the piece
+    pub at: Duration,
+    /// The message, status byte first
+    pub message: Vec<u8>,
+}
+
+/// The channel messages of [`to_midi`] with the times they are due at
+/// their tempo, for playing in real time. The last is a note-off at the
+/// end of the piece.
+pub fn midi_schedule(events: &[OutEvent]) -> Vec<ScheduledMessage> {
+    let mut schedule = Vec::new();
+    let mut micros_per_quarter = tempo_micros(DEFAULT_TEMPO) as u64;
+    let mut last_tick = 0;
+    let mut at = Duration::ZERO;
+    for (tick, message) in messages(events) {
+        at += Duration::from_micros(
+            (tick - last_tick) as u64 * micros_per_quarter / TICKS_PER_QUARTER as u64,
+        );
+        last_tick = tick;
+        match message.as_slice() {
+            [0xFF, 0x51, 0x03, a, b, c] => {
+                micros_per_quarter = u32::from_be_bytes([0, *a, *b, *c]) as u64;
+            }
+            _ => schedule.push(ScheduledMessage { at, message }),
+        }
+    }
+    schedule
+}
+
+/// The messages of the track in order, with their ticks.
+fn messages(events: &[OutEvent]) -> Vec<(u32, Vec<u8>)> {
+    // (tick, order at the tick, message): note-offs go before anything
+    // else at a tick so repeated notes restrike
+    let mut messages: Vec<(u32, u8, Vec<u8>)> = Vec::new();
+    let timed = timeline(events);
+    if !timed
+        .iter()
+        .any(|event| event.tick == 0 && matches!(event.kind, TimedKind::Tempo(_)))
+    {
+        messages.push((0, 1, tempo_message(DEFAULT_TEMPO)));
+    }
+    for event in timed {
+        match event.kind {
+            TimedKind::Tempo(tempo) => messages.push((event.tick, 1, tempo_message(tempo))),
+            TimedKind::Program(program) => messages.push((event.tick, 1, vec![0xC0, program])),
+            TimedKind::Chord { notes, duration } => {
+                for note in notes {
+                    let Ok(note) = u8::try_from(note) else {
+                        continue;
+                    };
+                    if note > 127 {
+                        continue;
+                    }
+                    messages.push((event.tick, 2, vec![0x90, note, VELOCITY]));
+                    messages.push((event.tick + duration, 0, vec![0x80, note, 0]));
+                }
+            }
+        }
+    }
+    messages.sort_by_key(|(tick, order, _)| (*tick, *order));
+    messages
+        .into_iter()
+        .map(|(tick, _, message)| (tick, message))
+        .collect()
+}
+
+/// Microseconds per quarter note at `tempo`.
+fn tempo_micros(tempo: u32) -> u32 {
+    (60_000_000 / tempo).min(0xFF_FFFF)
+}
+
+/// Set-tempo meta event: microseconds per quarter note.
+fn tempo_message(tempo: u32) -> Vec<u8> {
+    let [_, a, b, c] = tempo_micros(tempo).to_be_bytes();
+    vec![0xFF, 0x51, 0x03, a, b, c]
+}
+
+/// MIDI variable-length quantity: 7 bits per byte, high bit set on all
+/// but the last.
+fn write_vlq(out: &mut Vec<u8>, value: u32) {
+    let mut bytes = vec![(value & 0x7F) as u8];
+    let mut rest = value >> 7;
+    while rest > 0 {
+        bytes.push((rest & 0x7F) as u8 | 0x80);
+        rest >>= 7;
+    }
+    out.extend(bytes.iter().rev());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::notes;
+
+    #[test]
+    fn test_write_vlq() {
+        for (value, expected) in [
+            (0, vec![0x00]),
+            (0x7F, vec![0x7F]),
+            (0x80, vec![0x81, 0x00]),
+            (1920, vec![0x8F, 0x00]),
+            (0x0FFF_FFFF, vec![0xFF, 0xFF, 0xFF, 0x7F]),
+        ] {
+            let mut out = Vec::new();
+            write_vlq(&mut out, value);
+            assert_eq!(out, expected, "{}", value);
+        }
+    }
+
+    #[test]
+    fn test_to_midi() {
+        let events = [
+            OutEvent::InlineMml("t100".to_string()),
+            notes(vec![0, 4], 4),
+        ];
+        let smf = to_midi(&events);
+        assert_eq!(&smf[..14], b"MThd\0\0\0\x06\0\0\0\x01\x01\xE0");
+        assert_eq!(&smf[14..18], b"MTrk");
+        assert_eq!(
+            &smf[22..],
+            [
+                0x00, 0xFF, 0x51, 0x03, 0x09, 0x27, 0xC0, // t100: 600000 us
+                0x00, 0x90, 60, VELOCITY, 0x00, 0x90, 64, VELOCITY, // on
+                0x83, 0x60, 0x80, 60, 0x00, 0x00, 0x80, 64, 0x00, // off after 480
+                0x00, 0xFF, 0x2F, 0x00,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_midi_schedule() {
+        let events = [
+            OutEvent::InlineMml("@025".to_string()),
+            notes(vec![0], 4),
+            OutEvent::InlineMml("t60".to_string()),
+            notes(vec![7], 4),
+        ];
+        let at = |ms| Duration::from_millis(ms);
+        assert_eq!(
+            midi_schedule(&events),
+            [
+                ScheduledMessage {
+                    at: at(0),
+                    message: vec![0xC0, 25]
+                },
+                ScheduledMessage {
+                    at: at(0),
+                    message: vec![0x90, 60, VELOCITY]
+                },
+                ScheduledMessage {
+                    at: at(500),
+                    message: vec![0x80, 60, 0]
+                },
+                ScheduledMessage {
+                    at: at(500),
+                    message: vec![0x90, 67, VELOCITY]
+                },
+                // A quarter note at 60 lasts a second
+                ScheduledMessage {
+                    at: at(1500),
+                    message: vec![0x80, 67, 0]
+                },
+            ]
+        );
+    }
+}
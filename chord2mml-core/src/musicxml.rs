@@ -0,0 +1,157 @@
+//! MusicXML output (Rust-version extension).
+//!
+//! One part in 4/4 with a treble clef: each chord is a stack of notes
+//! spelled as the MML spells them, lasting its [`crate::timeline`]
+//! duration, and measures are whole notes (what a bar is in the input).
+//! Tempo and program changes from inline MML become `<sound>` elements.
+
+use std::fmt::Write;
+
+use crate::ast::OutEvent;
+use crate::notes2mml::spell_notes;
+use crate::options::Spelling;
+use crate::timeline::{timeline, TimedKind, TICKS_PER_QUARTER, TICKS_PER_WHOLE};
+
+/// Render `events` (e.g. `Conversion::out_events`) as a MusicXML
+/// (partwise) document.
+///
+/// # Example
+/// ```
+/// use chord2mml_core::{to_musicxml, Converter, Spelling};
+///
+/// let conversion = Converter::new().unwrap().convert_explained("C").unwrap();
+/// let xml = to_musicxml(&conversion.out_events, Spelling::Key);
+/// assert!(xml.contains("<step>E</step>"));
+/// ```
+pub fn to_musicxml(events: &[OutEvent], spelling: Spelling) -> String {
+    let mut spelled = spell_notes(events, spelling).into_iter();
+    let timed = timeline(events);
+    let first_program = timed.iter().find_map(|event| match event.kind {
+        TimedKind::Program(program) => Some(program),
+        _ => None,
+    });
+
+    let mut xml = String::from(concat!(
+        r#"<?xml version="1.0" encoding="UTF-8" standalone="no"?>"#,
+        "\n",
+        r#"<!DOCTYPE score-partwise PUBLIC "-//Recordare//DTD MusicXML 4.0 Partwise//EN" "http://www.musicxml.org/dtds/partwise.dtd">"#,
+        "\n",
+        r#"<score-partwise version="4.0">"#,
+        "\n  <part-list>\n",
+        "    <score-part id=\"P1\">\n",
+        "      <part-name>Chords</part-name>\n",
+        "      <score-instrument id=\"P1-I1\"><instrument-name>Chords</instrument-name></score-instrument>\n",
+    ));
+    let _ = writeln!(
+        xml,
+        "      <midi-instrument id=\"P1-I1\"><midi-channel>1</midi-channel><midi-program>{}</midi-program></midi-instrument>",
+        first_program.unwrap_or(0) as u32 + 1
+    );
+    xml.push_str("    </score-part>\n  </part-list>\n  <part id=\"P1\">\n");
+
+    let mut measure = 1;
+    xml.push_str(concat!(
+        "    <measure number=\"1\">\n",
+        "      <attributes>\n",
+        "        <divisions>480</divisions>\n",
+        "        <time><beats>4</beats><beat-type>4</beat-type></time>\n",
+        "        <clef><sign>G</sign><line>2</line></clef>\n",
+        "      </attributes>\n",
+    ));
+    for event in timed {
+        while event.tick >= measure * TICKS_PER_WHOLE {
+            measure += 1;
+            let _ = writeln!(xml, "    </measure>\n    <measure number=\"{}\">", measure);
+        }
+        match event.kind {
+            TimedKind::Tempo(tempo) => {
+                let _ = writeln!(
+                    xml,
+                    "      <direction placement=\"above\"><direction-type><metronome><beat-unit>quarter</beat-unit><per-minute>{}</per-minute></metronome></direction-type><sound tempo=\"{}\"/></direction>",
+                    tempo, tempo
+                );
+            }
+            TimedKind::Program(program) => {
+                let _ = writeln!(
+                    xml,
+                    "      <sound><midi-instrument id=\"P1-I1\"><midi-program>{}</midi-program></midi-instrument></sound>",
+                    program as u32 + 1
+                );
+            }
+            TimedKind::Chord { duration, .. } => {
+                let notes = spelled.next().unwrap_or_default();
+                for (i, note) in notes.iter().enumerate() {
+                    xml.push_str("      <note>");
+                    if i > 0 {
+                        xml.push_str("<chord/>");
+                    }
+                    let _ = write!(xml, "<pitch><step>{}</step>", note.letter);
+                    if note.alter != 0 {
+                        let _ = write!(xml, "<alter>{}</alter>", note.alter);
+                    }
+                    let _ = write!(
+                        xml,
+                        "<octave>{}</octave></pitch><duration>{}</duration>",
+                        note.octave, duration
+                    );
+                    if let Some(note_type) = note_type(duration) {
+                        let _ = write!(xml, "<type>{}</type>", note_type);
+                    }
+                    xml.push_str("</note>\n");
+                }
+            }
+        }
+    }
+    xml.push_str("    </measure>\n  </part>\n</score-partwise>\n");
+    xml
+}
+
+/// The note type of a duration that has one without dots or tuplets.
+fn note_type(duration: u32) -> Option<&'static str> {
+    const TYPES: [(u32, &str); 7] = [
+        (4 * TICKS_PER_QUARTER, "whole"),
+        (2 * TICKS_PER_QUARTER, "half"),
+        (TICKS_PER_QUARTER, "quarter"),
+        (TICKS_PER_QUARTER / 2, "eighth"),
+        (TICKS_PER_QUARTER / 4, "16th"),
+        (TICKS_PER_QUARTER / 8, "32nd"),
+        (TICKS_PER_QUARTER / 16, "64th"),
+    ];
+    TYPES
+        .iter()
+        .find(|(ticks, _)| *ticks == duration)
+        .map(|(_, name)| *name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::notes;
+
+    #[test]
+    fn test_chords_and_measures() {
+        let events = [
+            OutEvent::Key { offset: 5 },
+            notes(vec![10, 14, 17], 2),
+            notes(vec![0, 4, 7], 2),
+            notes(vec![5, 9, 12], 1),
+        ];
+        let xml = to_musicxml(&events, Spelling::Key);
+        let notes: Vec<&str> = xml.lines().filter(|line| line.contains("<note>")).collect();
+        assert_eq!(
+            notes[0].trim(),
+            "<note><pitch><step>B</step><alter>-1</alter><octave>4</octave></pitch><duration>960</duration><type>half</type></note>"
+        );
+        assert!(notes[1].contains("<chord/>"));
+        assert_eq!(notes.len(), 9);
+        assert_eq!(xml.matches("<measure ").count(), 2);
+        assert!(xml.contains("<measure number=\"2\">\n      <note><pitch><step>F</step>"));
+    }
+
+    #[test]
+    fn test_note_type() {
+        assert_eq!(note_type(1920), Some("whole"));
+        assert_eq!(note_type(480), Some("quarter"));
+        assert_eq!(note_type(640), None);
+    }
+}
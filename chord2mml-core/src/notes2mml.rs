@@ -6,11 +6,14 @@
 //! relative octave movement, the note-length digit after the first note of
 //! each chord, and `/*|*/` for bar lines. Sharp-vs-flat spelling is chosen
 //! by the current key + scale (JS isSharpByKeyAndScale); the default is
-//! C major, which spells sharps.
+//! C major, which spells sharps. `Spelling::Chord` instead spells each
+//! note from its chord root (see `spelling`).
 
-use anyhow::{anyhow, Result};
+use anyhow::Result;
 
-use crate::ast::OutEvent;
+use crate::ast::{NotesEvent, OutEvent, SourceMapping, Span, SpelledNote};
+use crate::options::{MmlProfile, Spelling};
+use crate::spelling::mml_name;
 
 const SHARP_NAMES: [&str; 12] = [
     "c", "c+", "d", "d+", "e", "f", "f+", "g", "g+", "a", "a+", "b",
@@ -21,67 +24,140 @@ const FLAT_NAMES: [&str; 12] = [
 
 const IONIAN: [i32; 7] = [0, 2, 4, 5, 7, 9, 11];
 
-pub(crate) fn notes_to_mml(events: &[OutEvent]) -> Result<String> {
-    let twelve_ionians = create_12_ionians();
+pub(crate) fn notes_to_mml(
+    events: &[OutEvent],
+    spelling: Spelling,
+    profile: MmlProfile,
+) -> Result<String> {
+    notes_to_mml_with_source_map(events, spelling, profile).map(|(mml, _)| mml)
+}
+
+/// [`notes_to_mml`], also mapping each chord with a span to the byte
+/// range of its `'...'` group in the MML (none under
+/// `MmlProfile::Tracks`, where a chord has no single range).
+pub(crate) fn notes_to_mml_with_source_map(
+    events: &[OutEvent],
+    spelling: Spelling,
+    profile: MmlProfile,
+) -> Result<(String, Vec<SourceMapping>)> {
+    match profile {
+        MmlProfile::Mmlabc => Ok(chord_groups(events, spelling)),
+        MmlProfile::Tracks => Ok((tracks(events, spelling), Vec::new())),
+    }
+}
+
+/// The spelled notes of each chord in `events`, as the MML spells them.
+///
+/// # Example
+/// ```
+/// use chord2mml_core::{spell_notes, Converter, Spelling};
+///
+/// let conversion = Converter::new().unwrap().convert_explained("key=F Bb").unwrap();
+/// let names: Vec<String> = spell_notes(&conversion.out_events, Spelling::Key)[0]
+///     .iter()
+///     .map(|note| note.to_string())
+///     .collect();
+/// assert_eq!(names, ["Bb4", "D5", "F5"]);
+/// ```
+pub fn spell_notes(events: &[OutEvent], spelling: Spelling) -> Vec<Vec<SpelledNote>> {
+    let mut speller = Speller::new(spelling);
+    let mut chords = Vec::new();
+    for event in events {
+        speller.update(event);
+        if let OutEvent::Notes(notes_event) = event {
+            if !notes_event.notes.is_empty() {
+                chords.push(
+                    speller
+                        .names(notes_event)
+                        .iter()
+                        .map(|(name, octave)| SpelledNote::from_mml(name, *octave))
+                        .collect(),
+                );
+            }
+        }
+    }
+    chords
+}
+
+/// The default profile: each chord is one `'...'` group.
+fn chord_groups(events: &[OutEvent], spelling: Spelling) -> (String, Vec<SourceMapping>) {
+    let mut source_map = Vec::new();
+    let mut writer = GroupWriter::new(spelling);
     // 和音なのでv16では大きすぎることがある想定（JS版のコメントを踏襲）
     let mut mml = String::from("v11");
 
-    let mut key_offset = 0;
-    let mut scale_offsets: Vec<i32> = IONIAN.to_vec();
-    let mut is_sharp = is_sharp_by_key_and_scale(key_offset, &scale_offsets, &twelve_ionians)?;
-
     for event in events {
+        let group = writer.write(event, &mut mml);
+        if let (
+            Some(output),
+            OutEvent::Notes(NotesEvent {
+                span: Some(input), ..
+            }),
+        ) = (group, event)
+        {
+            source_map.push(SourceMapping {
+                input: *input,
+                output,
+            });
+        }
+    }
+
+    (mml, source_map)
+}
+
+/// Writes [`chord_groups`] one event at a time, keeping the key and
+/// scale spelling in between (so a session can resume at any event).
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct GroupWriter {
+    speller: Speller,
+}
+
+impl GroupWriter {
+    pub(crate) fn new(spelling: Spelling) -> Self {
+        GroupWriter {
+            speller: Speller::new(spelling),
+        }
+    }
+
+    /// Append the MML of `event` to `mml`; for a chord, returns the byte
+    /// range of its `'...'` group.
+    pub(crate) fn write(&mut self, event: &OutEvent, mml: &mut String) -> Option<Span> {
+        self.speller.update(event);
         let notes_event = match event {
             OutEvent::Bar => {
                 mml.push_str("/*|*/");
-                continue;
+                return None;
             }
             OutEvent::InlineMml(inline) => {
                 mml.push_str(inline);
-                continue;
-            }
-            OutEvent::Key { offset } => {
-                key_offset = *offset;
-                is_sharp = is_sharp_by_key_and_scale(key_offset, &scale_offsets, &twelve_ionians)?;
-                continue;
-            }
-            OutEvent::Scale { offsets } => {
-                scale_offsets = offsets.clone();
-                is_sharp = is_sharp_by_key_and_scale(key_offset, &scale_offsets, &twelve_ionians)?;
-                continue;
+                return None;
             }
+            OutEvent::Key { .. } | OutEvent::Scale { .. } => return None,
             OutEvent::Notes(notes_event) => notes_event,
         };
 
-        let notes = &notes_event.notes;
-        if notes.is_empty() {
-            continue;
+        if notes_event.notes.is_empty() {
+            return None;
         }
+        let names = self.speller.names(notes_event);
 
         let mut last_octave_offset = 0;
+        let group_start = mml.len();
         mml.push('\'');
 
         // A bottom note below offset 0 starts in a lower octave (drop4 etc.)
-        let mut bottom_note = notes[0];
-        while bottom_note < 0 {
-            bottom_note += 12;
+        while names[0].1 < last_octave_offset {
             mml.push('>');
             last_octave_offset -= 1;
         }
 
-        for (i, &note) in notes.iter().enumerate() {
-            let octave_offset = note.div_euclid(12);
-            while octave_offset > last_octave_offset {
+        for (i, (name, octave_offset)) in names.iter().enumerate() {
+            while *octave_offset > last_octave_offset {
                 mml.push('<');
                 last_octave_offset += 1;
             }
 
-            let name_index = note.rem_euclid(12) as usize;
-            mml.push_str(if is_sharp {
-                SHARP_NAMES[name_index]
-            } else {
-                FLAT_NAMES[name_index]
-            });
+            mml.push_str(name);
 
             if i == 0 {
                 if let Some(note_length) = notes_event.note_length {
@@ -91,9 +167,116 @@ pub(crate) fn notes_to_mml(events: &[OutEvent]) -> Result<String> {
         }
 
         mml.push('\'');
+        Some(Span {
+            start: group_start,
+            end: mml.len(),
+        })
     }
+}
+
+/// `MmlProfile::Tracks`: one `;`-separated track per voice, lowest note
+/// first, each note with an absolute octave (`o4c` is MIDI 60) and a rest
+/// where a chord has fewer voices. Bars and inline MML go to every track.
+fn tracks(events: &[OutEvent], spelling: Spelling) -> String {
+    let voices = events
+        .iter()
+        .filter_map(|event| match event {
+            OutEvent::Notes(notes_event) => Some(notes_event.notes.len()),
+            _ => None,
+        })
+        .max()
+        .unwrap_or(0)
+        .max(1);
+    let mut tracks = vec![String::from("v11"); voices];
+    let mut speller = Speller::new(spelling);
 
-    Ok(mml)
+    for event in events {
+        speller.update(event);
+        match event {
+            OutEvent::Bar => tracks.iter_mut().for_each(|track| track.push_str("/*|*/")),
+            OutEvent::InlineMml(inline) => {
+                tracks.iter_mut().for_each(|track| track.push_str(inline))
+            }
+            OutEvent::Notes(notes_event) if !notes_event.notes.is_empty() => {
+                let names = speller.names(notes_event);
+                let length = notes_event
+                    .note_length
+                    .map_or(String::new(), |length| length.to_string());
+                for (voice, track) in tracks.iter_mut().enumerate() {
+                    match names.get(voice) {
+                        Some((name, octave_offset)) => {
+                            track.push_str(&format!("o{}{}{}", 4 + octave_offset, name, length))
+                        }
+                        None => track.push_str(&format!("r{}", length)),
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    tracks.join(";")
+}
+
+/// Names notes from the current key + scale, or from the chord root's
+/// letters under `Spelling::Chord` when they are known.
+#[derive(Debug, Clone, PartialEq)]
+struct Speller {
+    spelling: Spelling,
+    twelve_ionians: Vec<Vec<i32>>,
+    key_offset: i32,
+    scale_offsets: Vec<i32>,
+    is_sharp: bool,
+}
+
+impl Speller {
+    fn new(spelling: Spelling) -> Self {
+        let twelve_ionians = create_12_ionians();
+        let is_sharp = is_sharp_by_key_and_scale(0, &IONIAN, &twelve_ionians);
+        Speller {
+            spelling,
+            twelve_ionians,
+            key_offset: 0,
+            scale_offsets: IONIAN.to_vec(),
+            is_sharp,
+        }
+    }
+
+    /// Follow key and scale changes.
+    fn update(&mut self, event: &OutEvent) {
+        match event {
+            OutEvent::Key { offset } => self.key_offset = *offset,
+            OutEvent::Scale { offsets } => self.scale_offsets = offsets.clone(),
+            _ => return,
+        }
+        self.is_sharp =
+            is_sharp_by_key_and_scale(self.key_offset, &self.scale_offsets, &self.twelve_ionians);
+    }
+
+    /// (name, octave) per note: from the chord root's letters when chord
+    /// spelling is on and known, else from the key's table.
+    fn names(&self, notes_event: &NotesEvent) -> Vec<(String, i32)> {
+        let notes = &notes_event.notes;
+        match (&notes_event.letters, self.spelling) {
+            (Some(letters), Spelling::Chord) => notes
+                .iter()
+                .zip(letters)
+                .map(|(&note, &letter)| mml_name(note, letter))
+                .collect(),
+            _ => notes
+                .iter()
+                .map(|&note| {
+                    let name_index = note.rem_euclid(12) as usize;
+                    let name = if self.is_sharp {
+                        SHARP_NAMES[name_index]
+                    } else {
+                        FLAT_NAMES[name_index]
+                    };
+                    (name.to_string(), note.div_euclid(12))
+                })
+                .collect(),
+        }
+    }
 }
 
 /// Port of create12ionians: the twelve major scales as sorted pitch-class
@@ -111,21 +294,48 @@ fn create_12_ionians() -> Vec<Vec<i32>> {
 /// Port of isSharpByKeyAndScale: find which major scale the key+scale
 /// pitch-class set spells, then choose sharp or flat per that key
 /// (C/D/E/G/A/B → sharps; Db/Eb/Gb/Ab/Bb/F → flats).
-fn is_sharp_by_key_and_scale(
-    key: i32,
-    offsets: &[i32],
-    twelve_ionians: &[Vec<i32>],
-) -> Result<bool> {
-    // JS uses plain % (a negative key would never match and throw, same here)
+///
+/// Where JS throws for a set that is no major scale (harmonic minor,
+/// whole-tone, pentatonic, ...), this falls back to the major scale
+/// sharing the most pitch classes, ties going to the one nearest the key
+/// on the circle of fifths (sharp side first).
+fn is_sharp_by_key_and_scale(key: i32, offsets: &[i32], twelve_ionians: &[Vec<i32>]) -> bool {
+    // JS uses plain % for the exact match (a negative key never matches)
     let mut key_offsets: Vec<i32> = offsets.iter().map(|o| (o + key) % 12).collect();
     key_offsets.sort_unstable();
 
     let index = twelve_ionians
         .iter()
         .position(|ionian| ionian[..] == key_offsets[..])
-        .ok_or_else(|| anyhow!("isSharpByKeyAndScale: no matching major scale"))?;
+        .unwrap_or_else(|| nearest_ionian(key, offsets, twelve_ionians));
 
-    Ok(matches!(index, 0 | 2 | 4 | 7 | 9 | 11))
+    matches!(index, 0 | 2 | 4 | 7 | 9 | 11)
+}
+
+/// Index of the major scale with the largest pitch-class overlap with the
+/// key+scale set (see `is_sharp_by_key_and_scale` for tie-breaking).
+fn nearest_ionian(key: i32, offsets: &[i32], twelve_ionians: &[Vec<i32>]) -> usize {
+    let pitch_classes: Vec<i32> = offsets.iter().map(|o| (o + key).rem_euclid(12)).collect();
+    // Walk the circle of fifths outward from the key: 0, +1, -1, +2, ...
+    let by_fifths_from_key = (0..12).map(|step: i32| {
+        let fifths = if step % 2 == 1 {
+            (step + 1) / 2
+        } else {
+            -step / 2
+        };
+        (key + fifths * 7).rem_euclid(12) as usize
+    });
+    let mut best = (0, key.rem_euclid(12) as usize);
+    for index in by_fifths_from_key {
+        let overlap = pitch_classes
+            .iter()
+            .filter(|pc| twelve_ionians[index].contains(pc))
+            .count();
+        if overlap > best.0 {
+            best = (overlap, index);
+        }
+    }
+    best.1
 }
 
 #[cfg(test)]
@@ -136,25 +346,37 @@ mod tests {
     fn notes(notes: Vec<i32>) -> OutEvent {
         OutEvent::Notes(NotesEvent {
             notes,
+            letters: None,
             note_length: None,
+            span: None,
         })
     }
 
+    fn notes_to_mml_key(events: &[OutEvent]) -> Result<String> {
+        notes_to_mml(events, Spelling::Key, MmlProfile::Mmlabc)
+    }
+
     // Expected values from the JS test suite (notes2mml block)
     #[test]
     fn test_c_major() {
-        assert_eq!(notes_to_mml(&[notes(vec![0, 4, 7])]).unwrap(), "v11'ceg'");
+        assert_eq!(
+            notes_to_mml_key(&[notes(vec![0, 4, 7])]).unwrap(),
+            "v11'ceg'"
+        );
     }
 
     #[test]
     fn test_d_major() {
-        assert_eq!(notes_to_mml(&[notes(vec![2, 6, 9])]).unwrap(), "v11'df+a'");
+        assert_eq!(
+            notes_to_mml_key(&[notes(vec![2, 6, 9])]).unwrap(),
+            "v11'df+a'"
+        );
     }
 
     #[test]
     fn test_g_major_crosses_octave() {
         assert_eq!(
-            notes_to_mml(&[notes(vec![7, 11, 14])]).unwrap(),
+            notes_to_mml_key(&[notes(vec![7, 11, 14])]).unwrap(),
             "v11'gb<d'"
         );
     }
@@ -162,7 +384,7 @@ mod tests {
     #[test]
     fn test_b_major7() {
         assert_eq!(
-            notes_to_mml(&[notes(vec![11, 15, 18, 22])]).unwrap(),
+            notes_to_mml_key(&[notes(vec![11, 15, 18, 22])]).unwrap(),
             "v11'b<d+f+a+'"
         );
     }
@@ -170,9 +392,11 @@ mod tests {
     #[test]
     fn test_note_length_after_first_note() {
         assert_eq!(
-            notes_to_mml(&[OutEvent::Notes(NotesEvent {
+            notes_to_mml_key(&[OutEvent::Notes(NotesEvent {
                 notes: vec![0, 4, 7],
+                letters: None,
                 note_length: Some(1),
+                span: None,
             })])
             .unwrap(),
             "v11'c1eg'"
@@ -182,7 +406,7 @@ mod tests {
     #[test]
     fn test_negative_bottom_note() {
         assert_eq!(
-            notes_to_mml(&[notes(vec![-12, -7, -3, 0])]).unwrap(),
+            notes_to_mml_key(&[notes(vec![-12, -7, -3, 0])]).unwrap(),
             "v11'>cfa<c'"
         );
     }
@@ -190,7 +414,7 @@ mod tests {
     #[test]
     fn test_chords_are_adjacent_without_spaces() {
         assert_eq!(
-            notes_to_mml(&[notes(vec![0, 4, 7]), notes(vec![7, 11, 14])]).unwrap(),
+            notes_to_mml_key(&[notes(vec![0, 4, 7]), notes(vec![7, 11, 14])]).unwrap(),
             "v11'ceg''gb<d'"
         );
     }
@@ -198,7 +422,7 @@ mod tests {
     #[test]
     fn test_bar_marker() {
         assert_eq!(
-            notes_to_mml(&[notes(vec![0, 4, 7]), OutEvent::Bar, notes(vec![0, 4, 7])]).unwrap(),
+            notes_to_mml_key(&[notes(vec![0, 4, 7]), OutEvent::Bar, notes(vec![0, 4, 7])]).unwrap(),
             "v11'ceg'/*|*/'ceg'"
         );
     }
@@ -207,7 +431,7 @@ mod tests {
     fn test_flat_key_spelling() {
         // key=F spells flats: Bb major triad → b- <d f
         assert_eq!(
-            notes_to_mml(&[OutEvent::Key { offset: 5 }, notes(vec![10, 14, 17])]).unwrap(),
+            notes_to_mml_key(&[OutEvent::Key { offset: 5 }, notes(vec![10, 14, 17])]).unwrap(),
             "v11'b-<df'"
         );
     }
@@ -216,7 +440,7 @@ mod tests {
     fn test_phrygian_scale_spelling() {
         // C phrygian = the Ab major set → flats
         assert_eq!(
-            notes_to_mml(&[
+            notes_to_mml_key(&[
                 OutEvent::Scale {
                     offsets: vec![0, 1, 3, 5, 7, 8, 10]
                 },
@@ -226,4 +450,78 @@ mod tests {
             "v11'd-fa-'"
         );
     }
+
+    #[test]
+    fn test_non_diatonic_scale_falls_back() {
+        // C harmonic minor is no major scale; nearest is Eb major → flats
+        assert_eq!(
+            notes_to_mml_key(&[
+                OutEvent::Scale {
+                    offsets: vec![0, 2, 3, 5, 7, 8, 11]
+                },
+                notes(vec![8, 12, 15])
+            ])
+            .unwrap(),
+            "v11'a-<ce-'"
+        );
+        // A harmonic minor shares six notes with C major → sharps
+        assert_eq!(
+            notes_to_mml_key(&[
+                OutEvent::Key { offset: 9 },
+                OutEvent::Scale {
+                    offsets: vec![0, 2, 3, 5, 7, 8, 11]
+                },
+                notes(vec![4, 8, 11])
+            ])
+            .unwrap(),
+            "v11'eg+b'"
+        );
+        // Whole-tone and pentatonic sets convert instead of erroring
+        for offsets in [vec![0, 2, 4, 6, 8, 10], vec![0, 3, 5, 7, 10]] {
+            assert!(notes_to_mml_key(&[OutEvent::Scale { offsets }, notes(vec![0, 4, 7])]).is_ok());
+        }
+    }
+
+    #[test]
+    fn test_tracks_profile() {
+        let events = [
+            OutEvent::InlineMml("t90".to_string()),
+            OutEvent::Notes(NotesEvent {
+                notes: vec![0, 4, 7, 10],
+                letters: None,
+                note_length: Some(2),
+                span: None,
+            }),
+            OutEvent::Bar,
+            notes(vec![5, 9, 12]),
+        ];
+        assert_eq!(
+            notes_to_mml(&events, Spelling::Key, MmlProfile::Tracks).unwrap(),
+            "v11t90o4c2/*|*/o4f;v11t90o4e2/*|*/o4a;v11t90o4g2/*|*/o5c;v11t90o4a+2/*|*/r"
+        );
+    }
+
+    #[test]
+    fn test_chord_spelling_uses_letters() {
+        // Fm with letters F A C spells a- even in C major
+        let fm = OutEvent::Notes(NotesEvent {
+            notes: vec![5, 8, 12],
+            letters: Some(vec![3, 5, 0]),
+            note_length: Some(1),
+            span: None,
+        });
+        assert_eq!(
+            notes_to_mml(
+                std::slice::from_ref(&fm),
+                Spelling::Chord,
+                MmlProfile::Mmlabc
+            )
+            .unwrap(),
+            "v11'f1a-<c'"
+        );
+        assert_eq!(
+            notes_to_mml(&[fm], Spelling::Key, MmlProfile::Mmlabc).unwrap(),
+            "v11'f1g+<c'"
+        );
+    }
 }
@@ -0,0 +1,100 @@
+//! Conversion options (Rust-version extensions).
+//!
+//! The defaults reproduce the original JS chord2mml output exactly, so the
+//! golden corpus runs with `ConvertOptions::default()`. Every option here
+//! opts in to behavior the JS version does not have.
+
+/// How note names are spelled in the output.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Spelling {
+    /// One sharp-or-flat table for the whole key + scale (JS
+    /// isSharpByKeyAndScale), e.g. `Fm` in C major → `fg+<c`.
+    #[default]
+    Key,
+    /// Each note letter derived from the chord root as written plus the
+    /// note's interval degree, e.g. `Fm` → `fa-<c`. Falls back to key
+    /// spelling for notes whose root letter is unknown.
+    Chord,
+}
+
+/// What degree numerals (`III`, `bVI`, `5`) count from.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Degrees {
+    /// Always the major-scale offsets from the key root, whatever the
+    /// scale (JS ROOT_DEGREE): `Aeolian bVI` is needed for A-flat in C.
+    #[default]
+    Ionian,
+    /// The active key + scale: `key=A aeolian III` is C, and `key=Am`
+    /// implies aeolian until a scale directive says otherwise. Scales
+    /// without seven notes fall back to the major-scale offsets.
+    Scale,
+}
+
+/// Quality a bare degree (`II`, no quality written) takes.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum DiatonicQuality {
+    /// Major, like any chord without a quality (JS).
+    #[default]
+    None,
+    /// The diatonic triad stacked from the active scale (`II` → `IIm`).
+    Triad,
+    /// The diatonic seventh chord (`II` → `IIm7`, `V` → `V7`).
+    Seventh,
+}
+
+/// The MML dialect of the output.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum MmlProfile {
+    /// The JS chord2mml output read by obsidian-plugin-mmlabc: each chord
+    /// a `'...'` group with relative octaves, e.g. `v11'c1eg'`.
+    #[default]
+    Mmlabc,
+    /// One `;`-separated track per voice with absolute octaves, for MML
+    /// players without chord groups, e.g. `v11o4c1;v11o4e1;v11o4g1`.
+    Tracks,
+}
+
+/// Options for [`crate::convert_with_options`] and
+/// [`crate::convert_cst_with_options`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConvertOptions {
+    pub spelling: Spelling,
+    pub degrees: Degrees,
+    pub diatonic_quality: DiatonicQuality,
+    /// Longest input [`crate::convert_with_options`] accepts, in
+    /// characters (per line for [`crate::convert_lines`]); `None` for no
+    /// limit.
+    /// Defaults to the JS version's 1000.
+    pub max_input_len: Option<usize>,
+    /// Semitones to transpose every chord and key by (negative for down).
+    pub transpose: i32,
+    pub mml_profile: MmlProfile,
+    /// Spell notes in the key [`crate::detect_key`] estimates from the
+    /// chords when the input has no `key=` directive, instead of C major.
+    /// Degrees still count from C.
+    pub detect_key: bool,
+    /// After each chord, write a run up its first [`crate::chord_scales`]
+    /// scale to the octave in eighth notes. MML only: the other outputs
+    /// and the source map have the chords alone.
+    pub scale_runs: bool,
+    /// [`crate::reharmonize`] operations applied, in order, to the parsed
+    /// events before they convert (in the key `detect_key` estimates, when
+    /// set and the input has no key).
+    pub reharmonize: Vec<crate::Reharmonization>,
+}
+
+impl Default for ConvertOptions {
+    fn default() -> Self {
+        ConvertOptions {
+            spelling: Spelling::default(),
+            degrees: Degrees::default(),
+            diatonic_quality: DiatonicQuality::default(),
+            max_input_len: Some(1000),
+            transpose: 0,
+            mml_profile: MmlProfile::default(),
+            detect_key: false,
+            scale_runs: false,
+            reharmonize: Vec::new(),
+        }
+    }
+}
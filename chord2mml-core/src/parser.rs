@@ -9,40 +9,100 @@
 //! - Requires a C toolchain (the grammar crate compiles C in its `build.rs`),
 //!   which is why this module is gated behind the `tree-sitter` feature and
 //!   excluded from `wasm32-unknown-unknown` builds. WASM builds parse with
-//!   web-tree-sitter (JavaScript) instead and call `convert_cst`.
+//!   the pure-Rust parser (`pure_parser.rs`), or with web-tree-sitter
+//!   (JavaScript) and call `convert_cst`.
 
 use anyhow::{anyhow, Result};
 use std::collections::HashMap;
-use tree_sitter::{Node, Parser};
+use tree_sitter::{Node, Parser, Tree};
 
-use crate::ast::Event;
-use crate::cst_to_ast::{cst_to_ast, CSTNode};
+use crate::ast::{Event, InputError, Span};
+use crate::cst_to_ast::{cst_to_ast_with_context, CSTNode, DegreeContext};
+#[cfg(test)]
+use crate::options::ConvertOptions;
 
 /// Parse chord notation using Tree-sitter and convert to the event-array AST
+#[cfg(test)]
 pub(crate) fn parse_to_ast(input: &str) -> Result<Vec<Event>> {
-    let mut parser = Parser::new();
-    parser
-        .set_language(&tree_sitter_chord::language())
-        .map_err(|e| anyhow!("Failed to set TreeSitter language: {}", e))?;
+    let options = ConvertOptions::default();
+    ChordParser::new()?.parse(input, &mut DegreeContext::new(&options))
+}
 
-    let tree = parser
-        .parse(input, None)
-        .ok_or_else(|| anyhow!("Failed to parse chord notation"))?;
+/// A tree-sitter parser with the chord grammar loaded, reusable across
+/// inputs (held by [`crate::Converter`]).
+pub(crate) struct ChordParser {
+    parser: Parser,
+}
 
+impl ChordParser {
+    pub(crate) fn new() -> Result<Self> {
+        let mut parser = Parser::new();
+        parser
+            .set_language(&tree_sitter_chord::language())
+            .map_err(|e| anyhow!("Failed to set TreeSitter language: {}", e))?;
+        Ok(ChordParser { parser })
+    }
+
+    /// Parse one input into events, starting from (and updating) the key
+    /// state in `context`.
+    pub(crate) fn parse(&mut self, input: &str, context: &mut DegreeContext) -> Result<Vec<Event>> {
+        let tree = self.parse_tree(input, None)?;
+        tree_to_ast(&tree, input, context)
+    }
+
+    /// Parse into a tree, reusing the unchanged parts of `old_tree` (which
+    /// must already have been edited to match `input`).
+    pub(crate) fn parse_tree(&mut self, input: &str, old_tree: Option<&Tree>) -> Result<Tree> {
+        self.parser
+            .parse(input, old_tree)
+            .ok_or_else(|| anyhow!("Failed to parse chord notation"))
+    }
+}
+
+/// Events of a parsed tree, starting from (and updating) the key state in
+/// `context`; a tree with syntax errors is an error.
+pub(crate) fn tree_to_ast(
+    tree: &Tree,
+    input: &str,
+    context: &mut DegreeContext,
+) -> Result<Vec<Event>> {
     let root_node = tree.root_node();
 
     if root_node.has_error() {
-        return Err(anyhow!("Syntax error in chord notation: {}", input));
+        return Err(InputError {
+            message: format!("Syntax error in chord notation: {}", input),
+            span: error_span(root_node),
+        }
+        .into());
     }
 
     let cst = node_to_cst(root_node, input.as_bytes())?;
-    cst_to_ast(&cst)
+    cst_to_ast_with_context(&cst, context)
+}
+
+/// The range of the first ERROR or MISSING node under `node`.
+fn error_span(node: Node) -> Span {
+    if node.is_error() || node.is_missing() {
+        return Span {
+            start: node.start_byte(),
+            end: node.end_byte(),
+        };
+    }
+    let mut cursor = node.walk();
+    let child = node.children(&mut cursor).find(|child| child.has_error());
+    match child {
+        Some(child) => error_span(child),
+        None => Span {
+            start: node.start_byte(),
+            end: node.end_byte(),
+        },
+    }
 }
 
 /// Serialize a tree-sitter node into the CSTNode JSON shape shared with
 /// web-tree-sitter: named children with a field name go to `fields`, other
 /// named children go to `children`.
-fn node_to_cst(node: Node, source: &[u8]) -> Result<CSTNode> {
+pub(crate) fn node_to_cst(node: Node, source: &[u8]) -> Result<CSTNode> {
     let mut children = Vec::new();
     let mut fields: HashMap<String, Vec<CSTNode>> = HashMap::new();
 
@@ -72,6 +132,8 @@ fn node_to_cst(node: Node, source: &[u8]) -> Result<CSTNode> {
     Ok(CSTNode {
         node_type: node.kind().to_string(),
         text: Some(text),
+        start_index: Some(node.start_byte()),
+        end_index: Some(node.end_byte()),
         children,
         fields,
     })
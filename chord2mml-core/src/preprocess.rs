@@ -1,15 +1,21 @@
 //! Dialect preprocessing (port of chord2mml_preprocess.ts).
 //!
 //! Chord progressions written by LLMs (and humans) come in dialects the
-//! grammar does not accept directly. Each transform rewrites one dialect;
-//! the caller tries the original input first, then every combination and
-//! ordering of the transforms until one parses (JS chord2mml.parse's
-//! brute-force contract).
+//! grammar does not accept directly. Each named rule rewrites one dialect;
+//! the caller tries the original input first, then the rewrites in order
+//! until one parses (JS chord2mml.parse's contract), except that eager
+//! rules respell the input before its first parse. The JS version tried
+//! every ordering of every subset of its transforms; here each rule is
+//! tried alone and all of them chained in a fixed order, with steps that
+//! change nothing pruned, so the candidate count stays linear in the rule
+//! count.
 //!
 //! Pure string processing — shared by the native path (`convert`) and the
 //! WASM path (`preprocess_candidates` via chord2mml-wasm, with the parsing
 //! retry loop running in JavaScript where web-tree-sitter lives).
 
+use serde::Serialize;
+
 /// Dialect: replace hyphens with a middle dot (e.g. 1-3 → 1・3).
 /// A hyphen right after a chord root letter [A-G] or an accidental
 /// [#＃♯b♭] is part of a chord quality (e.g. C-7 = Cmin7) and is kept.
@@ -78,69 +84,822 @@ fn replace_word(s: &str, pattern: &str, replacement: &str) -> String {
     out
 }
 
-const TRANSFORMS: [fn(&str) -> String; 2] = [replace_hyphen_to_dot, replace_minor_roman_numerals];
+/// Dialect: Nashville Number System charts (Rust-version extension),
+/// opted into with a leading `Nashville` / `NNS` marker word, since
+/// `6- 4` already parses in the default dialect (as 6, separator, 4).
+/// Each chord is one bar and a parenthesized group shares a bar
+/// (`(1 4) 5` → `1 4 | 5`); a dash after the number means minor
+/// (`6-` → `6m`, `2-7` → `2m7`); diamonds (`<1>`, `◇1`) and pushes
+/// (`^1`, `1^`) carry no pitch and are dropped. Slash bass numbers
+/// (`1/3`) are already grammar.
+fn replace_nashville(s: &str) -> String {
+    let Some(body) = strip_marker(s, &["nashville", "nns"]) else {
+        return s.to_string();
+    };
 
-/// All candidate rewrites of the input, in the JS trial order: the
-/// original first, then every subset of the transforms in every order
-/// (JS getAllCombinations), deduplicated.
-pub fn preprocess_candidates(input: &str) -> Vec<String> {
-    let mut candidates = vec![input.to_string()];
+    // Bars of chord tokens, and the other tokens (directives) in between
+    let mut out = String::new();
+    let mut has_bar = false;
+    let mut group: Option<Vec<String>> = None;
+    for token in body.split_whitespace() {
+        let mut token = token;
+        if let Some(rest) = token.strip_prefix('(') {
+            group = Some(Vec::new());
+            token = rest;
+        }
+        let closes = token.ends_with(')');
+        let token = token.trim_end_matches(')');
+        // Bars come from the chords; written bar lines are redundant
+        if !token.is_empty() && token != "|" {
+            let chord = nashville_chord(token);
+            match (&mut group, chord) {
+                (Some(chords), Some(chord)) => chords.push(chord),
+                (None, Some(chord)) => push_bar(&mut out, &mut has_bar, &[chord]),
+                (_, None) => push_token(&mut out, token),
+            }
+        }
+        if closes {
+            if let Some(chords) = group.take() {
+                push_bar(&mut out, &mut has_bar, &chords);
+            }
+        }
+    }
+    if let Some(chords) = group {
+        push_bar(&mut out, &mut has_bar, &chords);
+    }
+    out
+}
+
+/// The input after a leading dialect marker word (one of `markers`,
+/// case-insensitive, optional `:`), or None when there is no marker.
+fn strip_marker<'a>(s: &'a str, markers: &[&str]) -> Option<&'a str> {
+    let s = s.trim_start();
+    let word_len = s
+        .find(|c: char| !c.is_ascii_alphabetic())
+        .unwrap_or(s.len());
+    let word = s[..word_len].to_ascii_lowercase();
+    if !markers.contains(&word.as_str()) {
+        return None;
+    }
+    let rest = &s[word_len..];
+    Some(rest.strip_prefix(':').unwrap_or(rest))
+}
+
+/// Rewrite one Nashville chord token into grammar form, or None when the
+/// token is not a number chord (so it passes through unchanged).
+fn nashville_chord(token: &str) -> Option<String> {
+    let token = token.trim_matches(['<', '>', '◇', '◆']);
+    let token = token.strip_prefix('^').unwrap_or(token);
+    let token = token.strip_suffix('^').unwrap_or(token);
+
+    let numeral_start = token.trim_start_matches(['#', '＃', '♯', 'b', '♭']);
+    if !numeral_start.starts_with(['1', '2', '3', '4', '5', '6', '7']) {
+        return None;
+    }
+
+    // A dash right after a number (the chord's or the bass's) is minor
+    let mut chord = String::with_capacity(token.len());
+    let mut prev = None;
+    for c in token.chars() {
+        let after_number = prev.is_some_and(|p: char| p.is_ascii_digit());
+        chord.push(if c == '-' && after_number { 'm' } else { c });
+        prev = Some(c);
+    }
+    Some(chord)
+}
+
+fn push_bar(out: &mut String, has_bar: &mut bool, chords: &[String]) {
+    if chords.is_empty() {
+        return;
+    }
+    if *has_bar {
+        push_token(out, "|");
+    }
+    *has_bar = true;
+    for chord in chords {
+        push_token(out, chord);
+    }
+}
+
+fn push_token(out: &mut String, token: &str) {
+    if !out.is_empty() {
+        out.push(' ');
+    }
+    out.push_str(token);
+}
+
+/// Dialect: Japanese and solfège note names as chord roots (Rust-version
+/// extension): katakana ド レ ミ ファ ソ ラ シ, iroha ハ ニ ホ ヘ ト イ ロ with
+/// the 嬰 (sharp) / 変 (flat) prefixes (嬰ヘ → F#, 変ロ → Bb), and
+/// capitalized Do Re Mi Fa Sol/So La Si/Ti. A Latin syllable must start a
+/// word and be followed by a chord-ish character, so `Dorian`, `Mixolydian`
+/// and `Sitar` stay intact (`Dom7` is C minor seventh).
+fn replace_solfege_roots(s: &str) -> String {
+    const KANA: [(&str, &str); 14] = [
+        ("ファ", "F"),
+        ("ド", "C"),
+        ("レ", "D"),
+        ("ミ", "E"),
+        ("ソ", "G"),
+        ("ラ", "A"),
+        ("シ", "B"),
+        ("ハ", "C"),
+        ("ニ", "D"),
+        ("ホ", "E"),
+        ("ヘ", "F"),
+        ("ト", "G"),
+        ("イ", "A"),
+        ("ロ", "B"),
+    ];
+    const SYLLABLES: [(&str, &str); 9] = [
+        ("Sol", "G"),
+        ("Do", "C"),
+        ("Re", "D"),
+        ("Mi", "E"),
+        ("Fa", "F"),
+        ("So", "G"),
+        ("La", "A"),
+        ("Si", "B"),
+        ("Ti", "B"),
+    ];
+
+    let mut out = String::with_capacity(s.len());
+    let mut i = 0;
+    'scan: while i < s.len() {
+        let rest = &s[i..];
+
+        // 嬰/変 + kana root → letter + accidental
+        let (accidental, kana_start) = match rest.chars().next() {
+            Some('嬰') => ("#", '嬰'.len_utf8()),
+            Some('変') => ("b", '変'.len_utf8()),
+            _ => ("", 0),
+        };
+        for (kana, letter) in KANA {
+            if rest[kana_start..].starts_with(kana) {
+                out.push_str(letter);
+                out.push_str(accidental);
+                i += kana_start + kana.len();
+                continue 'scan;
+            }
+        }
+
+        let prev_ok = s[..i]
+            .chars()
+            .last()
+            .is_none_or(|c| !c.is_ascii_alphanumeric());
+        if prev_ok {
+            for (syllable, letter) in SYLLABLES {
+                if let Some(after) = rest.strip_prefix(syllable) {
+                    // Lowercase letters that can start a quality (m, maj,
+                    // sus, dim, aug/add, omit/on/over) or a flat (b)
+                    let next = after.chars().next();
+                    if next.is_none_or(|c| !c.is_ascii_lowercase() || "msdaob".contains(c)) {
+                        out.push_str(letter);
+                        i += syllable.len();
+                        continue 'scan;
+                    }
+                }
+            }
+        }
+
+        let c = rest.chars().next().unwrap();
+        out.push(c);
+        i += c.len_utf8();
+    }
+    out
+}
 
-    for seq in transform_sequences() {
-        let mut candidate = input.to_string();
-        for &f in &seq {
-            candidate = f(&candidate);
+/// Dialect: German note names (Rust-version extension), opted into with a
+/// leading `German` / `Deutsch` marker word, since `B` means B natural
+/// otherwise. `H` is B natural and `B` is B-flat; `-is` sharpens and
+/// `-es` (`-s` after A and E) flattens (`Fis` → F#, `Es` → Eb, `Hes` →
+/// Bb); a lowercase root is minor (`a` → Am, `fis7` → F#m7). Applies to
+/// chord roots, slash basses and `key=` directives; other words pass
+/// through.
+fn replace_german_note_names(s: &str) -> String {
+    let Some(body) = strip_marker(s, &["german", "deutsch"]) else {
+        return s.to_string();
+    };
+
+    let mut out = String::with_capacity(body.len());
+    for (i, token) in body.split_whitespace().enumerate() {
+        if i > 0 {
+            out.push(' ');
         }
-        if !candidates.contains(&candidate) {
-            candidates.push(candidate);
+        let key_prefix_len = key_directive_prefix_len(token);
+        out.push_str(&token[..key_prefix_len]);
+        let token = &token[key_prefix_len..];
+
+        // Roots start the token and follow each slash or separator
+        let mut segment_start = 0;
+        for (j, c) in token.char_indices().chain([(token.len(), '/')]) {
+            if matches!(c, '/' | '・' | '→') {
+                let segment = &token[segment_start..j];
+                out.push_str(&german_chord(segment).unwrap_or_else(|| segment.to_string()));
+                if j < token.len() {
+                    out.push(c);
+                }
+                segment_start = j + c.len_utf8();
+            }
         }
     }
+    out
+}
 
-    candidates
+/// Length of a leading `key`, `key=` or `key:` (case-insensitive), or 0.
+fn key_directive_prefix_len(token: &str) -> usize {
+    let Some(prefix) = token.get(..3).filter(|p| p.eq_ignore_ascii_case("key")) else {
+        return 0;
+    };
+    if token[prefix.len()..].starts_with(['=', ':']) {
+        4
+    } else {
+        3
+    }
 }
 
-type Transform = fn(&str) -> String;
-type TransformSequence = Vec<Transform>;
+/// Rewrite one German chord (root + quality) into grammar form, or None
+/// when it does not start with a German root followed by a quality.
+fn german_chord(segment: &str) -> Option<String> {
+    let mut chars = segment.chars();
+    let c = chars.next()?;
+    let (letter, mut accidentals) = match c.to_ascii_uppercase() {
+        'H' => ('B', 0i32),
+        'B' => ('B', -1),
+        l @ 'A'..='G' => (l, 0),
+        _ => return None,
+    };
+    let minor = c.is_ascii_lowercase();
 
-/// JS getAllCombinations order for two transforms:
-/// [], [f0], [f1], then the permutations of [f0, f1].
-fn transform_sequences() -> Vec<TransformSequence> {
-    let n = TRANSFORMS.len();
-    let mut subsets: Vec<TransformSequence> = Vec::new();
-    for mask in 0..(1u32 << n) {
-        let seq: TransformSequence = (0..n)
-            .filter(|j| mask & (1 << j) != 0)
-            .map(|j| TRANSFORMS[j])
-            .collect();
-        subsets.push(seq);
+    // Every reading of the accidental suffixes; the longest one followed
+    // by a quality wins, so `Asus4` is A sus4 while `As7` is A-flat 7
+    let suffixes = chars.as_str();
+    let mut readings = vec![(0, 0)];
+    let mut rest = suffixes;
+    let mut offset = 0;
+    loop {
+        if let Some(r) = rest.strip_prefix("is") {
+            offset += 1;
+            rest = r;
+        } else if let Some(r) = rest.strip_prefix("es") {
+            offset -= 1;
+            rest = r;
+        } else if rest.len() == suffixes.len()
+            && matches!(letter, 'A' | 'E')
+            && rest.starts_with('s')
+        {
+            offset -= 1;
+            rest = &rest[1..];
+        } else {
+            break;
+        }
+        readings.push((suffixes.len() - rest.len(), offset));
+    }
+    let (suffix_len, offset) = readings
+        .into_iter()
+        .rev()
+        .find(|&(len, _)| is_quality_start(&suffixes[len..]))?;
+    accidentals += offset;
+    let quality = &suffixes[suffix_len..];
+
+    let mut chord = letter.to_string();
+    let mark = if accidentals > 0 { "#" } else { "b" };
+    chord.push_str(&mark.repeat(accidentals.unsigned_abs() as usize));
+    if minor && !quality.starts_with(|c: char| c.is_ascii_alphabetic()) {
+        chord.push('m');
     }
+    chord.push_str(quality);
+    Some(chord)
+}
 
-    let mut sequences = Vec::new();
-    for seq in subsets {
-        if seq.len() <= 1 {
-            sequences.push(seq);
+/// Whether text can follow a chord root: nothing, or the start of a
+/// quality, inversion, octave offset or modifier.
+fn is_quality_start(text: &str) -> bool {
+    const WORDS: [&str; 12] = [
+        "m", "M", "dim", "aug", "add", "sus", "omit", "on", "over", "o1", "o3", "o5",
+    ];
+    text.is_empty()
+        || text.starts_with(|c: char| c.is_ascii_digit() || "+-(^',△".contains(c))
+        || WORDS.iter().any(|w| text.starts_with(w))
+}
+
+/// Dialect: song charts (Rust-version extension) — ChordPro text
+/// (`[Am]Hello [F]world`) and plain chords-above-lyrics text. Keeps only
+/// the chords, in order (the [`extract_chord_chart`] importer with
+/// `line_bars` off).
+fn replace_chord_chart(s: &str) -> String {
+    extract_chord_chart(s, false).unwrap_or_else(|| s.to_string())
+}
+
+/// Extract the chord progression from a song chart, or None when the text
+/// is not one (no ChordPro `[chord]` and no chord-only line next to a
+/// lyric line).
+///
+/// Chords come from ChordPro brackets and from lines holding only chords
+/// and bars (after an optional `Verse:`-style label); lyric lines and
+/// other ChordPro directives are dropped, except `{key: G}` and
+/// `{tempo: 120}`, which become `key=G` and `bpm120`. With `line_bars`,
+/// each source line becomes one bar (`C G | Am F`).
+pub fn extract_chord_chart(text: &str, line_bars: bool) -> Option<String> {
+    let mut is_chart = false;
+    let mut has_lyrics = false;
+    let mut lines: Vec<Vec<String>> = Vec::new();
+    for line in text.lines() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        if let Some(directive) = line.strip_prefix('{').and_then(|l| l.strip_suffix('}')) {
+            lines.extend(chordpro_directive(directive).map(|d| vec![d]));
+            continue;
+        }
+        let bracketed = bracket_chords(line);
+        if !bracketed.is_empty() {
+            is_chart = true;
+            lines.push(bracketed);
+        } else if let Some(chords) = chord_line(line) {
+            lines.push(chords);
         } else {
-            permute(&seq, &mut Vec::new(), &mut sequences);
+            has_lyrics = true;
         }
     }
-    sequences
+    if !is_chart && (!has_lyrics || lines.is_empty()) {
+        return None;
+    }
+
+    let separator = if line_bars { " | " } else { " " };
+    let out: Vec<String> = lines.iter().map(|chords| chords.join(" ")).collect();
+    Some(out.join(separator))
 }
 
-fn permute(
-    remaining: &[Transform],
-    current: &mut TransformSequence,
-    out: &mut Vec<TransformSequence>,
-) {
-    if remaining.is_empty() {
-        out.push(current.clone());
-        return;
+/// `key=` / `bpm` form of a ChordPro `{key: G}` / `{tempo: 120}` directive.
+fn chordpro_directive(directive: &str) -> Option<String> {
+    let (name, value) = directive.split_once(':')?;
+    let value = value.trim();
+    match name.trim().to_ascii_lowercase().as_str() {
+        "key" if looks_like_chord(value) => Some(format!("key={value}")),
+        "tempo" if value.chars().all(|c| c.is_ascii_digit()) => Some(format!("bpm{value}")),
+        _ => None,
+    }
+}
+
+/// The chords in ChordPro brackets on a line, in order. Every bracketed
+/// token is kept, even one that does not look like a chord (`[G7alt]`,
+/// `[Co7]`), so a later rule can respell it or the parser report it.
+fn bracket_chords(line: &str) -> Vec<String> {
+    let mut chords = Vec::new();
+    let mut rest = line;
+    while let Some(start) = rest.find('[') {
+        let Some(len) = rest[start..].find(']') else {
+            break;
+        };
+        let inner = rest[start + 1..start + len].trim();
+        if !inner.is_empty() {
+            chords.push(inner.to_string());
+        }
+        rest = &rest[start + len + 1..];
+    }
+    chords
+}
+
+/// The tokens of a line holding only chords and bars, after an optional
+/// leading label ending in `:`, or None for a lyric line.
+fn chord_line(line: &str) -> Option<Vec<String>> {
+    let mut tokens = line.split_whitespace().peekable();
+    tokens.next_if(|t| t.len() > 1 && t.ends_with(':'));
+    let tokens: Vec<String> = tokens.map(str::to_string).collect();
+    let is_chords = tokens.iter().any(|t| looks_like_chord(t))
+        && tokens
+            .iter()
+            .all(|t| looks_like_chord(t) || matches!(t.as_str(), "|" | "-" | "→" | "・"));
+    is_chords.then_some(tokens)
+}
+
+/// Whether a word reads as a chord symbol (root letter, accidentals,
+/// quality words and symbols, slash bass) rather than a lyric word.
+fn looks_like_chord(word: &str) -> bool {
+    const QUALITY_WORDS: [&str; 9] = ["maj", "min", "dim", "aug", "sus", "add", "omit", "m", "M"];
+    word.split('/').all(|part| {
+        let mut rest = match part.strip_prefix(|c: char| matches!(c, 'A'..='G')) {
+            Some(rest) => rest.trim_start_matches(['#', 'b', '♯', '♭']),
+            None => return false,
+        };
+        while let Some(c) = rest.chars().next() {
+            if let Some(w) = QUALITY_WORDS.iter().find(|w| rest.starts_with(*w)) {
+                rest = &rest[w.len()..];
+            } else if c.is_ascii_digit() || "#b♯♭+-()^,'°ø△Δ".contains(c) {
+                rest = &rest[c.len_utf8()..];
+            } else {
+                return false;
+            }
+        }
+        true
+    })
+}
+
+/// Dialect: chord spellings common in LLM output and ASCII charts
+/// (Rust-version extension). These spellings parse as written but mean
+/// something else there (`Cm7b5` as Cm7 then a B-flat chord, `C / F` as
+/// half bars), so the rule is eager: the respelled reading is tried first
+/// and the input as written only when it fails.
+/// - half-diminished `ø`, `ø7`, `m7-5`, `m7b5` → `m7(b5)`
+/// - diminished seventh `°7`, `o7`, `dim7` → `dim(add6)`; `°` → `dim`
+/// - major seventh `^7`, `Δ7`, `△7`, `Δ` → `maj7` (`^9`, `Δ9` → `maj9`)
+/// - minor-major seventh `m(maj7)`, `mM7`, `-Δ7`, ... → `madd7`
+/// - tension lists `7(9,13)`, `7/9`, `6/9`, `7b5`, `7#9` → `(add9)`,
+///   `(#9)`-style modifiers; `alt` is `(#5)(b9)(#9)`
+/// - `C / F / G` (every slash group a single chord) → `C F G`
+///
+/// Roots are letters or roman-numeral degrees (`V7alt`).
+fn replace_chord_spellings(s: &str) -> String {
+    let s = replace_slash_separators(s);
+    let mut out = String::with_capacity(s.len());
+    for (i, token) in s.split_whitespace().enumerate() {
+        if i > 0 {
+            out.push(' ');
+        }
+        // Chords start the token and follow each separator; a hyphen
+        // separates only when a chord root follows (`Em7-5` is one chord)
+        let mut chord_start = 0;
+        for (j, c) in token.char_indices().chain([(token.len(), '・')]) {
+            let hyphen_separator =
+                c == '-' && token[j + 1..].starts_with(|c: char| matches!(c, 'A'..='G'));
+            if matches!(c, '・' | '→') || hyphen_separator {
+                let chord = &token[chord_start..j];
+                out.push_str(&respell_chord(chord).unwrap_or_else(|| chord.to_string()));
+                if j < token.len() {
+                    out.push(c);
+                }
+                chord_start = j + c.len_utf8();
+            }
+        }
+    }
+    out
+}
+
+/// `C / F / G` → `C F G` when there is no bar line and every group
+/// between the spaced slashes is one chord; other uses of ` / ` keep
+/// the JS half-bar meaning (`C C / F`).
+fn replace_slash_separators(s: &str) -> String {
+    let tokens: Vec<&str> = s.split_whitespace().collect();
+    let slashes = tokens.iter().filter(|&&t| t == "/").count();
+    let alternating = tokens.iter().enumerate().all(|(i, t)| {
+        if i % 2 == 1 {
+            *t == "/"
+        } else {
+            looks_like_chord(t)
+        }
+    });
+    if slashes < 2 || !alternating || tokens.len().is_multiple_of(2) {
+        return s.to_string();
     }
-    for (i, &f) in remaining.iter().enumerate() {
-        let mut rest = remaining.to_vec();
-        rest.remove(i);
-        current.push(f);
-        permute(&rest, current, out);
-        current.pop();
+    tokens
+        .iter()
+        .filter(|&&t| t != "/")
+        .copied()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Base qualities the grammar accepts, longest first.
+const GRAMMAR_BASES: [&str; 36] = [
+    "maj(9)", "Maj(9)", "MAJ(9)", "△(9)", "M(9)", "maj9", "Maj9", "MAJ9", "maj7", "Maj7", "MAJ7",
+    "min7", "Min7", "MIN7", "7sus2", "7sus4", "sus4", "sus2", "maj", "Maj", "MAJ", "min", "Min",
+    "MIN", "dim", "aug", "M9", "△9", "M7", "m7", "-7", "13", "11", "△", "M", "m",
+];
+
+/// Dialect bases and their grammar forms, longest first.
+const DIALECT_BASES: [(&str, &str); 30] = [
+    ("min(maj7)", "madd7"),
+    ("minMaj7", "madd7"),
+    ("minmaj7", "madd7"),
+    ("-(maj7)", "madd7"),
+    ("m(maj7)", "madd7"),
+    ("min7b5", "m7(b5)"),
+    ("m(M7)", "madd7"),
+    ("mMaj7", "madd7"),
+    ("mmaj7", "madd7"),
+    ("-maj7", "madd7"),
+    ("m7-5", "m7(b5)"),
+    ("m7b5", "m7(b5)"),
+    ("m7♭5", "m7(b5)"),
+    ("-7b5", "m7(b5)"),
+    ("dim7", "dim(add6)"),
+    ("-Δ7", "madd7"),
+    ("-△7", "madd7"),
+    ("mΔ7", "madd7"),
+    ("m△7", "madd7"),
+    ("mM7", "madd7"),
+    ("ø7", "m7(b5)"),
+    ("°7", "dim(add6)"),
+    ("o7", "dim(add6)"),
+    ("Δ7", "maj7"),
+    ("△7", "maj7"),
+    ("^7", "maj7"),
+    ("Δ9", "maj9"),
+    ("^9", "maj9"),
+    ("ø", "m7(b5)"),
+    ("°", "dim"),
+];
+
+/// Respell one chord (root, quality, tensions, slash part) into grammar
+/// form, or None when it is not a chord or is already grammar.
+fn respell_chord(chord: &str) -> Option<String> {
+    let root_len = chord
+        .strip_prefix(|c: char| matches!(c, 'A'..='G'))
+        .map(|rest| chord.len() - rest.trim_start_matches(['#', '＃', '♯', 'b', '♭']).len())
+        .or_else(|| {
+            // A roman-numeral degree (`V7alt`, `bVII7b9`)
+            let numeral = chord.trim_start_matches(['#', '＃', '♯', 'b', '♭']);
+            let rest = numeral.trim_start_matches(['I', 'V']);
+            (rest.len() < numeral.len()).then(|| chord.len() - rest.len())
+        })?;
+    let (root, rest) = chord.split_at(root_len);
+
+    // A slash part of upper tensions belongs to the quality (`C7/9`,
+    // `C6/9`); anything else is a bass (`C/E`, `C/3`)
+    let (quality, slash) = match rest.split_once('/') {
+        Some((quality, slash)) => (quality, Some(slash)),
+        None => (rest, None),
+    };
+    let slash_tensions = slash.filter(|slash| {
+        slash.split('/').all(|item| {
+            let number = item.trim_start_matches(['b', '#', '♭', '♯', '+', '-']);
+            number.parse::<u32>().is_ok_and(|n| n >= 9)
+        })
+    });
+
+    let mut changed = false;
+    let (mut out, mut tail) = if let Some(&(from, to)) = DIALECT_BASES
+        .iter()
+        .find(|(from, _)| quality.starts_with(from))
+    {
+        changed = true;
+        (format!("{root}{to}"), &quality[from.len()..])
+    } else {
+        // `^` alone is a major seventh, unless it is an inversion (`^1`)
+        let base = GRAMMAR_BASES
+            .iter()
+            .find(|b| quality.starts_with(*b))
+            .copied()
+            .or_else(|| {
+                quality
+                    .starts_with(|c: char| c.is_ascii_digit())
+                    .then(|| &quality[..1])
+            })
+            .unwrap_or("");
+        (format!("{root}{base}"), &quality[base.len()..])
+    };
+    if tail == "^" {
+        changed = true;
+        out.push_str("maj7");
+        tail = "";
+    }
+
+    let (modifiers, modifiers_changed) = respell_tensions(tail)?;
+    out.push_str(&modifiers);
+    changed |= modifiers_changed;
+    if let Some(tensions) = slash_tensions {
+        let (modifiers, _) = respell_tensions(&format!("({})", tensions.replace('/', ",")))?;
+        out.push_str(&modifiers);
+        changed = true;
+    } else if let Some(bass) = slash {
+        out.push('/');
+        out.push_str(bass);
+    }
+    changed.then_some(out)
+}
+
+/// Grammar modifiers for the tensions after a base quality — a run of
+/// `b5`, `#11`, `add9`, `(9,13)` ... — and whether any was rewritten.
+/// None when the text is something else (a degree, an inversion, ...),
+/// which leaves the chord as written.
+fn respell_tensions(text: &str) -> Option<(String, bool)> {
+    let mut out = String::new();
+    let mut changed = false;
+    let mut rest = text;
+    while !rest.is_empty() {
+        // Grammar modifiers as written stay
+        if let Some(len) = grammar_modifier_len(rest) {
+            out.push_str(&rest[..len]);
+            rest = &rest[len..];
+            continue;
+        }
+        changed = true;
+        let items = if let Some(group) = rest.strip_prefix('(') {
+            let end = group.find(')')?;
+            rest = &group[end + 1..];
+            group[..end].split(',').map(str::trim).collect()
+        } else {
+            let len = if rest.starts_with("alt") {
+                3
+            } else {
+                rest.char_indices()
+                    .skip(1)
+                    .find(|&(_, c)| !c.is_ascii_digit())
+                    .map_or(rest.len(), |(i, _)| i)
+            };
+            let item = &rest[..len];
+            rest = &rest[len..];
+            vec![item]
+        };
+        for item in items {
+            out.push_str(tension_modifier(item)?);
+        }
+    }
+    Some((out, changed))
+}
+
+/// Length of a grammar quality modifier at the start of text, if any.
+fn grammar_modifier_len(text: &str) -> Option<usize> {
+    for fixed in [
+        "(b5)", "(-5)", "(+5)", "(#5)", "(b9)", "(-9)", "(#9)", "(+9)", "(#11)", "(+11)", "(b13)",
+        "(-13)",
+    ] {
+        if text.starts_with(fixed) {
+            return Some(fixed.len());
+        }
+    }
+    let open = usize::from(text.starts_with('('));
+    let body = &text[open..];
+    let word = ["add", "omit", "o"]
+        .into_iter()
+        .find(|w| body.starts_with(w))?;
+    let digits = body[word.len()..]
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(body.len() - word.len());
+    if digits == 0
+        || (word != "add" && !matches!(&body[word.len()..word.len() + 1], "1" | "3" | "5"))
+    {
+        return None;
+    }
+    let mut len = open + word.len() + digits;
+    if open == 1 {
+        text[len..].starts_with(')').then_some(())?;
+        len += 1;
+    }
+    Some(len)
+}
+
+/// Grammar modifier for one tension: natural tensions are added,
+/// alterations kept.
+fn tension_modifier(item: &str) -> Option<&'static str> {
+    let item = item.trim_start_matches("add");
+    Some(
+        match item.replace('♭', "b").replace(['♯', '＃'], "#").as_str() {
+            "b5" | "-5" => "(b5)",
+            "#5" | "+5" => "(#5)",
+            "2" => "(add2)",
+            "4" => "(add4)",
+            "6" => "(add6)",
+            "9" => "(add9)",
+            "11" => "(add11)",
+            "13" => "(add13)",
+            "maj7" | "M7" | "Δ7" | "△7" => "(add7)",
+            "b9" | "-9" => "(b9)",
+            "#9" | "+9" => "(#9)",
+            "#11" | "+11" => "(#11)",
+            "b13" | "-13" => "(b13)",
+            "alt" => "(#5)(b9)(#9)",
+            _ => return None,
+        },
+    )
+}
+
+/// A named dialect rewrite. Eager rules only fix spellings that have no
+/// other sensible reading, so they run on every candidate, the input as
+/// written included, ahead of its first parse.
+struct Rule {
+    name: &'static str,
+    apply: fn(&str) -> String,
+    eager: bool,
+}
+
+/// The dialect rules, in pipeline order: the song-chart importer first,
+/// since the chords it extracts can be in any of the other dialects. Rules
+/// that leave an input unchanged are pruned, so adding a rule costs one
+/// string pass per input.
+const RULES: [Rule; 7] = [
+    Rule {
+        name: "chord-chart",
+        apply: replace_chord_chart,
+        eager: false,
+    },
+    Rule {
+        name: "hyphen-to-dot",
+        apply: replace_hyphen_to_dot,
+        eager: false,
+    },
+    Rule {
+        name: "minor-roman-numerals",
+        apply: replace_minor_roman_numerals,
+        eager: false,
+    },
+    Rule {
+        name: "nashville",
+        apply: replace_nashville,
+        eager: false,
+    },
+    Rule {
+        name: "solfege-roots",
+        apply: replace_solfege_roots,
+        eager: false,
+    },
+    Rule {
+        name: "german-note-names",
+        apply: replace_german_note_names,
+        eager: false,
+    },
+    Rule {
+        name: "chord-spellings",
+        apply: replace_chord_spellings,
+        eager: true,
+    },
+];
+
+/// One candidate reading of the input: the rewritten text and the names of
+/// the dialect rules applied to get it, in order (empty for the original).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Rewrite {
+    pub text: String,
+    pub rules: Vec<&'static str>,
+}
+
+/// All candidate rewrites of the input, in trial order; see
+/// [`preprocess_rewrites`].
+pub fn preprocess_candidates(input: &str) -> Vec<String> {
+    preprocess_rewrites(input)
+        .into_iter()
+        .map(|rewrite| rewrite.text)
+        .collect()
+}
+
+/// Candidate rewrites of the input with the rules behind each, in trial
+/// order, deduplicated: the input with the eager rules applied (when they
+/// change it), the input as written, then each other rule that changes the
+/// input on its own, then all rules chained in pipeline order (each step
+/// feeding the next, with steps that change nothing skipped). Eager rules also run on top of every later candidate.
+/// This keeps the JS trial order for single dialects while staying linear
+/// in the number of rules, where the JS getAllCombinations tried every
+/// ordering of every subset.
+///
+/// The as-written candidate is the one with no rules; callers report its
+/// error when no candidate parses, so error spans locate the input.
+pub fn preprocess_rewrites(input: &str) -> Vec<Rewrite> {
+    let original = Rewrite {
+        text: input.to_string(),
+        rules: Vec::new(),
+    };
+    let mut rewrites = Vec::new();
+    let push = |rewrites: &mut Vec<Rewrite>, mut rewrite: Rewrite| {
+        apply_eager_rules(&mut rewrite);
+        if !rewrites.iter().any(|r: &Rewrite| r.text == rewrite.text) {
+            rewrites.push(rewrite);
+        }
+    };
+    push(&mut rewrites, original.clone());
+    if !rewrites.iter().any(|r| r.text == input) {
+        rewrites.push(original.clone());
+    }
+
+    for rule in RULES.iter().filter(|rule| !rule.eager) {
+        let text = (rule.apply)(input);
+        if text != input {
+            push(
+                &mut rewrites,
+                Rewrite {
+                    text,
+                    rules: vec![rule.name],
+                },
+            );
+        }
+    }
+
+    // A rule can apply only after an earlier one (solfège roots inside
+    // ChordPro brackets), so the chain runs every rule
+    let mut chained = original;
+    for rule in RULES.iter().filter(|rule| !rule.eager) {
+        let text = (rule.apply)(&chained.text);
+        if text != chained.text {
+            chained.text = text;
+            chained.rules.push(rule.name);
+            if chained.rules.len() > 1 {
+                push(&mut rewrites, chained.clone());
+            }
+        }
+    }
+
+    rewrites
+}
+
+/// Apply the eager rules to a candidate, recording the ones that change it.
+fn apply_eager_rules(rewrite: &mut Rewrite) {
+    for rule in RULES.iter().filter(|rule| rule.eager) {
+        let text = (rule.apply)(&rewrite.text);
+        if text != rewrite.text {
+            rewrite.text = text;
+            rewrite.rules.push(rule.name);
+        }
     }
 }
 
@@ -168,10 +927,174 @@ mod tests {
         assert_eq!(replace_minor_roman_numerals("ii7"), "IIm7");
     }
 
+    #[test]
+    fn test_nashville() {
+        assert_eq!(replace_nashville("Nashville: 1 4 5 1"), "1 | 4 | 5 | 1");
+        assert_eq!(replace_nashville("NNS 6- 4 1 5"), "6m | 4 | 1 | 5");
+        assert_eq!(replace_nashville("nns 2-7 5 1^"), "2m7 | 5 | 1");
+        assert_eq!(replace_nashville("NNS (1 4) <5> ^1/3"), "1 4 | 5 | 1/3");
+        // Directives pass through without starting a bar
+        assert_eq!(replace_nashville("NNS key=D 1 | 4"), "key=D 1 | 4");
+        // Without the marker nothing changes
+        assert_eq!(replace_nashville("6- 4"), "6- 4");
+    }
+
+    #[test]
+    fn test_solfege_roots() {
+        assert_eq!(replace_solfege_roots("ドm ファ ソ7 ド"), "Cm F G7 C");
+        assert_eq!(replace_solfege_roots("ハ・嬰ヘ・変ロ"), "C・F#・Bb");
+        assert_eq!(replace_solfege_roots("ラm7 レ#"), "Am7 D#");
+        assert_eq!(replace_solfege_roots("Do Rem7 Sol7 Sib"), "C Dm7 G7 Bb");
+        assert_eq!(replace_solfege_roots("Fa/La Ti"), "F/A B");
+        // Words that merely start with a syllable are left alone
+        assert_eq!(replace_solfege_roots("Dorian Do"), "Dorian C");
+        assert_eq!(
+            replace_solfege_roots("Mixolydian Sitar"),
+            "Mixolydian Sitar"
+        );
+    }
+
+    #[test]
+    fn test_german_note_names() {
+        assert_eq!(
+            replace_german_note_names("German: H B Fis Es"),
+            "B Bb F# Eb"
+        );
+        assert_eq!(
+            replace_german_note_names("Deutsch a d7 fis es"),
+            "Am Dm7 F#m Ebm"
+        );
+        assert_eq!(
+            replace_german_note_names("German As7 Asus4 Hes"),
+            "Ab7 Asus4 Bb"
+        );
+        assert_eq!(
+            replace_german_note_names("German G/H key=B Cis"),
+            "G/B key=Bb C#"
+        );
+        // Directives pass through
+        assert_eq!(replace_german_note_names("German drop2 a"), "drop2 Am");
+        assert_eq!(replace_german_note_names("H"), "H");
+    }
+
+    #[test]
+    fn test_chordpro() {
+        assert_eq!(
+            replace_chord_chart("{title: Song}\n{key: G}\n[G]Hello [Em]world\n[C]again [D7]now"),
+            "key=G G Em C D7"
+        );
+        assert_eq!(
+            extract_chord_chart("[Am]Hello [F]world\n[C]again [G]", true).unwrap(),
+            "Am F | C G"
+        );
+        // Bracketed tokens are kept even when they do not look like chords
+        assert_eq!(
+            replace_chord_chart("[Am]Hello [G7alt]world [Co7]"),
+            "Am G7alt Co7"
+        );
+        assert_eq!(replace_chord_chart("[Am]Hello [Xyz]world"), "Am Xyz");
+    }
+
+    #[test]
+    fn test_chords_over_lyrics() {
+        let song = "Verse: C      G/B\nA day in the life\nAm7    F\nAnd so it goes";
+        assert_eq!(replace_chord_chart(song), "C G/B Am7 F");
+        assert_eq!(extract_chord_chart(song, true).unwrap(), "C G/B | Am7 F");
+        // Plain progressions (no lyric line) are not charts
+        assert_eq!(extract_chord_chart("C G\nAm F", false), None);
+        assert!(!looks_like_chord("Be"));
+        assert!(looks_like_chord("Bbmaj7/D"));
+    }
+
+    #[test]
+    fn test_chord_spellings() {
+        for (input, expected) in [
+            ("Cmin(maj7)", "Cmadd7"),
+            ("C-Δ7", "Cmadd7"),
+            ("C7/9", "C7(add9)"),
+            ("C6/9", "C6(add9)"),
+            ("Bb°7", "Bbdim(add6)"),
+            ("Bbdim7", "Bbdim(add6)"),
+            ("Fø", "Fm7(b5)"),
+            ("C^7", "Cmaj7"),
+            ("C△7", "Cmaj7"),
+            ("Em7-5", "Em7(b5)"),
+            ("Em7b5-A7", "Em7(b5)-A7"),
+            ("G7(b9,#11)", "G7(b9)(#11)"),
+            ("E7#9", "E7(#9)"),
+            ("Cmaj7#11", "Cmaj7(#11)"),
+            ("G7alt", "G7(#5)(b9)(#9)"),
+            ("C7(9,13)", "C7(add9)(add13)"),
+            ("C / F / G", "C F G"),
+        ] {
+            assert_eq!(replace_chord_spellings(input), expected, "{input}");
+        }
+        // Grammar and other words are left as written
+        for input in [
+            "Cmaj9 C(add9) Cm7(b5) C7(b9)(#11) C/E C/3 C-F-G C^1 EonC C' Dorian key=C",
+            "C / C C",
+            "C-",
+        ] {
+            assert_eq!(replace_chord_spellings(input), input);
+        }
+    }
+
     #[test]
     fn test_candidates_start_with_original() {
         let candidates = preprocess_candidates("ii-V-I");
         assert_eq!(candidates[0], "ii-V-I");
         assert!(candidates.contains(&"IIm・V・I".to_string()));
     }
+
+    #[test]
+    fn test_rewrites_report_rules() {
+        let rewrites = preprocess_rewrites("ii-V-I");
+        let texts: Vec<_> = rewrites.iter().map(|r| r.text.as_str()).collect();
+        assert_eq!(texts, ["ii-V-I", "ii・V・I", "IIm-V-I", "IIm・V・I"]);
+        assert_eq!(rewrites[3].rules, ["hyphen-to-dot", "minor-roman-numerals"]);
+    }
+
+    #[test]
+    fn test_rewrites_chain_rules_after_earlier_ones() {
+        // Solfège roots only show once the chart rule has taken the
+        // chords out of their brackets
+        let rewrites = preprocess_rewrites("[ドm]Hello [ソ7]world");
+        let last = rewrites.last().unwrap();
+        assert_eq!(last.text, "Cm G7");
+        assert_eq!(last.rules, ["chord-chart", "solfege-roots"]);
+        // The spelling only shows once the hyphens are separators
+        let rewrites = preprocess_rewrites("ii-V7alt-I");
+        let last = rewrites.last().unwrap();
+        assert_eq!(last.text, "IIm・V7(#5)(b9)(#9)・I");
+        assert_eq!(
+            last.rules,
+            ["hyphen-to-dot", "minor-roman-numerals", "chord-spellings"]
+        );
+    }
+
+    #[test]
+    fn test_rewrites_skip_inactive_rules() {
+        // Nothing to rewrite: only the original
+        assert_eq!(preprocess_candidates("C F G"), ["C F G"]);
+        let rewrites = preprocess_rewrites("Nashville 1 4 5");
+        assert_eq!(rewrites[1].rules, ["nashville"]);
+        assert_eq!(rewrites.len(), 2);
+    }
+
+    #[test]
+    fn test_rewrites_respell_eagerly() {
+        // The respelled reading comes before the input as written
+        let rewrites = preprocess_rewrites("Cm7b5 F");
+        assert_eq!(rewrites[0].text, "Cm7(b5) F");
+        assert_eq!(rewrites[0].rules, ["chord-spellings"]);
+        assert_eq!(rewrites[1].text, "Cm7b5 F");
+        assert!(rewrites[1].rules.is_empty());
+        // and on top of the other rules
+        let rewrites = preprocess_rewrites("Rem7b5 Sol7");
+        assert_eq!(rewrites.last().unwrap().text, "Dm7(b5) G7");
+        assert_eq!(
+            rewrites.last().unwrap().rules,
+            ["solfege-roots", "chord-spellings"]
+        );
+    }
 }
@@ -0,0 +1,1122 @@
+//! Pure-Rust parser (no C dependency, always available).
+//!
+//! A hand-written port of `tree-sitter-chord/grammar.js` that builds the
+//! same [`CSTNode`] tree (node types, fields, byte ranges) as the
+//! tree-sitter parser, so WASM builds can convert text without
+//! web-tree-sitter. It reproduces the tree-sitter behaviors the corpus
+//! relies on:
+//! - Context-aware lexing: only the tokens valid at a position are tried,
+//!   the longest match wins, and on a tie a string literal beats a pattern
+//!   (`C 7` is C7, not C followed by degree 7).
+//! - Whitespace (`extras`) may appear between any two tokens, including
+//!   inside a chord (`C m7`, `C on E`).
+//! - A `-` right after a root is the separator when an event can follow
+//!   it (the grammar's dynamic precedence) and the minor quality otherwise.
+//!
+//! Keep this module in sync with grammar.js; the `differential` tests run
+//! both parsers over the corpus and compare the trees.
+
+use anyhow::Result;
+
+use crate::ast::{InputError, Span};
+use crate::cst_to_ast::CSTNode;
+
+/// Terminal tokens. Declaration order is the tie-break order for matches of
+/// equal length: the anonymous string tokens come first (tree-sitter
+/// prefers strings over patterns), then the named tokens in grammar order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Token {
+    Separator,
+    Slash,
+    On,
+    Over,
+    QualityBase,
+    Modifier,
+    Inversion,
+    Octave,
+    InlineAbc,
+    InlineMml,
+    Tempo,
+    MidiPc,
+    Bar,
+    BarSlash,
+    Key,
+    Scale,
+    ModeChordOverBassNote,
+    ModeSlashChordInversion,
+    ModePolychord,
+    ModeRootInv,
+    Mode1stInv,
+    Mode2ndInv,
+    Mode3rdInv,
+    ModeClose,
+    ModeDrop2,
+    ModeDrop4,
+    ModeDrop2and4,
+    ModeNoBass,
+    ModeBassIsRoot,
+    OctaveUp,
+    OctaveUpUpper,
+    OctaveUpLower,
+    OctaveDown,
+    OctaveDownUpper,
+    OctaveDownLower,
+    Note,
+    Degree,
+}
+
+const TOKENS: [Token; 37] = [
+    Token::Separator,
+    Token::Slash,
+    Token::On,
+    Token::Over,
+    Token::QualityBase,
+    Token::Modifier,
+    Token::Inversion,
+    Token::Octave,
+    Token::InlineAbc,
+    Token::InlineMml,
+    Token::Tempo,
+    Token::MidiPc,
+    Token::Bar,
+    Token::BarSlash,
+    Token::Key,
+    Token::Scale,
+    Token::ModeChordOverBassNote,
+    Token::ModeSlashChordInversion,
+    Token::ModePolychord,
+    Token::ModeRootInv,
+    Token::Mode1stInv,
+    Token::Mode2ndInv,
+    Token::Mode3rdInv,
+    Token::ModeClose,
+    Token::ModeDrop2,
+    Token::ModeDrop4,
+    Token::ModeDrop2and4,
+    Token::ModeNoBass,
+    Token::ModeBassIsRoot,
+    Token::OctaveUp,
+    Token::OctaveUpUpper,
+    Token::OctaveUpLower,
+    Token::OctaveDown,
+    Token::OctaveDownUpper,
+    Token::OctaveDownLower,
+    Token::Note,
+    Token::Degree,
+];
+
+/// A set of [`Token`]s, one bit per variant.
+#[derive(Debug, Clone, Copy)]
+struct TokenSet(u64);
+
+impl TokenSet {
+    const fn of(tokens: &[Token]) -> Self {
+        let mut bits = 0;
+        let mut i = 0;
+        while i < tokens.len() {
+            bits |= 1 << tokens[i] as u32;
+            i += 1;
+        }
+        TokenSet(bits)
+    }
+
+    const fn union(self, other: TokenSet) -> Self {
+        TokenSet(self.0 | other.0)
+    }
+
+    fn contains(self, token: Token) -> bool {
+        self.0 & (1 << token as u32) != 0
+    }
+}
+
+/// Tokens that start an event (everything but the chord continuations and
+/// the separator).
+const EVENT_START: TokenSet = TokenSet(
+    !TokenSet::of(&[
+        Token::Separator,
+        Token::Slash,
+        Token::On,
+        Token::Over,
+        Token::QualityBase,
+        Token::Modifier,
+        Token::Inversion,
+        Token::Octave,
+    ])
+    .0,
+);
+const AFTER_EVENT: TokenSet = EVENT_START.union(TokenSet::of(&[Token::Separator]));
+
+/// Where a chord (or the lower part of a slash chord) has got to; each
+/// stage allows the parts that may still follow.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Stage {
+    Root,
+    Quality,
+    Inversion,
+    Octave,
+}
+
+impl Stage {
+    fn continuations(self, with_bass: bool) -> TokenSet {
+        let parts: &[Token] = match self {
+            Stage::Root => &[
+                Token::QualityBase,
+                Token::Modifier,
+                Token::Inversion,
+                Token::Octave,
+            ],
+            Stage::Quality => &[Token::Modifier, Token::Inversion, Token::Octave],
+            Stage::Inversion => &[Token::Octave],
+            Stage::Octave => &[],
+        };
+        let bass = if with_bass {
+            TokenSet::of(&[Token::Slash, Token::On, Token::Over])
+        } else {
+            TokenSet(0)
+        };
+        TokenSet::of(parts).union(bass).union(AFTER_EVENT)
+    }
+}
+
+/// Parse chord notation into the CST that tree-sitter-chord produces; a
+/// syntax error is an error, like a tree-sitter tree with errors.
+pub(crate) fn parse_cst(input: &str) -> Result<CSTNode> {
+    let mut parser = Parser { input, pos: 0 };
+    parser.source_file().ok_or_else(|| {
+        // The parse stops where no valid token starts (the end of the
+        // input after a trailing separator)
+        let len = input[parser.pos..].chars().next().map_or(0, char::len_utf8);
+        InputError {
+            message: format!("Syntax error in chord notation: {}", input),
+            span: Span {
+                start: parser.pos,
+                end: parser.pos + len,
+            },
+        }
+        .into()
+    })
+}
+
+struct Parser<'a> {
+    input: &'a str,
+    pos: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn source_file(&mut self) -> Option<CSTNode> {
+        let mut children = Vec::new();
+        let mut set = EVENT_START;
+        loop {
+            self.skip_whitespace();
+            if self.pos == self.input.len() {
+                // A separator must be followed by an event
+                if children
+                    .last()
+                    .is_some_and(|node: &CSTNode| node.node_type == "separator")
+                {
+                    return None;
+                }
+                break;
+            }
+            let (token, end) = lex(self.input, self.pos, set)?;
+            let start = self.pos;
+            let node = match token {
+                Token::Separator => {
+                    self.pos = end;
+                    set = EVENT_START;
+                    children.push(self.node("separator", start, end));
+                    continue;
+                }
+                Token::Note | Token::Degree => self.chord(token, end),
+                _ => {
+                    self.pos = end;
+                    self.node(event_type(token), start, end)
+                }
+            };
+            children.push(node);
+            set = AFTER_EVENT;
+        }
+
+        // Like tree-sitter's root node: leading whitespace excluded,
+        // trailing whitespace included
+        let start = self.skip_whitespace_from(0);
+        let mut root = self.node("source_file", start, self.input.len());
+        root.children = children;
+        Some(root)
+    }
+
+    /// A chord whose root token ends at `root_end`: root, quality,
+    /// inversion, octave offset and bass, each optional after the root.
+    fn chord(&mut self, root_token: Token, root_end: usize) -> CSTNode {
+        let start = self.pos;
+        let mut chord = self.node("chord", start, start);
+        self.pos = root_end;
+        self.parts(&mut chord, root_token, start, true);
+        chord.end_index = Some(self.pos);
+        chord.text = Some(self.input[start..self.pos].to_string());
+        chord
+    }
+
+    /// Parse the root (already lexed, starting at `start`) and the parts
+    /// after it into `parent`'s fields, leaving `self.pos` at the end of
+    /// the last part. The bass is only allowed in the upper part.
+    fn parts(&mut self, parent: &mut CSTNode, root_token: Token, start: usize, with_bass: bool) {
+        let root_end = self.pos;
+        let kind = if root_token == Token::Note {
+            "note"
+        } else {
+            "degree"
+        };
+        let mut root = self.node("root", start, root_end);
+        add_field(&mut root, kind, self.node(kind, start, root_end));
+        add_field(parent, "root", root);
+
+        let mut stage = Stage::Root;
+        let mut quality: Option<CSTNode> = None;
+        loop {
+            let part_start = self.skip_whitespace_from(self.pos);
+            let Some((token, end)) = lex(self.input, part_start, stage.continuations(with_bass))
+            else {
+                break;
+            };
+            match token {
+                // `-` right after the root is the minor quality unless an
+                // event follows it (then it separates, by dynamic precedence)
+                Token::Separator
+                    if stage == Stage::Root
+                        && &self.input[part_start..end] == "-"
+                        && lex(self.input, self.skip_whitespace_from(end), EVENT_START)
+                            .is_none() =>
+                {
+                    let base = self.node("quality_base", part_start, end);
+                    let mut node = self.node("quality", part_start, end);
+                    add_field(&mut node, "base", base);
+                    quality = Some(node);
+                    stage = Stage::Quality;
+                }
+                Token::QualityBase | Token::Modifier => {
+                    let (field, kind) = if token == Token::QualityBase {
+                        ("base", "quality_base")
+                    } else {
+                        ("modifier", "quality_modifier")
+                    };
+                    let part = self.node(kind, part_start, end);
+                    let node = quality.get_or_insert_with(|| self.node("quality", part_start, end));
+                    node.end_index = Some(end);
+                    add_field(node, field, part);
+                    stage = Stage::Quality;
+                }
+                Token::Inversion | Token::Octave => {
+                    if let Some(node) = quality.take() {
+                        self.finish(parent, "quality", node);
+                    }
+                    let (field, kind, next) = if token == Token::Inversion {
+                        ("inversion", "chord_inversion", Stage::Inversion)
+                    } else {
+                        ("octave", "octave_offset", Stage::Octave)
+                    };
+                    add_field(parent, field, self.node(kind, part_start, end));
+                    stage = next;
+                }
+                Token::Slash | Token::On | Token::Over => {
+                    if let Some(node) = quality.take() {
+                        self.finish(parent, "quality", node);
+                    }
+                    self.pos = end;
+                    let kind = if token == Token::Slash {
+                        "bass"
+                    } else {
+                        "on_bass"
+                    };
+                    let bass = self.bass(kind, part_start);
+                    add_field(parent, "bass", bass);
+                    return;
+                }
+                // An event or separator ends the chord
+                _ => break,
+            }
+            self.pos = end;
+        }
+        if let Some(node) = quality.take() {
+            self.finish(parent, "quality", node);
+        }
+    }
+
+    /// The bass of a slash chord or on-chord, from its `/`, `on` or `over`
+    /// at `start`; a root right after it is always the lower part
+    /// (prec.right).
+    fn bass(&mut self, kind: &'static str, start: usize) -> CSTNode {
+        let mut bass = self.node(kind, start, self.pos);
+        let lower_start = self.skip_whitespace_from(self.pos);
+        if let Some((token @ (Token::Note | Token::Degree), end)) =
+            lex(self.input, lower_start, AFTER_EVENT)
+        {
+            self.pos = end;
+            self.parts(&mut bass, token, lower_start, false);
+        }
+        bass.end_index = Some(self.pos);
+        bass.text = Some(self.input[start..self.pos].to_string());
+        bass
+    }
+
+    /// Add a node built up token by token, fixing its text to its range.
+    fn finish(&self, parent: &mut CSTNode, field: &str, mut node: CSTNode) {
+        let (start, end) = (node.start_index.unwrap_or(0), node.end_index.unwrap_or(0));
+        node.text = Some(self.input[start..end].to_string());
+        add_field(parent, field, node);
+    }
+
+    fn node(&self, kind: &str, start: usize, end: usize) -> CSTNode {
+        CSTNode {
+            node_type: kind.to_string(),
+            text: Some(self.input[start..end].to_string()),
+            start_index: Some(start),
+            end_index: Some(end),
+            ..Default::default()
+        }
+    }
+
+    fn skip_whitespace(&mut self) {
+        self.pos = self.skip_whitespace_from(self.pos);
+    }
+
+    fn skip_whitespace_from(&self, pos: usize) -> usize {
+        pos + count_while(&self.input[pos..], is_space)
+    }
+}
+
+/// The grammar's `\s` as tree-sitter compiles it: ASCII space, tab and
+/// line breaks only (an ideographic space is a syntax error).
+fn is_space(c: char) -> bool {
+    matches!(c, ' ' | '\t' | '\n' | '\r')
+}
+
+fn add_field(parent: &mut CSTNode, name: &str, node: CSTNode) {
+    parent
+        .fields
+        .entry(name.to_string())
+        .or_default()
+        .push(node);
+}
+
+/// The node type of a single-token event.
+fn event_type(token: Token) -> &'static str {
+    match token {
+        Token::InlineAbc => "inline_abc",
+        Token::InlineMml => "inline_mml",
+        Token::Tempo => "tempo",
+        Token::MidiPc => "midi_pc",
+        Token::Bar => "bar",
+        Token::BarSlash => "bar_slash",
+        Token::Key => "key",
+        Token::Scale => "scale",
+        Token::ModeChordOverBassNote => "mode_chord_over_bass_note",
+        Token::ModeSlashChordInversion => "mode_slash_chord_inversion",
+        Token::ModePolychord => "mode_polychord",
+        Token::ModeRootInv => "mode_root_inv",
+        Token::Mode1stInv => "mode_1st_inv",
+        Token::Mode2ndInv => "mode_2nd_inv",
+        Token::Mode3rdInv => "mode_3rd_inv",
+        Token::ModeClose => "mode_close",
+        Token::ModeDrop2 => "mode_drop2",
+        Token::ModeDrop4 => "mode_drop4",
+        Token::ModeDrop2and4 => "mode_drop2and4",
+        Token::ModeNoBass => "mode_no_bass",
+        Token::ModeBassIsRoot => "mode_bass_is_root",
+        Token::OctaveUp => "octave_up",
+        Token::OctaveUpUpper => "octave_up_upper",
+        Token::OctaveUpLower => "octave_up_lower",
+        Token::OctaveDown => "octave_down",
+        Token::OctaveDownUpper => "octave_down_upper",
+        Token::OctaveDownLower => "octave_down_lower",
+        _ => unreachable!("{:?} is not a single-token event", token),
+    }
+}
+
+/// The longest token in `set` at `pos`, and where it ends.
+fn lex(input: &str, pos: usize, set: TokenSet) -> Option<(Token, usize)> {
+    let rest = &input[pos..];
+    let mut best: Option<(Token, usize)> = None;
+    for token in TOKENS {
+        if !set.contains(token) {
+            continue;
+        }
+        if let Some(len) = match_token(token, rest) {
+            if len > 0 && best.is_none_or(|(_, best_len)| len > best_len) {
+                best = Some((token, len));
+            }
+        }
+    }
+    best.map(|(token, len)| (token, pos + len))
+}
+
+/// The length of the longest match of `token` at the start of `s`.
+fn match_token(token: Token, s: &str) -> Option<usize> {
+    match token {
+        Token::Separator => longest(s, &["-", "→", "・"]),
+        Token::Slash => longest(s, &["/"]),
+        Token::On => longest(s, &["on"]),
+        Token::Over => longest(s, &["over"]),
+        Token::QualityBase => match_quality_base(s),
+        Token::Modifier => match_modifier(s),
+        Token::Inversion => {
+            let b = s.as_bytes();
+            (b.len() >= 2 && b[0] == b'^' && (b'0'..=b'3').contains(&b[1])).then_some(2)
+        }
+        Token::Octave => {
+            let ups = count_bytes(s, b'\'');
+            let downs = count_bytes(&s[ups..], b',');
+            (ups + downs > 0).then_some(ups + downs)
+        }
+        Token::InlineAbc => match_comment(s, "/*/*", "*/*/"),
+        Token::InlineMml => match_comment(s, "/*", "*/"),
+        Token::Tempo => {
+            let word = match_words(s, &["bpm", "tempo"], Gap::None)?;
+            let gap = count_while(&s[word..], is_space);
+            let digits = count_while(&s[word + gap..], |c| c.is_ascii_digit());
+            (digits > 0).then(|| with_punctuation(s, word + gap + digits))
+        }
+        Token::MidiPc => match_words(s, GM_INSTRUMENT_ALIASES, Gap::Whitespace)
+            .map(|len| with_punctuation(s, len)),
+        Token::Bar => longest(s, &["|"]),
+        Token::BarSlash => s
+            .strip_prefix('/')
+            .filter(|rest| rest.starts_with([' ', '\t']))
+            .map(|_| 2),
+        Token::Key => match_key(s),
+        Token::Scale => {
+            match_words(s, SCALES, Gap::SpaceOrHyphen).map(|len| with_punctuation(s, len))
+        }
+        Token::ModeChordOverBassNote => directive(s, &["chord over bass note"]),
+        Token::ModeSlashChordInversion => directive(s, &["slash chord inversion"]),
+        Token::ModePolychord => directive(
+            s,
+            &[
+                "upper structure triad",
+                "upper structure",
+                "UST",
+                "US",
+                "polychord",
+                "poly",
+            ],
+        ),
+        Token::ModeRootInv => directive(s, &["root inv"]),
+        Token::Mode1stInv => directive(s, &["1st inv"]),
+        Token::Mode2ndInv => directive(s, &["2nd inv"]),
+        Token::Mode3rdInv => directive(s, &["3rd inv"]),
+        Token::ModeClose => directive(s, &["close harmony", "close"]),
+        Token::ModeDrop2 => directive(s, &["drop2", "drop-2", "open triad"]),
+        Token::ModeDrop4 => directive(s, &["drop4", "drop-4"]),
+        Token::ModeDrop2and4 => directive(s, &["drop2and4", "drop-2-and-4"]),
+        Token::ModeNoBass => directive(s, &["no bass"]),
+        Token::ModeBassIsRoot => {
+            directive(s, &["bass is root", "bass plays root", "bass play root"])
+        }
+        Token::OctaveUp => directive(s, OCTAVE_UP),
+        Token::OctaveUpUpper => octave_directive(s, OCTAVE_UP, false),
+        Token::OctaveUpLower => octave_directive(s, OCTAVE_UP, true),
+        Token::OctaveDown => directive(s, OCTAVE_DOWN),
+        Token::OctaveDownUpper => octave_directive(s, OCTAVE_DOWN, false),
+        Token::OctaveDownLower => octave_directive(s, OCTAVE_DOWN, true),
+        Token::Note => {
+            let letter = s.starts_with(|c: char| ('A'..='G').contains(&c));
+            letter.then(|| 1 + accidentals_len(&s[1..]))
+        }
+        Token::Degree => {
+            let accidentals = accidentals_len(s);
+            let numeral = longest(
+                &s[accidentals..],
+                &[
+                    "VII", "III", "VI", "IV", "II", "V", "I", "1", "2", "3", "4", "5", "6", "7",
+                ],
+            )?;
+            Some(accidentals + numeral)
+        }
+    }
+}
+
+const OCTAVE_UP: &[&str] = &["octave up", "octave-up"];
+const OCTAVE_DOWN: &[&str] = &["octave down", "octave-down"];
+
+/// Quality base strings (grammar.js quality_base, without the `-` shared
+/// with the separator, which the parser resolves).
+const QUALITY_BASES: &[&str] = &[
+    "maj9", "Maj9", "MAJ9", "M9", "△9", "maj(9)", "Maj(9)", "MAJ(9)", "M(9)", "△(9)", "maj7",
+    "Maj7", "MAJ7", "M7", "△", "maj", "Maj", "MAJ", "M", "min7", "Min7", "MIN7", "m7", "-7", "min",
+    "Min", "MIN", "m", "7sus2", "7sus4", "sus4", "sus2", "dim", "aug", "+", "13", "11", "9", "7",
+    "6",
+];
+
+/// Scale names (grammar.js scale); `~` is an optional space or hyphen.
+const SCALES: &[&str] = &[
+    "ionian",
+    "dorian",
+    "phrygian",
+    "lydian",
+    "mixolydian",
+    "aeolian",
+    "locrian",
+    "harmonic~minor",
+    "melodic~minor",
+    "harmonic~major",
+    "whole~tone",
+    "diminished",
+    "half~whole",
+    "whole~half",
+    "major~pentatonic",
+    "minor~pentatonic",
+    "pentatonic",
+    "blues",
+];
+
+/// GM instrument name aliases (grammar.js GM_INSTRUMENT_ALIASES); `~` is
+/// optional whitespace. Keep in sync with grammar.js.
+const GM_INSTRUMENT_ALIASES: &[&str] = &[
+    "piano~1",
+    "acoustic grand piano",
+    "grand piano",
+    "pf",
+    "piano~2",
+    "bright acoustic piano",
+    "piano~3",
+    "electric grand piano",
+    "honky-tonk piano",
+    "honky-tonk",
+    "e.piano~1",
+    "electric piano 1",
+    "rhodes",
+    "wurlitzer",
+    "e.piano~2",
+    "electric piano 2",
+    "fm piano",
+    "harpsichord",
+    "clav.",
+    "clavinet",
+    "celesta",
+    "glockenspl",
+    "glockenspiel",
+    "music box",
+    "vibraphone",
+    "marimba",
+    "xylophone",
+    "tubularbell",
+    "tubular bells",
+    "santur",
+    "dulcimer",
+    "organ~1",
+    "drawbar organ",
+    "organ~2",
+    "percussive organ",
+    "organ~3",
+    "rock organ",
+    "church org~1",
+    "church org~2",
+    "church org~3",
+    "church organ",
+    "reed organ",
+    "accordion~f",
+    "accordion~l",
+    "accordion",
+    "harmonica",
+    "bandoneon",
+    "nylon gt.",
+    "acoustic guitar (nylon)",
+    "steel gt.",
+    "acoustic guitar (steel)",
+    "jazz gt.",
+    "electric guitar (jazz)",
+    "clean gt.",
+    "electric guitar (clean)",
+    "muted gt.",
+    "electric guitar (muted)",
+    "overdrive~gt",
+    "electric guitar (overdrive)",
+    "dist.gt.",
+    "dist.gt",
+    "distortiongt.",
+    "distortiongt",
+    "electric guitar (distortion)",
+    "gt.harmonix",
+    "gt.harmonics",
+    "electric guitar (harmonics)",
+    "acoustic bass",
+    "electric bass (finger)",
+    "electric bass (picked)",
+    "electric bass (fretless)",
+    "slap bass 1",
+    "slap bass 2",
+    "synth bass 1",
+    "synth bass 2",
+    "violin",
+    "viola",
+    "cello",
+    "contrabass",
+    "tremolo strings",
+    "pizzicato strings",
+    "orchestral harp",
+    "timpani",
+    "strings~ensemble~1",
+    "strings~1",
+    "str.~1",
+    "strings~ensemble~2",
+    "strings~2",
+    "str.~2",
+    "synth strings 1",
+    "synth strings 2",
+    "voice aahs",
+    "choir aahs",
+    "choir",
+    "chor.",
+    "voice oohs",
+    "synth voice",
+    "orchestra hit",
+    "trumpet",
+    "trombone",
+    "tuba",
+    "muted trumpet",
+    "french horn",
+    "brass section",
+    "synth brass 1",
+    "synth brass 2",
+    "soprano sax",
+    "alto sax",
+    "tenor sax",
+    "baritone sax",
+    "oboe",
+    "english horn",
+    "bassoon",
+    "clarinet",
+    "piccolo",
+    "flute",
+    "recorder",
+    "pan flute",
+    "blown bottle",
+    "shakuhachi",
+    "whistle",
+    "ocarina",
+    "lead~1",
+    "square",
+    "lead~2",
+    "sawtooth",
+    "lead~3",
+    "calliope",
+    "lead~4",
+    "chiff",
+    "lead~5",
+    "charang",
+    "lead~6",
+    "voice",
+    "lead~7",
+    "fifths",
+    "lead~8",
+    "bass and lead",
+    "pad~1",
+    "new age",
+    "pad~2",
+    "warm",
+    "pad~3",
+    "polysynth",
+    "pad~4",
+    "pad~5",
+    "bowed glass",
+    "pad~6",
+    "metallic",
+    "pad~7",
+    "halo",
+    "pad~8",
+    "sweep",
+    "fx~1",
+    "rain",
+    "fx~2",
+    "soundtrack",
+    "fx~3",
+    "crystal",
+    "fx~4",
+    "atmosphere",
+    "fx~5",
+    "brightness",
+    "fx~6",
+    "goblins",
+    "fx~7",
+    "echoes",
+    "fx~8",
+    "sci-fi",
+    "sitar",
+    "banjo",
+    "shamisen",
+    "koto",
+    "kalimba",
+    "bag pipe",
+    "fiddle",
+    "shanai",
+    "tinkle bell",
+    "agogo",
+    "steel drums",
+    "woodblock",
+    "taiko",
+    "melodic tom",
+    "synth drum",
+    "reverse cymbal",
+    "guitar fret noise",
+    "breath noise",
+    "seashore",
+    "bird tweet",
+    "telephone ring",
+    "helicopter",
+    "applause",
+    "gunshot",
+];
+
+/// How `~` in a word list matches.
+#[derive(Clone, Copy)]
+enum Gap {
+    None,
+    /// Any run of spaces, tabs and newlines (GM aliases)
+    Whitespace,
+    /// An optional space or hyphen (scale names)
+    SpaceOrHyphen,
+}
+
+/// The longest of `words` at the start of `s`, with ASCII letters matched
+/// case-insensitively.
+fn match_words(s: &str, words: &[&str], gap: Gap) -> Option<usize> {
+    words
+        .iter()
+        .filter_map(|word| match_word(s, word, gap))
+        .max()
+}
+
+fn match_word(s: &str, word: &str, gap: Gap) -> Option<usize> {
+    let mut pos = 0;
+    for expected in word.chars() {
+        let rest = &s[pos..];
+        if expected == '~' {
+            pos += match gap {
+                Gap::None => 0,
+                Gap::Whitespace => count_while(rest, is_space),
+                Gap::SpaceOrHyphen => usize::from(rest.starts_with([' ', '-'])),
+            };
+            continue;
+        }
+        let c = rest.chars().next()?;
+        if !c.eq_ignore_ascii_case(&expected) {
+            return None;
+        }
+        pos += c.len_utf8();
+    }
+    Some(pos)
+}
+
+/// A mode directive: case-insensitive words with an optional trailing
+/// `,` or `.`.
+fn directive(s: &str, variants: &[&str]) -> Option<usize> {
+    match_words(s, variants, Gap::None).map(|len| with_punctuation(s, len))
+}
+
+/// `octave up/` (upper only) or `/octave up` (lower only).
+fn octave_directive(s: &str, variants: &[&str], lower: bool) -> Option<usize> {
+    let len = if lower {
+        1 + match_words(s.strip_prefix('/')?, variants, Gap::None)?
+    } else {
+        let len = match_words(s, variants, Gap::None)?;
+        s[len..].starts_with('/').then_some(len + 1)?
+    };
+    Some(with_punctuation(s, len))
+}
+
+/// `len` extended by an optional trailing `,` or `.`.
+fn with_punctuation(s: &str, len: usize) -> usize {
+    len + usize::from(s[len..].starts_with([',', '.']))
+}
+
+/// `key` [ =:]? [A-G] sharps flats (`minor` | `m`)? [,.]?
+fn match_key(s: &str) -> Option<usize> {
+    let mut len = match_word(s, "key", Gap::None)?;
+    len += usize::from(s[len..].starts_with([' ', '=', ':']));
+    if !s[len..].starts_with(|c: char| ('A'..='G').contains(&c)) {
+        return None;
+    }
+    len += 1 + accidentals_len(&s[len + 1..]);
+    len += match_word(&s[len..], "minor", Gap::None)
+        .or_else(|| s[len..].starts_with('m').then_some(1))
+        .unwrap_or(0);
+    Some(with_punctuation(s, len))
+}
+
+/// Sharps then flats, half- or full-width.
+fn accidentals_len(s: &str) -> usize {
+    let sharps = count_while(s, |c| matches!(c, '#' | '＃' | '♯'));
+    sharps + count_while(&s[sharps..], |c| matches!(c, 'b' | '♭'))
+}
+
+fn match_quality_base(s: &str) -> Option<usize> {
+    // Quartal harmony: 4.[2-9]+
+    let quartal = s
+        .strip_prefix("4.")
+        .map(|rest| count_while(rest, |c| ('2'..='9').contains(&c)))
+        .filter(|&digits| digits > 0)
+        .map(|digits| 2 + digits);
+    quartal.max(longest(s, QUALITY_BASES))
+}
+
+/// Altered fifths and tensions (grammar.js quality_modifier literals).
+const ALTERATIONS: &[&str] = &[
+    "(b5)", "(-5)", "(+5)", "(#5)", "(b9)", "(-9)", "(#9)", "(+9)", "(#11)", "(+11)", "(b13)",
+    "(-13)",
+];
+
+fn match_modifier(s: &str) -> Option<usize> {
+    let paren = usize::from(s.starts_with('('));
+    let rest = &s[paren..];
+    // \(?add[0-9]+\)?
+    let add = rest.strip_prefix("add").and_then(|digits| {
+        let n = count_while(digits, |c| c.is_ascii_digit());
+        (n > 0).then_some(3 + n)
+    });
+    // \(?(omit|o)[135]\)?
+    let omit = longest(rest, &["omit", "o"]).and_then(|word| {
+        rest[word..]
+            .starts_with(['1', '3', '5'])
+            .then_some(word + 1)
+    });
+    let pattern = add
+        .or(omit)
+        .map(|len| paren + len + usize::from(rest[len..].starts_with(')')));
+    pattern.max(longest(s, ALTERATIONS))
+}
+
+/// An inline passthrough `open` content+ `close`, where the content may not
+/// contain `*/` (grammar.js inline_mml / inline_abc).
+fn match_comment(s: &str, open: &str, close: &str) -> Option<usize> {
+    let body = s.strip_prefix(open)?;
+    let mut pos = 0;
+    loop {
+        let rest = &body[pos..];
+        if pos > 0 && rest.starts_with(close) {
+            return Some(open.len() + pos + close.len());
+        }
+        let mut chars = rest.chars();
+        pos += match chars.next()? {
+            '*' => match chars.next()? {
+                '/' => return None,
+                c => 1 + c.len_utf8(),
+            },
+            c => c.len_utf8(),
+        };
+    }
+}
+
+/// The longest of the literal `strings` at the start of `s`.
+fn longest(s: &str, strings: &[&str]) -> Option<usize> {
+    strings
+        .iter()
+        .filter(|string| s.starts_with(**string))
+        .map(|string| string.len())
+        .max()
+}
+
+fn count_while(s: &str, pred: impl Fn(char) -> bool) -> usize {
+    s.chars().take_while(|&c| pred(c)).map(char::len_utf8).sum()
+}
+
+fn count_bytes(s: &str, byte: u8) -> usize {
+    s.bytes().take_while(|&b| b == byte).count()
+}
+
+#[cfg(all(test, feature = "tree-sitter"))]
+mod differential {
+    use super::*;
+    use crate::parser::{node_to_cst, ChordParser};
+    use crate::preprocess::preprocess_candidates;
+
+    fn assert_same(parser: &mut ChordParser, input: &str) {
+        let tree = parser.parse_tree(input, None).unwrap();
+        let root = tree.root_node();
+        let expected = (!root.has_error()).then(|| node_to_cst(root, input.as_bytes()).unwrap());
+        assert_eq!(parse_cst(input).ok(), expected, "input {:?}", input);
+    }
+
+    #[test]
+    fn test_corpus_inputs() {
+        #[derive(serde::Deserialize)]
+        struct Case {
+            input: String,
+        }
+
+        let mut parser = ChordParser::new().unwrap();
+        let corpus_dir = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/corpus");
+        for entry in std::fs::read_dir(corpus_dir).unwrap() {
+            let json = std::fs::read_to_string(entry.unwrap().path()).unwrap();
+            let cases: Vec<Case> = serde_json::from_str(&json).unwrap();
+            for case in cases {
+                for candidate in preprocess_candidates(&case.input) {
+                    assert_same(&mut parser, &candidate);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_random_inputs() {
+        // Fragments chosen to hit token boundaries and the `-` ambiguity.
+        let fragments = [
+            "C",
+            "D#",
+            "Bb",
+            "E♭",
+            "F＃",
+            "IV",
+            "bVII",
+            "#iv",
+            "VII",
+            "III",
+            "IIV",
+            "1",
+            "3",
+            "7",
+            "6",
+            "9",
+            "13",
+            "m",
+            "m7",
+            "Min7",
+            "MIN",
+            "M",
+            "△9",
+            "M(9)",
+            "maj(9)",
+            "sus4",
+            "7sus4",
+            "dim",
+            "aug",
+            "+",
+            "4.5",
+            "4.",
+            "-",
+            "-7",
+            "- ",
+            "-F",
+            "/",
+            "/ ",
+            "/\t",
+            "/\n",
+            "on",
+            "onE",
+            "over",
+            "Cover",
+            "(b5)",
+            "(-5)",
+            "(#5)",
+            "(b9)",
+            "(+11)",
+            "(b13",
+            "add9",
+            "(add9)",
+            "add11)",
+            "(o1",
+            "omit5",
+            "o3",
+            "^0",
+            "^1",
+            "^4",
+            "'",
+            ",",
+            "'',",
+            "|",
+            "→",
+            "・",
+            " ",
+            "  ",
+            "\n",
+            "\t",
+            "\u{3000}",
+            ".",
+            "(",
+            ")",
+            "*",
+            "x",
+            "key=G",
+            "key C",
+            "key:F#minor",
+            "keyEb",
+            "key=Bbm",
+            "dorian",
+            "Aeolian",
+            "harmonic minor",
+            "Melodic-Minor",
+            "harmonicmajor",
+            "whole tone",
+            "diminished",
+            "half-whole",
+            "minor pentatonic",
+            "pentatonic",
+            "blues",
+            "tempo\n90.",
+            "bpm120,",
+            "octave up",
+            "/octave up",
+            "octave up/",
+            "/octave down,",
+            "octave-down/",
+            "poly",
+            "US",
+            "UST",
+            "upper structure",
+            "close",
+            "close harmony",
+            "open triad",
+            "drop2",
+            "drop-2-and-4",
+            "bass is root",
+            "no bass",
+            "1st inv",
+            "root inv.",
+            "piano 1",
+            "piano  1",
+            "piano1.",
+            "Cello",
+            "Choir",
+            "voice",
+            "polysynth",
+            "overdrive gt",
+            "Bass and lead",
+            "gt.harmonix",
+            "sci-fi",
+            "str. 1",
+            "accordion f",
+            "Bandoneon",
+            "/*c*/",
+            "/*/*a*/*/",
+            "/*",
+            "*/",
+            "**",
+            "/**/",
+            "/*a**/",
+        ];
+        let mut parser = ChordParser::new().unwrap();
+        let mut seed: u64 = 0x2545F4914F6CDD1D;
+        let mut next = move || {
+            seed ^= seed << 13;
+            seed ^= seed >> 7;
+            seed ^= seed << 17;
+            seed >> 8
+        };
+        for _ in 0..20000 {
+            let len = 1 + next() % 8;
+            let input: String = (0..len)
+                .map(|_| fragments[next() as usize % fragments.len()])
+                .collect();
+            assert_same(&mut parser, &input);
+        }
+    }
+
+    #[test]
+    fn test_whitespace_inside_chords() {
+        let mut parser = ChordParser::new().unwrap();
+        for input in [
+            "C 7", "C m7", "C on E", "C/\nE", "C / E", "C - F", "C -7", "C-", "C- 7",
+        ] {
+            assert_same(&mut parser, input);
+        }
+    }
+}
@@ -0,0 +1,571 @@
+//! Reharmonization (Rust-version extension, `ConvertOptions::reharmonize`).
+//!
+//! Operations on the parsed [`Event`] stream that make variations of a
+//! progression, each run over the result of the one before. They change
+//! and insert plain chords only; slash chords and polychords stay as
+//! written but count as neighbors by their upper chord. Inserted chords
+//! have no span, take the octave of the chord they lead to and follow the
+//! chord before them, taking their time from it so that every bar keeps
+//! its length: in `C | Am`, a ii–V makes the C a half note and the
+//! `Bm7(b5)` and `E7` quarter notes.
+//!
+//! The key is the one given to [`reharmonize`] (C major when None) until a
+//! `key=` directive changes it, and a `scale=` directive changes its notes,
+//! as in [`crate::analyze`]. Only [`Reharmonization::SecondaryDominants`],
+//! [`Reharmonization::Sevenths`] and [`Reharmonization::Ninths`] depend on
+//! it.
+
+use serde::Serialize;
+
+use crate::analysis::{chord_of, Key, HARMONIC_MINOR, MAJOR, MELODIC_MINOR, NATURAL_MINOR};
+use crate::ast::{ChordEvent, Event};
+use crate::ast2ast::{bar_to_note_length, set_note_length};
+use crate::ast2notes::chord_intervals;
+use crate::chord_text::NATURALS;
+
+/// One reharmonization operation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Reharmonization {
+    /// A dominant seventh resolving down a fifth moves by a tritone:
+    /// `G7 C` → `Db7 C`
+    TritoneSubstitution,
+    /// A ii–V is inserted before each major or minor chord that its V does
+    /// not already precede: `C Eb` → `C Fm7 Bb7 Eb`, `C Am` → `C Bm7(b5) E7 Am`
+    TwoFive,
+    /// A diatonic chord resolving down a fifth to a major or minor chord
+    /// becomes its dominant seventh: `C Am Dm G` → `C A7 D7 G`
+    SecondaryDominants,
+    /// A chord of the same quality a semitone above is inserted before
+    /// each chord not already approached by step: `C F` → `C Gb F`
+    ApproachChords,
+    /// Triads become the seventh chords of the key: `C Am F G` →
+    /// `Cmaj7 Am7 Fmaj7 G7`
+    Sevenths,
+    /// Triads and seventh chords become ninth chords, where the ninth is in
+    /// the key: `C Dm G7` → `Cmaj7(add9) Dm7(add9) G9`. A dominant seventh
+    /// whose ninth is not takes the flat ninth: `E7` → `E7(b9)` in A minor
+    Ninths,
+}
+
+/// A chord of the event stream and the key it is in.
+struct Position {
+    index: usize,
+    root: i32,
+    letter: Option<u8>,
+    quality: String,
+    intervals: Vec<i32>,
+    octave_offset: i32,
+    key: Key,
+    /// The key's notes, relative to the tonic
+    scale: Vec<i32>,
+}
+
+impl Position {
+    fn has(&self, interval: i32) -> bool {
+        self.intervals.contains(&interval)
+    }
+
+    /// Semitones from this chord's root up to `other`'s.
+    fn to(&self, other: &Position) -> i32 {
+        (other.root - self.root).rem_euclid(12)
+    }
+
+    fn is_dominant_seventh(&self) -> bool {
+        self.has(4) && self.has(10) && !self.has(3) && !self.has(11)
+    }
+
+    /// A major or minor chord with a perfect fifth, which a ii–V or a
+    /// secondary dominant can lead to
+    fn is_target(&self) -> bool {
+        self.has(7) && (self.has(3) != self.has(4))
+    }
+
+    /// The root relative to the tonic, when it is a note of the key.
+    fn degree(&self) -> Option<usize> {
+        let root = (self.root - self.key.tonic).rem_euclid(12);
+        self.scale.iter().position(|&tone| tone == root)
+    }
+
+    /// Whether all its notes are in the key; in a minor key without a
+    /// `scale=`, any of natural, harmonic and melodic minor.
+    fn is_diatonic(&self) -> bool {
+        let tones: Vec<i32> = self
+            .intervals
+            .iter()
+            .map(|interval| (self.root + interval - self.key.tonic).rem_euclid(12))
+            .collect();
+        let within = |scale: &[i32]| tones.iter().all(|tone| scale.contains(tone));
+        if self.key.minor && self.scale == NATURAL_MINOR {
+            [NATURAL_MINOR, HARMONIC_MINOR, MELODIC_MINOR]
+                .iter()
+                .any(|scale| within(scale))
+        } else {
+            within(&self.scale)
+        }
+    }
+
+    /// A chord `semitones` and `letters` above this one's root, in its octave.
+    fn chord(&self, semitones: i32, letters: u8, quality: &str) -> Event {
+        Event::Chord(ChordEvent {
+            root: (self.root + semitones).rem_euclid(12),
+            root_letter: self.letter.map(|letter| (letter + letters) % 7),
+            quality: quality.to_string(),
+            inversion: None,
+            octave_offset: self.octave_offset,
+            note_length: None,
+            span: None,
+        })
+    }
+}
+
+/// Apply `operations` to `events` (e.g. `Conversion::events`) in order, in
+/// `key` (C major when None).
+///
+/// # Example
+/// ```
+/// use chord2mml_core::{chord_text, reharmonize, Converter, Reharmonization};
+///
+/// let conversion = Converter::new().unwrap().convert_explained("C Am Dm G7 C").unwrap();
+/// let events = reharmonize(
+///     conversion.events,
+///     &[Reharmonization::SecondaryDominants, Reharmonization::TritoneSubstitution],
+///     None,
+/// );
+/// assert_eq!(chord_text(&events), "C Eb7 Ab7 Db7 C");
+/// ```
+pub fn reharmonize(
+    mut events: Vec<Event>,
+    operations: &[Reharmonization],
+    key: Option<Key>,
+) -> Vec<Event> {
+    let key = key.unwrap_or_default();
+    for operation in operations {
+        let positions = positions(&events, key);
+        events = match operation {
+            Reharmonization::TritoneSubstitution => tritone_substitution(events, &positions),
+            Reharmonization::TwoFive => two_five(events, &positions),
+            Reharmonization::SecondaryDominants => secondary_dominants(events, &positions),
+            Reharmonization::ApproachChords => approach_chords(events, &positions),
+            Reharmonization::Sevenths => sevenths(events, &positions),
+            Reharmonization::Ninths => {
+                let events = sevenths(events, &positions);
+                let positions = self::positions(&events, key);
+                ninths(events, &positions)
+            }
+        };
+    }
+    events
+}
+
+/// The chords of `events` with the key in effect at each.
+fn positions(events: &[Event], key: Key) -> Vec<Position> {
+    let mut key = key;
+    let mut scale = scale_of(key);
+    let mut positions = Vec::new();
+    for (index, event) in events.iter().enumerate() {
+        match event {
+            Event::Key { offset, minor } => {
+                key = Key {
+                    tonic: offset.rem_euclid(12),
+                    minor: *minor,
+                };
+                scale = scale_of(key);
+            }
+            Event::Scale { offsets } => scale = offsets.clone(),
+            _ => {}
+        }
+        let Some((root, quality, _)) = chord_of(event) else {
+            continue;
+        };
+        // Qualities come from the parser, so they are all in the table
+        let Ok(intervals) = chord_intervals(quality) else {
+            continue;
+        };
+        let (letter, octave_offset) = match event {
+            Event::Chord(chord) => (chord.root_letter, chord.octave_offset),
+            Event::SlashChord(slash)
+            | Event::ChordOverBassNote(slash)
+            | Event::Inversion(slash)
+            | Event::Polychord(slash) => (slash.upper_root_letter, slash.upper_octave_offset),
+            _ => unreachable!("chord_of returned a chord"),
+        };
+        positions.push(Position {
+            index,
+            root,
+            letter,
+            quality: quality.to_string(),
+            intervals,
+            octave_offset,
+            key,
+            scale: scale.clone(),
+        });
+    }
+    positions
+}
+
+fn scale_of(key: Key) -> Vec<i32> {
+    if key.minor {
+        NATURAL_MINOR.to_vec()
+    } else {
+        MAJOR.to_vec()
+    }
+}
+
+fn plain_chord(events: &mut [Event], index: usize) -> Option<&mut ChordEvent> {
+    match &mut events[index] {
+        Event::Chord(chord) => Some(chord),
+        _ => None,
+    }
+}
+
+fn note_length(event: &Event) -> Option<u32> {
+    match event {
+        Event::Chord(chord) => chord.note_length,
+        Event::SlashChord(slash)
+        | Event::ChordOverBassNote(slash)
+        | Event::Inversion(slash)
+        | Event::Polychord(slash) => slash.note_length,
+        _ => None,
+    }
+}
+
+/// `events` with `inserted` chords after the chord at each index. All
+/// chords get the length ast2ast would give them, then each chord followed
+/// by inserted ones keeps half of it and they share the other half.
+fn insert_after(mut events: Vec<Event>, inserted: Vec<(usize, Vec<Event>)>) -> Vec<Event> {
+    if inserted.is_empty() {
+        return events;
+    }
+    bar_to_note_length(&mut events);
+    let mut result = Vec::with_capacity(events.len() + inserted.len() * 2);
+    let mut inserted = inserted.into_iter().peekable();
+    for (index, mut event) in events.into_iter().enumerate() {
+        let Some((_, mut chords)) = inserted.next_if(|(at, _)| *at == index) else {
+            result.push(event);
+            continue;
+        };
+        let length = note_length(&event).unwrap_or(1) * 2;
+        set_note_length(&mut event, length);
+        let count = chords.len() as u32;
+        for chord in &mut chords {
+            set_note_length(chord, length * count);
+        }
+        result.push(event);
+        result.extend(chords);
+    }
+    result
+}
+
+fn tritone_substitution(mut events: Vec<Event>, positions: &[Position]) -> Vec<Event> {
+    for pair in positions.windows(2) {
+        let [chord, next] = pair else {
+            unreachable!("windows of 2");
+        };
+        if !chord.is_dominant_seventh() || chord.to(next) != 5 {
+            continue;
+        }
+        if let Some(event) = plain_chord(&mut events, chord.index) {
+            // A tritone down or up, whichever stays in the octave, spelled
+            // as the diminished fifth or the augmented fourth with fewer
+            // accidentals (G → Db, Bb → E)
+            event.root += if event.root >= 6 { -6 } else { 6 };
+            let root = event.root;
+            event.root_letter = event.root_letter.map(|letter| {
+                [(letter + 4) % 7, (letter + 3) % 7]
+                    .into_iter()
+                    .min_by_key(|&letter| {
+                        ((root - NATURALS[letter as usize] + 6).rem_euclid(12) - 6).abs()
+                    })
+                    .unwrap_or(letter)
+            });
+        }
+    }
+    events
+}
+
+fn two_five(events: Vec<Event>, positions: &[Position]) -> Vec<Event> {
+    let mut inserted = Vec::new();
+    for pair in positions.windows(2) {
+        let [previous, target] = pair else {
+            unreachable!("windows of 2");
+        };
+        // Already led to by its V or subV, or the same chord again
+        if !target.is_target() || [0, 1, 7].contains(&target.to(previous)) {
+            continue;
+        }
+        let two = if target.has(3) {
+            "min7,flatted fifth"
+        } else {
+            "min7"
+        };
+        inserted.push((
+            previous.index,
+            vec![target.chord(2, 1, two), target.chord(7, 4, "7")],
+        ));
+    }
+    insert_after(events, inserted)
+}
+
+fn secondary_dominants(mut events: Vec<Event>, positions: &[Position]) -> Vec<Event> {
+    for pair in positions.windows(2) {
+        let [chord, target] = pair else {
+            unreachable!("windows of 2");
+        };
+        if !chord.is_diatonic()
+            || chord.is_dominant_seventh()
+            || !target.is_target()
+            || chord.to(target) != 5
+        {
+            continue;
+        }
+        let ninth = chord.has(2);
+        if let Some(event) = plain_chord(&mut events, chord.index) {
+            event.quality = if ninth { "9" } else { "7" }.to_string();
+        }
+    }
+    events
+}
+
+fn approach_chords(events: Vec<Event>, positions: &[Position]) -> Vec<Event> {
+    let mut inserted = Vec::new();
+    for pair in positions.windows(2) {
+        let [previous, target] = pair else {
+            unreachable!("windows of 2");
+        };
+        if [0, 1, 11].contains(&target.to(previous)) {
+            continue;
+        }
+        inserted.push((previous.index, vec![target.chord(1, 1, &target.quality)]));
+    }
+    insert_after(events, inserted)
+}
+
+fn sevenths(mut events: Vec<Event>, positions: &[Position]) -> Vec<Event> {
+    for (i, chord) in positions.iter().enumerate() {
+        // The seventh a note of the key would give, from the root
+        let seventh = match (chord.degree(), chord.scale.len()) {
+            (Some(degree), 7) => {
+                let tonic = (chord.root - chord.key.tonic).rem_euclid(12);
+                Some((chord.scale[(degree + 6) % 7] - tonic).rem_euclid(12))
+            }
+            _ => None,
+        };
+        let resolves = positions.get(i + 1).is_some_and(|next| chord.to(next) == 5);
+        let quality = match (chord.quality.as_str(), seventh) {
+            ("maj", Some(11)) => "maj7",
+            ("maj", Some(10)) => "7",
+            // Outside the key: a dominant when it resolves like one
+            ("maj", _) if resolves => "7",
+            ("maj", _) => "maj7",
+            ("min", Some(11)) => "min,add7",
+            ("min", _) => "min7",
+            ("dim triad", Some(9)) => "dim triad,add6",
+            ("dim triad", Some(10)) => "min7,flatted fifth",
+            ("dim triad", _) => "dim triad,add6",
+            _ => continue,
+        };
+        if let Some(event) = plain_chord(&mut events, chord.index) {
+            event.quality = quality.to_string();
+        }
+    }
+    events
+}
+
+fn ninths(mut events: Vec<Event>, positions: &[Position]) -> Vec<Event> {
+    for chord in positions {
+        let mut quality = match chord.quality.as_str() {
+            "maj7" => "maj7,add9",
+            "7" => "9",
+            "min7" => "min7,add9",
+            "min,add7" => "min,add7,add9",
+            _ => continue,
+        };
+        // A chord of the key keeps to its notes (no ninth on iii in major),
+        // but a dominant takes the flat ninth instead (V7 in minor, V7/vi)
+        let in_key = |interval: i32| {
+            chord
+                .scale
+                .contains(&(chord.root + interval - chord.key.tonic).rem_euclid(12))
+        };
+        if chord.degree().is_some() && !in_key(2) {
+            if quality != "9" || !in_key(1) {
+                continue;
+            }
+            quality = "7,flatted ninth";
+        }
+        if let Some(event) = plain_chord(&mut events, chord.index) {
+            event.quality = quality.to_string();
+        }
+    }
+    events
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ast2ast::ast_to_ast;
+    use crate::chord_text::chord_text;
+    use crate::Converter;
+
+    fn reharmonized(input: &str, operations: &[Reharmonization]) -> String {
+        let conversion = Converter::new().unwrap().convert_explained(input).unwrap();
+        chord_text(&reharmonize(conversion.events, operations, None))
+    }
+
+    #[test]
+    fn test_tritone_substitution() {
+        for (input, output) in [
+            ("Dm7 G7 Cmaj7", "Dm7 Db7 Cmaj7"),
+            ("C7 F", "Gb7 F"),
+            ("Bb7 Eb", "E7 Eb"),
+            ("E9 Am", "Bb9 Am"),
+            // Not resolving down a fifth, or not a dominant
+            ("G7 Am", "G7 Am"),
+            ("Gmaj7 C", "Gmaj7 C"),
+        ] {
+            assert_eq!(
+                reharmonized(input, &[Reharmonization::TritoneSubstitution]),
+                output
+            );
+        }
+    }
+
+    #[test]
+    fn test_two_five() {
+        for (input, output) in [
+            ("C Eb", "C Fm7 Bb7 Eb"),
+            // C is already the V of F
+            ("C F", "C F"),
+            ("C | Am", "C Bm7(b5) E7 | Am"),
+            // Already preceded by its V or subV
+            ("Cmaj7 Dm7 G7 C", "Cmaj7 Em7(b5) A7 Dm7 G7 C"),
+            ("Db7 C", "Db7 C"),
+        ] {
+            assert_eq!(reharmonized(input, &[Reharmonization::TwoFive]), output);
+        }
+    }
+
+    /// The length of each bar in whole notes, after ast2ast.
+    fn bar_lengths(input: &str, operations: &[Reharmonization]) -> Vec<f64> {
+        let conversion = Converter::new().unwrap().convert_explained(input).unwrap();
+        let events = ast_to_ast(reharmonize(conversion.events, operations, None));
+        let mut bars = vec![0.0];
+        for event in &events {
+            match event {
+                Event::Bar | Event::BarSlash => bars.push(0.0),
+                _ => {
+                    if let Some(length) = note_length(event) {
+                        *bars.last_mut().unwrap() += 1.0 / length as f64;
+                    }
+                }
+            }
+        }
+        bars
+    }
+
+    #[test]
+    fn test_inserted_chords_keep_the_form() {
+        for input in [
+            "C Eb",
+            "C | Am",
+            "C Am / Dm G | F G7 | C",
+            "Cmaj7 Am7 Dm7 G7",
+        ] {
+            let bars = bar_lengths(input, &[]);
+            for operations in [
+                &[Reharmonization::TwoFive][..],
+                &[Reharmonization::ApproachChords],
+                &[Reharmonization::TwoFive, Reharmonization::ApproachChords],
+            ] {
+                assert_eq!(bar_lengths(input, operations), bars, "{input}");
+            }
+        }
+        // The chord before gives half its time
+        let conversion = Converter::new()
+            .unwrap()
+            .convert_explained("C | Am")
+            .unwrap();
+        let events = reharmonize(conversion.events, &[Reharmonization::TwoFive], None);
+        let lengths: Vec<_> = events.iter().filter_map(note_length).collect();
+        assert_eq!(lengths, [2, 4, 4, 1]);
+    }
+
+    #[test]
+    fn test_secondary_dominants() {
+        assert_eq!(
+            reharmonized("C Am Dm G C", &[Reharmonization::SecondaryDominants]),
+            "C A7 D7 G7 C"
+        );
+        // Eb is not in the key
+        assert_eq!(
+            reharmonized("Bdim Em Eb Ab", &[Reharmonization::SecondaryDominants]),
+            "B7 Em Eb Ab"
+        );
+        assert_eq!(
+            reharmonized("key=Am Am Dm E Am", &[Reharmonization::SecondaryDominants]),
+            "key=Am A7 Dm E7 Am"
+        );
+    }
+
+    #[test]
+    fn test_approach_chords() {
+        assert_eq!(
+            reharmonized("Cmaj7 Am7 Dm7 G7", &[Reharmonization::ApproachChords]),
+            "Cmaj7 Bbm7 Am7 Ebm7 Dm7 Ab7 G7"
+        );
+        assert_eq!(
+            reharmonized("C Db C", &[Reharmonization::ApproachChords]),
+            "C Db C"
+        );
+    }
+
+    #[test]
+    fn test_sevenths_and_ninths() {
+        assert_eq!(
+            reharmonized("C Am F G Bdim Em", &[Reharmonization::Sevenths]),
+            "Cmaj7 Am7 Fmaj7 G7 Bm7(b5) Em7"
+        );
+        assert_eq!(
+            reharmonized("key=Am Am Dm E Am", &[Reharmonization::Sevenths]),
+            "key=Am Am7 Dm7 E7 Am7"
+        );
+        // Chords outside the key: dominant when resolving down a fifth
+        assert_eq!(
+            reharmonized("Ab D G", &[Reharmonization::Sevenths]),
+            "Abmaj7 D7 G7"
+        );
+        assert_eq!(
+            reharmonized("C Dm Em G7", &[Reharmonization::Ninths]),
+            "Cmaj7(add9) Dm7(add9) Em7 G9"
+        );
+        assert_eq!(
+            reharmonized("key=Am Am Dm E Am", &[Reharmonization::Ninths]),
+            "key=Am Am7(add9) Dm7(add9) E7(b9) Am7(add9)"
+        );
+        assert_eq!(
+            reharmonized("C E7 Am", &[Reharmonization::Ninths]),
+            "Cmaj7(add9) E7(b9) Am7(add9)"
+        );
+    }
+
+    #[test]
+    fn test_operations_run_in_order() {
+        let operations = [
+            Reharmonization::TwoFive,
+            Reharmonization::TritoneSubstitution,
+        ];
+        assert_eq!(reharmonized("C Eb", &operations), "C Fm7 E7 Eb");
+        // In the key of the directive, and of the key given
+        let conversion = Converter::new()
+            .unwrap()
+            .convert_explained("Bb Gm Cm F")
+            .unwrap();
+        let events = reharmonize(
+            conversion.events,
+            &[Reharmonization::Sevenths],
+            Some(Key::major(10)),
+        );
+        assert_eq!(chord_text(&events), "Bbmaj7 Gm7 Cm7 F7");
+    }
+}
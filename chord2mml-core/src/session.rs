@@ -0,0 +1,432 @@
+//! Incremental conversion for live editing (Rust-version extension).
+//!
+//! A [`Session`] keeps the text and its tree-sitter tree between edits.
+//! Each edit is applied to the old tree and the text reparsed
+//! incrementally, so only the edited region is re-lexed. The events are
+//! then read from the whole tree and resolved by ast2ast (a chord's note
+//! length depends on its whole bar), but voicing and MML writing only run
+//! for the events that changed and those after them whose modes, octave
+//! or spelling state changed; the rest reuse the previous results.
+//!
+//! [`CstSession`] is the parser-less half for the WASM path: the caller
+//! parses incrementally with web-tree-sitter and passes each new CST.
+
+use anyhow::Result;
+
+use crate::ast::{ChordEvent, Event, SlashChordEvent};
+use crate::ast2ast::ast_to_ast;
+use crate::ast2notes::NotesState;
+use crate::cst_to_ast::{cst_json_to_ast_with_options, cst_to_ast_with_options, CSTNode};
+use crate::events_to_mml;
+use crate::notes2mml::GroupWriter;
+use crate::options::{ConvertOptions, MmlProfile};
+
+/// The stages after parsing, rerun per event where the events changed.
+///
+/// # Example
+/// ```
+/// use chord2mml_core::{ConvertOptions, CstSession};
+///
+/// let cst = r#"{"type": "source_file", "children": [{"type": "chord",
+///     "fields": {"root": [{"type": "root", "text": "C",
+///         "fields": {"note": [{"type": "note", "text": "C"}]}}]}}]}"#;
+/// let mut session = CstSession::new(ConvertOptions::default());
+/// assert_eq!(session.update(cst).unwrap(), "v11'c1eg'");
+/// ```
+#[derive(Debug, Clone)]
+pub struct CstSession {
+    options: ConvertOptions,
+    /// The last events converted, and their MML
+    last: Option<(Vec<Event>, String)>,
+    /// The ast2ast events of the last conversion and what the later
+    /// stages made of each (empty when the options need the whole list)
+    steps: Vec<Step>,
+}
+
+/// One ast2ast event and its share of the MML.
+#[derive(Debug, Clone)]
+struct Step {
+    event: Event,
+    mml: String,
+    /// The ast2notes and MML-writing state after the event
+    notes: NotesState,
+    writer: GroupWriter,
+}
+
+impl CstSession {
+    pub fn new(options: ConvertOptions) -> Self {
+        CstSession {
+            options,
+            last: None,
+            steps: Vec::new(),
+        }
+    }
+
+    pub fn options(&self) -> &ConvertOptions {
+        &self.options
+    }
+
+    /// Convert the CST (as JSON) of the current text.
+    pub fn update(&mut self, cst_json: &str) -> Result<String> {
+        let events = cst_json_to_ast_with_options(cst_json, &self.options)?;
+        self.run(events)
+    }
+
+    /// Convert the CST of the current text.
+    pub fn update_cst(&mut self, root: &CSTNode) -> Result<String> {
+        let events = cst_to_ast_with_options(root, &self.options)?;
+        self.run(events)
+    }
+
+    /// The MML for the events, reusing the last results where they match.
+    pub(crate) fn run(&mut self, events: Vec<Event>) -> Result<String> {
+        if let Some((last_events, mml)) = &self.last {
+            if same_events(last_events, &events) {
+                return Ok(mml.clone());
+            }
+        }
+        let mml = if self.per_event() {
+            self.run_steps(ast_to_ast(events.clone()))?
+        } else {
+            events_to_mml(events.clone(), &self.options)?
+        };
+        self.last = Some((events, mml.clone()));
+        Ok(mml)
+    }
+
+    /// Whether every event's MML follows from the events before it alone.
+    /// Reharmonization, key detection and scale runs read the whole
+    /// progression, and the tracks profile writes every chord into every
+    /// track.
+    fn per_event(&self) -> bool {
+        self.options.reharmonize.is_empty()
+            && !self.options.detect_key
+            && !self.options.scale_runs
+            && self.options.mml_profile == MmlProfile::Mmlabc
+    }
+
+    /// ast2notes and notes2mml over `resolved`, resuming after the events
+    /// that match the last run and stopping once an unchanged tail is
+    /// reached in the same state.
+    fn run_steps(&mut self, resolved: Vec<Event>) -> Result<String> {
+        let old = &self.steps;
+        let head = old
+            .iter()
+            .zip(&resolved)
+            .take_while(|(step, event)| same_event(&step.event, event))
+            .count();
+        let tail = old
+            .iter()
+            .rev()
+            .zip(resolved.iter().rev())
+            .take(old.len().min(resolved.len()) - head)
+            .take_while(|(step, event)| same_event(&step.event, event))
+            .count();
+
+        let mut steps: Vec<Step> = old[..head].to_vec();
+        let (mut notes, mut writer) = match steps.last() {
+            Some(step) => (step.notes.clone(), step.writer.clone()),
+            None => (
+                NotesState::default(),
+                GroupWriter::new(self.options.spelling),
+            ),
+        };
+        let tail_start = resolved.len() - tail;
+        for (i, event) in resolved.into_iter().enumerate().skip(head) {
+            if i >= tail_start {
+                // The same event in the same state as last time: the rest
+                // is unchanged too
+                let j = i + old.len() - tail_start - tail;
+                let (old_notes, old_writer) = match j.checked_sub(1) {
+                    Some(before) => (old[before].notes.clone(), old[before].writer.clone()),
+                    None => (
+                        NotesState::default(),
+                        GroupWriter::new(self.options.spelling),
+                    ),
+                };
+                if old_notes == notes && old_writer == writer {
+                    steps.extend_from_slice(&old[j..]);
+                    break;
+                }
+            }
+            let mut mml = String::new();
+            if let Some(out_event) = notes.notes(event.clone())? {
+                writer.write(&out_event, &mut mml);
+            }
+            steps.push(Step {
+                event,
+                mml,
+                notes: notes.clone(),
+                writer: writer.clone(),
+            });
+        }
+
+        let mut mml = String::from("v11");
+        for step in &steps {
+            mml.push_str(&step.mml);
+        }
+        self.steps = steps;
+        Ok(mml)
+    }
+}
+
+/// Whether two events convert alike: equal but for where they were
+/// written.
+fn same_event(a: &Event, b: &Event) -> bool {
+    match (a, b) {
+        (Event::Chord(a), Event::Chord(b)) => {
+            ChordEvent {
+                span: None,
+                ..a.clone()
+            } == ChordEvent {
+                span: None,
+                ..b.clone()
+            }
+        }
+        (Event::SlashChord(a), Event::SlashChord(b))
+        | (Event::ChordOverBassNote(a), Event::ChordOverBassNote(b))
+        | (Event::Inversion(a), Event::Inversion(b))
+        | (Event::Polychord(a), Event::Polychord(b)) => {
+            SlashChordEvent {
+                span: None,
+                ..a.clone()
+            } == SlashChordEvent {
+                span: None,
+                ..b.clone()
+            }
+        }
+        _ => a == b,
+    }
+}
+
+fn same_events(a: &[Event], b: &[Event]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).all(|(a, b)| same_event(a, b))
+}
+
+#[cfg(feature = "tree-sitter")]
+pub use native::Session;
+
+#[cfg(feature = "tree-sitter")]
+mod native {
+    use super::*;
+    use anyhow::anyhow;
+    use std::ops::Range;
+    use tree_sitter::{InputEdit, Point, Tree};
+
+    use crate::converter::{check_input_len, empty_input_error};
+    use crate::cst_to_ast::DegreeContext;
+    use crate::parser::{tree_to_ast, ChordParser};
+    use crate::preprocess;
+
+    /// A chart being edited: each [`Session::edit`] reparses incrementally
+    /// and returns the MML of the whole text.
+    ///
+    /// # Example
+    /// ```
+    /// use chord2mml_core::{ConvertOptions, Session};
+    ///
+    /// let mut session = Session::new(ConvertOptions::default()).unwrap();
+    /// assert_eq!(session.set_text("C F").unwrap(), "v11'c1eg''f1a<c'");
+    /// // Replace "F" (bytes 2..3) with "G7"
+    /// assert_eq!(session.edit(2..3, "G7").unwrap(), "v11'c1eg''g1b<df'");
+    /// assert_eq!(session.text(), "C G7");
+    /// ```
+    pub struct Session {
+        parser: ChordParser,
+        text: String,
+        /// The tree of `text` as written (also when it only converts
+        /// through a dialect rewrite), reused by the next edit
+        tree: Option<Tree>,
+        stages: CstSession,
+    }
+
+    impl Session {
+        pub fn new(options: ConvertOptions) -> Result<Self> {
+            Ok(Session {
+                parser: ChordParser::new()?,
+                text: String::new(),
+                tree: None,
+                stages: CstSession::new(options),
+            })
+        }
+
+        pub fn text(&self) -> &str {
+            &self.text
+        }
+
+        /// Replace the whole text (parsed from scratch).
+        pub fn set_text(&mut self, text: &str) -> Result<String> {
+            self.text = text.to_string();
+            self.tree = None;
+            self.convert()
+        }
+
+        /// Replace the bytes in `range` of the current text with `text`
+        /// and return the MML of the result.
+        pub fn edit(&mut self, range: Range<usize>, text: &str) -> Result<String> {
+            let valid = range.start <= range.end
+                && self.text.is_char_boundary(range.start)
+                && self.text.is_char_boundary(range.end);
+            if !valid {
+                return Err(anyhow!(
+                    "Invalid edit range {}..{} for text of {} bytes",
+                    range.start,
+                    range.end,
+                    self.text.len()
+                ));
+            }
+
+            let start_position = point_at(&self.text, range.start);
+            let old_end_position = point_at(&self.text, range.end);
+            self.text.replace_range(range.clone(), text);
+            let new_end_byte = range.start + text.len();
+            if let Some(tree) = &mut self.tree {
+                tree.edit(&InputEdit {
+                    start_byte: range.start,
+                    old_end_byte: range.end,
+                    new_end_byte,
+                    start_position,
+                    old_end_position,
+                    new_end_position: point_at(&self.text, new_end_byte),
+                });
+            }
+            self.convert()
+        }
+
+        fn convert(&mut self) -> Result<String> {
+            let input = self.text.trim().to_string();
+            if input.is_empty() {
+                return Err(empty_input_error());
+            }
+            check_input_len(&input, self.stages.options())?;
+
+            // The candidates of `convert`, in its order; the input as
+            // written is read from the incrementally parsed tree, the
+            // rewrites from scratch, and its error is the one reported
+            let options = self.stages.options().clone();
+            let mut written_error = None;
+            for rewrite in preprocess::preprocess_rewrites(&input) {
+                let mut context = DegreeContext::new(&options);
+                let events = if rewrite.rules.is_empty() {
+                    let tree = self.parser.parse_tree(&self.text, self.tree.as_ref())?;
+                    let events = tree_to_ast(&tree, &self.text, &mut context);
+                    self.tree = Some(tree);
+                    events
+                } else {
+                    self.parser.parse(&rewrite.text, &mut context)
+                };
+                match events.and_then(|events| self.stages.run(events)) {
+                    Ok(mml) => return Ok(mml),
+                    Err(e) => {
+                        if rewrite.rules.is_empty() {
+                            written_error = Some(e);
+                        }
+                    }
+                }
+            }
+            Err(written_error.expect("the input as written is a candidate"))
+        }
+    }
+
+    /// Row and byte column of a byte offset.
+    fn point_at(text: &str, byte: usize) -> Point {
+        let before = &text[..byte];
+        let row = before.matches('\n').count();
+        let column = before.rfind('\n').map_or(byte, |i| byte - i - 1);
+        Point { row, column }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn test_edits_match_full_conversion() {
+            let mut session = Session::new(ConvertOptions::default()).unwrap();
+            session.set_text("key=D I IV V").unwrap();
+            // Insert, replace and delete, across lines
+            for (range, text, expected_text) in [
+                (12..12, " I", "key=D I IV V I"),
+                (4..5, "G", "key=G I IV V I"),
+                (5..5, "\nVIm", "key=G\nVIm I IV V I"),
+                (6..10, "", "key=G\nI IV V I"),
+            ] {
+                let mml = session.edit(range, text).unwrap();
+                assert_eq!(session.text(), expected_text);
+                assert_eq!(
+                    mml,
+                    crate::convert(expected_text).unwrap(),
+                    "{expected_text}"
+                );
+            }
+        }
+
+        #[test]
+        fn test_dialect_and_errors() {
+            let mut session = Session::new(ConvertOptions::default()).unwrap();
+            // A dialect rewrite converts, and later edits stay incremental
+            assert_eq!(
+                session.set_text("ii V").unwrap(),
+                crate::convert("IIm V").unwrap()
+            );
+            assert!(session.edit(0..2, "H").is_err());
+            assert_eq!(
+                session.edit(0..1, "C").unwrap(),
+                crate::convert("C V").unwrap()
+            );
+            assert!(session.edit(2..9, "x").is_err());
+            assert!(session.edit(Range { start: 2, end: 1 }, "x").is_err());
+        }
+
+        #[test]
+        fn test_unchanged_events_reuse_mml() {
+            let mut session = Session::new(ConvertOptions::default()).unwrap();
+            let mml = session.set_text("C F").unwrap();
+            assert_eq!(session.edit(1..2, "  -  ").unwrap(), mml);
+            assert_eq!(session.text(), "C  -  F");
+        }
+
+        #[test]
+        fn test_state_changes_rerun_later_events() {
+            let mut session = Session::new(ConvertOptions::default()).unwrap();
+            session.set_text("key=F C Bb | G7 C/E | Bb").unwrap();
+            // Key (spelling), inversion mode (voicing) and bars (lengths)
+            for (range, text, expected_text) in [
+                (4..5, "D", "key=D C Bb | G7 C/E | Bb"),
+                (13..13, "1st inv ", "key=D C Bb | 1st inv G7 C/E | Bb"),
+                (11..13, "", "key=D C Bb 1st inv G7 C/E | Bb"),
+                (28..30, "Bbm", "key=D C Bb 1st inv G7 C/E | Bbm"),
+            ] {
+                let mml = session.edit(range, text).unwrap();
+                assert_eq!(session.text(), expected_text);
+                assert_eq!(
+                    mml,
+                    crate::convert(expected_text).unwrap(),
+                    "{expected_text}"
+                );
+            }
+        }
+
+        #[test]
+        fn test_unchanged_steps_are_reused() {
+            let mut session = Session::new(ConvertOptions::default()).unwrap();
+            session.set_text("C F G Am").unwrap();
+            session.edit(2..3, "Fm").unwrap();
+            // G and Am were not rerun: their steps keep the old spans
+            let spans: Vec<_> = session
+                .stages
+                .steps
+                .iter()
+                .map(|step| match &step.event {
+                    Event::Chord(chord) => chord.span.map(|span| span.start),
+                    _ => None,
+                })
+                .collect();
+            assert_eq!(spans, [Some(0), Some(2), Some(4), Some(6)]);
+            assert_eq!(
+                session.edit(0..0, "key=Eb ").unwrap(),
+                crate::convert("key=Eb C Fm G Am").unwrap()
+            );
+        }
+    }
+}
@@ -0,0 +1,141 @@
+//! Chord-aware note spelling (Rust-version extension, `Spelling::Chord`).
+//!
+//! The JS-compatible output spells every note from a single sharp or flat
+//! table chosen by the key. Here each note's letter is derived from the
+//! chord root's letter as written (`Gb` is G, `F#` is F; degrees take the
+//! key letter) plus the note's interval degree, so `Fm` spells A-flat
+//! rather than G-sharp, and the accidental is whatever the letter needs.
+//!
+//! Letters are indexes C=0, D=1, E=2, F=3, G=4, A=5, B=6.
+
+/// Natural pitch of each letter (C D E F G A B).
+const LETTER_PITCHES: [i32; 7] = [0, 2, 4, 5, 7, 9, 11];
+const LETTER_NAMES: [&str; 7] = ["c", "d", "e", "f", "g", "a", "b"];
+
+/// Letter index of an uppercase note name, `None` for anything else.
+pub(crate) fn letter_of(c: char) -> Option<u8> {
+    Some(match c {
+        'C' => 0,
+        'D' => 1,
+        'E' => 2,
+        'F' => 3,
+        'G' => 4,
+        'A' => 5,
+        'B' => 6,
+        _ => return None,
+    })
+}
+
+/// Spell the notes of one chord: each note's letter is the root letter
+/// moved up by the note's interval degree within `quality`.
+pub(crate) fn spell_chord(notes: &[i32], root: i32, root_letter: u8, quality: &str) -> Vec<u8> {
+    let has_major_third = notes.iter().any(|n| (n - root).rem_euclid(12) == 4);
+    notes
+        .iter()
+        .map(|n| {
+            let steps = degree_steps((n - root).rem_euclid(12), quality, has_major_third);
+            (root_letter + steps) % 7
+        })
+        .collect()
+}
+
+/// Letter steps above the root for an interval class, read in the
+/// context of the chord quality: 6 is a flatted fifth in `dim`/`(b5)`
+/// chords and a sharp eleventh otherwise; 8 is an augmented fifth in
+/// `aug`/`(#5)` chords and a flat thirteenth otherwise; 3 is a sharp
+/// ninth when the chord also has a major third; 9 is a diminished
+/// seventh in `dim7` and `dim(add6)`, its notation.
+fn degree_steps(interval: i32, quality: &str, has_major_third: bool) -> u8 {
+    let has = |part: &str| quality.split(',').any(|p| p == part);
+    let dim7 = has("dim7") || (has("dim triad") && has("add6"));
+    match interval {
+        0 => 0,
+        1 | 2 => 1,
+        3 if has_major_third => 1,
+        3 | 4 => 2,
+        5 => 3,
+        6 if has("dim triad") || dim7 || has("flatted fifth") => 4,
+        6 => 3,
+        7 => 4,
+        8 if has("aug") || has("augmented fifth") => 4,
+        9 if dim7 => 6,
+        8 | 9 => 5,
+        _ => 6,
+    }
+}
+
+/// MML name and octave of a note spelled with a given letter: the
+/// accidental is the distance from the letter's natural pitch (`+`/`-`,
+/// doubled for double sharps/flats), and the octave is that of the
+/// natural note, so B-sharp at pitch 12 is `b+` in octave 0.
+pub(crate) fn mml_name(note: i32, letter: u8) -> (String, i32) {
+    let natural = LETTER_PITCHES[letter as usize];
+    // Nearest accidental in -6..=5 semitones from the natural letter
+    let accidental = (note - natural + 6).rem_euclid(12) - 6;
+    let mark = if accidental > 0 { "+" } else { "-" };
+    let name = format!(
+        "{}{}",
+        LETTER_NAMES[letter as usize],
+        mark.repeat(accidental.unsigned_abs() as usize)
+    );
+    (name, (note - accidental).div_euclid(12))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn names(notes: &[i32], root: i32, root_letter: u8, quality: &str) -> Vec<String> {
+        let letters = spell_chord(notes, root, root_letter, quality);
+        notes
+            .iter()
+            .zip(letters)
+            .map(|(&n, l)| mml_name(n, l).0)
+            .collect()
+    }
+
+    #[test]
+    fn test_f_minor_spells_a_flat() {
+        assert_eq!(names(&[5, 8, 12], 5, 3, "min"), ["f", "a-", "c"]);
+    }
+
+    #[test]
+    fn test_sharp_ninth_vs_minor_third() {
+        // E7#9: the G natural is a raised second (F double-sharp)
+        assert_eq!(
+            names(&[4, 8, 11, 14, 19], 4, 2, "7"),
+            ["e", "g+", "b", "d", "f++"]
+        );
+    }
+
+    #[test]
+    fn test_flat_root_letter_kept() {
+        // Gb major: G-flat B-flat D-flat, not F-sharp A-sharp C-sharp
+        assert_eq!(names(&[6, 10, 13], 6, 4, "maj"), ["g-", "b-", "d-"]);
+    }
+
+    #[test]
+    fn test_fifth_alterations() {
+        assert_eq!(names(&[0, 3, 6], 0, 0, "dim triad"), ["c", "e-", "g-"]);
+        assert_eq!(names(&[0, 4, 8], 0, 0, "aug"), ["c", "e", "g+"]);
+    }
+
+    #[test]
+    fn test_diminished_seventh() {
+        for quality in ["dim7", "dim triad,add6"] {
+            assert_eq!(
+                names(&[0, 3, 6, 9], 0, 0, quality),
+                ["c", "e-", "g-", "b--"]
+            );
+        }
+    }
+
+    #[test]
+    fn test_octave_of_spelled_note() {
+        // B-sharp sounds at 12 but is written in the B's octave
+        assert_eq!(mml_name(12, 6), ("b+".to_string(), 0));
+        // C-flat sounds at -1 but is written in the C's octave
+        assert_eq!(mml_name(-1, 0), ("c-".to_string(), 0));
+        assert_eq!(mml_name(14, 1), ("d".to_string(), 1));
+    }
+}
@@ -0,0 +1,298 @@
+//! A small built-in synthesizer and WAV output (Rust-version extension).
+//!
+//! Plays the [`crate::timeline`] of the realized chords with one
+//! oscillator per note (sine, sawtooth or square) shaped by an ADSR
+//! envelope: each chord's notes are held for its length at the tempo of
+//! the inline `t` events, then released. The GM program of `@` events picks
+//! a family of sound (see [`Instrument::for_program`]); [`SynthOptions`]
+//! may override its waveform or envelope. No audio device is needed: the
+//! result is samples, or a 16-bit mono WAV file.
+
+use std::f32::consts::TAU;
+
+use crate::ast::OutEvent;
+use crate::timeline::{timeline, TimedKind, DEFAULT_TEMPO, TICKS_PER_QUARTER};
+
+/// Loudness of one note before the mix is normalized: the MML's `v11`,
+/// shared by the notes of a 4-note chord.
+const NOTE_GAIN: f32 = 11.0 / 15.0 / 4.0;
+/// The mix is scaled down to this peak when louder.
+const PEAK: f32 = 0.9;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Waveform {
+    Sine,
+    Saw,
+    Square,
+}
+
+/// ADSR envelope: times in seconds, `sustain` as a level from 0 to 1.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Envelope {
+    pub attack: f32,
+    pub decay: f32,
+    pub sustain: f32,
+    pub release: f32,
+}
+
+impl Envelope {
+    /// The level `time` seconds after the note starts, for a note held for
+    /// `gate` seconds; 0 once the release has ended.
+    pub fn level(&self, time: f32, gate: f32) -> f32 {
+        if time < gate {
+            self.held_level(time)
+        } else {
+            let released = time - gate;
+            if released >= self.release {
+                0.0
+            } else {
+                self.held_level(gate) * (1.0 - released / self.release)
+            }
+        }
+    }
+
+    fn held_level(&self, time: f32) -> f32 {
+        if time < self.attack {
+            time / self.attack
+        } else if time < self.attack + self.decay {
+            1.0 - (1.0 - self.sustain) * (time - self.attack) / self.decay
+        } else {
+            self.sustain
+        }
+    }
+}
+
+/// How a note sounds.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Instrument {
+    pub waveform: Waveform,
+    pub envelope: Envelope,
+}
+
+impl Instrument {
+    /// An approximation of a GM program's family: struck and plucked
+    /// instruments decay, organs, reeds and pipes sustain, strings, brass
+    /// and pads swell.
+    pub fn for_program(program: u8) -> Self {
+        let (waveform, attack, decay, sustain, release) = match program {
+            0..=15 => (Waveform::Sine, 0.005, 0.8, 0.3, 0.3), // piano, chromatic percussion
+            16..=23 => (Waveform::Square, 0.01, 0.05, 0.9, 0.05), // organ
+            24..=39 => (Waveform::Saw, 0.005, 0.6, 0.2, 0.2), // guitar, bass
+            40..=55 => (Waveform::Saw, 0.15, 0.2, 0.8, 0.4),  // strings, ensemble
+            56..=63 => (Waveform::Saw, 0.05, 0.1, 0.8, 0.15), // brass
+            64..=79 => (Waveform::Square, 0.04, 0.1, 0.8, 0.1), // reed, pipe
+            80..=87 => (Waveform::Square, 0.01, 0.1, 0.7, 0.1), // synth lead
+            88..=95 => (Waveform::Saw, 0.4, 0.5, 0.7, 0.8),   // synth pad
+            _ => (Waveform::Sine, 0.01, 0.3, 0.5, 0.3),
+        };
+        Instrument {
+            waveform,
+            envelope: Envelope {
+                attack,
+                decay,
+                sustain,
+                release,
+            },
+        }
+    }
+}
+
+/// Options of [`render`] and [`to_wav`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SynthOptions {
+    pub sample_rate: u32,
+    /// Used instead of the program's waveform
+    pub waveform: Option<Waveform>,
+    /// Used instead of the program's envelope
+    pub envelope: Option<Envelope>,
+}
+
+impl Default for SynthOptions {
+    fn default() -> Self {
+        SynthOptions {
+            sample_rate: 44_100,
+            waveform: None,
+            envelope: None,
+        }
+    }
+}
+
+/// Play `events` (e.g. `Conversion::out_events`) as mono samples from -1
+/// to 1, ending when the last note's release ends.
+pub fn render(events: &[OutEvent], options: &SynthOptions) -> Vec<f32> {
+    let rate = options.sample_rate as f32;
+    let mut samples: Vec<f32> = Vec::new();
+    let mut instrument = Instrument::for_program(0);
+    // Seconds at `last_tick`, for tempo changes
+    let mut tempo = DEFAULT_TEMPO;
+    let mut last_tick = 0;
+    let mut last_seconds = 0.0;
+    let mut seconds_at = |tick: u32, tempo: u32| {
+        last_seconds += (tick - last_tick) as f32 * 60.0 / (tempo * TICKS_PER_QUARTER) as f32;
+        last_tick = tick;
+        last_seconds
+    };
+
+    for event in timeline(events) {
+        let start = seconds_at(event.tick, tempo);
+        match event.kind {
+            TimedKind::Tempo(new_tempo) => tempo = new_tempo,
+            TimedKind::Program(program) => instrument = Instrument::for_program(program),
+            TimedKind::Chord { notes, duration } => {
+                let waveform = options.waveform.unwrap_or(instrument.waveform);
+                let envelope = options.envelope.unwrap_or(instrument.envelope);
+                let gate = duration as f32 * 60.0 / (tempo * TICKS_PER_QUARTER) as f32;
+                let first = (start * rate) as usize;
+                let length = ((gate + envelope.release) * rate) as usize;
+                if samples.len() < first + length {
+                    samples.resize(first + length, 0.0);
+                }
+                for note in notes.into_iter().filter(|note| (0..=127).contains(note)) {
+                    let frequency = 440.0 * 2f32.powf((note - 69) as f32 / 12.0);
+                    for (i, sample) in samples[first..first + length].iter_mut().enumerate() {
+                        let time = i as f32 / rate;
+                        let phase = (frequency * time).fract();
+                        *sample +=
+                            oscillator(waveform, phase) * envelope.level(time, gate) * NOTE_GAIN;
+                    }
+                }
+            }
+        }
+    }
+
+    let peak = samples.iter().fold(0.0f32, |peak, s| peak.max(s.abs()));
+    if peak > PEAK {
+        samples.iter_mut().for_each(|s| *s *= PEAK / peak);
+    }
+    samples
+}
+
+/// [`render`] as a WAV file (16-bit PCM, mono).
+///
+/// # Example
+/// ```
+/// use chord2mml_core::{to_wav, Converter, SynthOptions};
+///
+/// let conversion = Converter::new().unwrap().convert_explained("BPM240 C").unwrap();
+/// let wav = to_wav(&conversion.out_events, &SynthOptions::default());
+/// assert_eq!(&wav[..4], b"RIFF");
+/// ```
+pub fn to_wav(events: &[OutEvent], options: &SynthOptions) -> Vec<u8> {
+    let samples = render(events, options);
+    let data_len = (samples.len() * 2) as u32;
+    let mut wav = Vec::with_capacity(44 + samples.len() * 2);
+    wav.extend(b"RIFF");
+    wav.extend((36 + data_len).to_le_bytes());
+    wav.extend(b"WAVEfmt ");
+    wav.extend(16u32.to_le_bytes());
+    wav.extend(1u16.to_le_bytes()); // PCM
+    wav.extend(1u16.to_le_bytes()); // mono
+    wav.extend(options.sample_rate.to_le_bytes());
+    wav.extend((options.sample_rate * 2).to_le_bytes()); // bytes per second
+    wav.extend(2u16.to_le_bytes()); // bytes per frame
+    wav.extend(16u16.to_le_bytes()); // bits per sample
+    wav.extend(b"data");
+    wav.extend(data_len.to_le_bytes());
+    for sample in samples {
+        wav.extend(((sample.clamp(-1.0, 1.0) * i16::MAX as f32) as i16).to_le_bytes());
+    }
+    wav
+}
+
+/// One period of `waveform` at `phase` (0 to 1).
+fn oscillator(waveform: Waveform, phase: f32) -> f32 {
+    match waveform {
+        Waveform::Sine => (phase * TAU).sin(),
+        Waveform::Saw => 2.0 * phase - 1.0,
+        Waveform::Square => {
+            if phase < 0.5 {
+                1.0
+            } else {
+                -1.0
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::notes;
+
+    #[test]
+    fn test_envelope() {
+        let envelope = Envelope {
+            attack: 0.1,
+            decay: 0.2,
+            sustain: 0.5,
+            release: 0.4,
+        };
+        for (time, gate, level) in [
+            (0.05, 1.0, 0.5),
+            (0.2, 1.0, 0.75),
+            (0.5, 1.0, 0.5),
+            (1.2, 1.0, 0.25),
+            (1.4, 1.0, 0.0),
+            // Released during the attack
+            (0.25, 0.05, 0.25),
+        ] {
+            assert!(
+                (envelope.level(time, gate) - level).abs() < 1e-6,
+                "{} {}",
+                time,
+                gate
+            );
+        }
+    }
+
+    #[test]
+    fn test_render_length_follows_tempo() {
+        let options = SynthOptions {
+            sample_rate: 1000,
+            waveform: Some(Waveform::Square),
+            envelope: Some(Envelope {
+                attack: 0.0,
+                decay: 0.0,
+                sustain: 1.0,
+                release: 0.5,
+            }),
+        };
+        // A whole note at 120 is 2 seconds, and 1 second at 240
+        let samples = render(&[notes(vec![9], 1)], &options);
+        assert_eq!(samples.len(), 2500);
+        let events = [
+            OutEvent::InlineMml("t240".to_string()),
+            notes(vec![9], 2),
+            notes(vec![9], 2),
+        ];
+        assert_eq!(render(&events, &options).len(), 1500);
+
+        let peak = samples.iter().fold(0.0f32, |peak, s| peak.max(s.abs()));
+        assert!((peak - NOTE_GAIN).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_mix_is_normalized() {
+        let chord = notes(vec![0, 4, 7, 11, 14, 17, 21], 4);
+        let samples = render(&[chord], &SynthOptions::default());
+        let peak = samples.iter().fold(0.0f32, |peak, s| peak.max(s.abs()));
+        assert!(peak <= PEAK + 1e-6);
+    }
+
+    #[test]
+    fn test_wav_header() {
+        let options = SynthOptions {
+            sample_rate: 8000,
+            ..SynthOptions::default()
+        };
+        let wav = to_wav(&[notes(vec![0], 4)], &options);
+        assert_eq!(&wav[..4], b"RIFF");
+        assert_eq!(&wav[8..16], b"WAVEfmt ");
+        assert_eq!(&wav[24..28], 8000u32.to_le_bytes());
+        assert_eq!(&wav[36..40], b"data");
+        let data_len = u32::from_le_bytes(wav[40..44].try_into().unwrap()) as usize;
+        assert_eq!(wav.len(), 44 + data_len);
+        // A quarter note at 120 (0.5 s) and the piano release (0.3 s)
+        assert_eq!(data_len, 2 * 6400);
+    }
+}
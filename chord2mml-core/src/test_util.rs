@@ -0,0 +1,14 @@
+//! Fixtures shared by the unit tests.
+
+use crate::ast::{NotesEvent, OutEvent};
+
+/// A chord of `notes` (semitones from the base octave's C) lasting
+/// 1/`note_length`, with no letters or span.
+pub(crate) fn notes(notes: Vec<i32>, note_length: u32) -> OutEvent {
+    OutEvent::Notes(NotesEvent {
+        notes,
+        letters: None,
+        note_length: Some(note_length),
+        span: None,
+    })
+}
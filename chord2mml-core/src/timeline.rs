@@ -0,0 +1,131 @@
+//! Timing of the realized chords, for the outputs that are not MML
+//! (MIDI, MusicXML).
+//!
+//! Each chord lasts a whole note divided by its note length (the digit
+//! notes2mml writes after its first note). The inline MML that MML output
+//! passes through is read for what a player would do with it: `t120` sets
+//! the tempo and `@000` the GM program; anything else is ignored.
+
+use crate::ast::OutEvent;
+
+/// Timeline resolution (ticks per quarter note).
+pub const TICKS_PER_QUARTER: u32 = 480;
+/// A whole note, the length of a chord without bars.
+pub const TICKS_PER_WHOLE: u32 = 4 * TICKS_PER_QUARTER;
+/// Quarter notes per minute when no `t` is given (MML's default).
+pub const DEFAULT_TEMPO: u32 = 120;
+
+/// Something that happens at a tick.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TimedEvent {
+    pub tick: u32,
+    pub kind: TimedKind,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TimedKind {
+    /// Quarter notes per minute
+    Tempo(u32),
+    /// GM program number (0-127)
+    Program(u8),
+    /// A chord's notes as MIDI note numbers (middle C is 60), held for
+    /// `duration` ticks
+    Chord { notes: Vec<i32>, duration: u32 },
+}
+
+/// Lay out `events` (the output of the pipeline, e.g.
+/// `Conversion::out_events`) in time, in order.
+pub fn timeline(events: &[OutEvent]) -> Vec<TimedEvent> {
+    let mut tick = 0;
+    let mut timed = Vec::new();
+    for event in events {
+        let kind = match event {
+            OutEvent::Notes(notes_event) if !notes_event.notes.is_empty() => {
+                let duration = TICKS_PER_WHOLE / notes_event.note_length.unwrap_or(1).max(1);
+                timed.push(TimedEvent {
+                    tick,
+                    kind: TimedKind::Chord {
+                        notes: notes_event.notes.iter().map(|note| note + 60).collect(),
+                        duration,
+                    },
+                });
+                tick += duration;
+                continue;
+            }
+            OutEvent::InlineMml(mml) => match read_inline_mml(mml) {
+                Some(kind) => kind,
+                None => continue,
+            },
+            _ => continue,
+        };
+        timed.push(TimedEvent { tick, kind });
+    }
+    timed
+}
+
+/// The tempo or program an inline MML event sets, if it is one.
+fn read_inline_mml(mml: &str) -> Option<TimedKind> {
+    let number = |digits: &str| -> Option<u32> {
+        if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+            return None;
+        }
+        digits.parse().ok()
+    };
+    if let Some(digits) = mml.strip_prefix('t') {
+        return number(digits)
+            .filter(|&tempo| tempo > 0)
+            .map(TimedKind::Tempo);
+    }
+    if let Some(digits) = mml.strip_prefix('@') {
+        return number(digits)
+            .and_then(|program| u8::try_from(program).ok())
+            .filter(|&program| program < 128)
+            .map(TimedKind::Program);
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::notes;
+
+    #[test]
+    fn test_timeline() {
+        let events = [
+            OutEvent::InlineMml("t90".to_string()),
+            OutEvent::InlineMml("@025".to_string()),
+            notes(vec![0, 4, 7], 2),
+            OutEvent::Bar,
+            OutEvent::InlineMml("o5".to_string()),
+            notes(vec![7, 11, 14], 1),
+        ];
+        assert_eq!(
+            timeline(&events),
+            [
+                TimedEvent {
+                    tick: 0,
+                    kind: TimedKind::Tempo(90)
+                },
+                TimedEvent {
+                    tick: 0,
+                    kind: TimedKind::Program(25)
+                },
+                TimedEvent {
+                    tick: 0,
+                    kind: TimedKind::Chord {
+                        notes: vec![60, 64, 67],
+                        duration: 960
+                    }
+                },
+                TimedEvent {
+                    tick: 960,
+                    kind: TimedKind::Chord {
+                        notes: vec![67, 71, 74],
+                        duration: 1920
+                    }
+                },
+            ]
+        );
+    }
+}
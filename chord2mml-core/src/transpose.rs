@@ -0,0 +1,102 @@
+//! Transposition of the event stream (Rust-version extension,
+//! `ConvertOptions::transpose`).
+//!
+//! Runs right after `cst_to_ast`, so degrees are already resolved against
+//! the key as written. Roots move by the full interval (`transpose: 12`
+//! is an octave up) and keys follow, so key spelling and later key
+//! directives stay in step. Root letters move by the interval's letter
+//! steps (up a major second: C → D, F# → G#) for chord spelling.
+
+use crate::ast::{Event, SlashChordEvent};
+
+/// Letter steps for each interval class: the usual spelling of the
+/// interval (minor/major second → 1, tritone → augmented fourth).
+const LETTER_STEPS: [u8; 12] = [0, 1, 1, 2, 2, 3, 3, 4, 5, 5, 6, 6];
+
+/// Move every chord and key in `events` by `semitones`.
+pub(crate) fn transpose(events: &mut [Event], semitones: i32) {
+    if semitones == 0 {
+        return;
+    }
+    let letter_steps = LETTER_STEPS[semitones.rem_euclid(12) as usize];
+    let move_letter = |letter: Option<u8>| letter.map(|letter| (letter + letter_steps) % 7);
+
+    for event in events {
+        match event {
+            Event::Chord(chord) => {
+                chord.root += semitones;
+                chord.root_letter = move_letter(chord.root_letter);
+            }
+            Event::SlashChord(slash)
+            | Event::ChordOverBassNote(slash)
+            | Event::Inversion(slash)
+            | Event::Polychord(slash) => transpose_slash(slash, semitones, move_letter),
+            Event::Key { offset, .. } => *offset = (*offset + semitones).rem_euclid(12),
+            _ => {}
+        }
+    }
+}
+
+fn transpose_slash(
+    slash: &mut SlashChordEvent,
+    semitones: i32,
+    move_letter: impl Fn(Option<u8>) -> Option<u8>,
+) {
+    slash.upper_root += semitones;
+    slash.upper_root_letter = move_letter(slash.upper_root_letter);
+    slash.lower_root += semitones;
+    slash.lower_root_letter = move_letter(slash.lower_root_letter);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ast::ChordEvent;
+
+    fn chord(root: i32, root_letter: u8) -> Event {
+        Event::Chord(ChordEvent {
+            root,
+            root_letter: Some(root_letter),
+            quality: "maj".to_string(),
+            inversion: None,
+            octave_offset: 0,
+            note_length: None,
+            span: None,
+        })
+    }
+
+    #[test]
+    fn test_transpose_roots_letters_and_keys() {
+        let mut events = vec![
+            Event::Key {
+                offset: 10,
+                minor: false,
+            },
+            chord(6, 3),
+        ];
+        transpose(&mut events, 2);
+        // F# up a major second is G#, key of Bb becomes C
+        assert_eq!(
+            events,
+            [
+                Event::Key {
+                    offset: 0,
+                    minor: false,
+                },
+                chord(8, 4)
+            ]
+        );
+
+        transpose(&mut events, -14);
+        assert_eq!(
+            events,
+            [
+                Event::Key {
+                    offset: 10,
+                    minor: false,
+                },
+                chord(-6, 3)
+            ]
+        );
+    }
+}
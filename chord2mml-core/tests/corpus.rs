@@ -1,11 +1,14 @@
 //! Golden-corpus test: runs every case in tests/corpus/*.json through the
-//! native pipeline and compares with the expected output.
+//! native pipeline, with both the tree-sitter and the pure-Rust parser,
+//! and compares with the expected output. An incremental `Session` edits
+//! its text from case to case and must agree with `convert`.
 //!
 //! The corpus is the spec, extracted from the original JS chord2mml's test
 //! suite and verified by executing the JS implementation (see the restart
 //! plan). The same corpus is run through the WASM path by
 //! chord2mml-web/test/wasm-path-test.mjs.
 
+use chord2mml_core::{ConvertOptions, Converter, Session};
 use serde::Deserialize;
 use std::path::Path;
 
@@ -21,6 +24,9 @@ fn corpus() {
     let mut failures = Vec::new();
     let mut total = 0;
     let mut file_count = 0;
+    let mut tree_sitter = Converter::new().expect("tree-sitter converter");
+    let mut pure_rust = Converter::pure_rust(ConvertOptions::default());
+    let mut session = Session::new(ConvertOptions::default()).expect("session");
 
     let mut entries: Vec<_> = std::fs::read_dir(&corpus_dir)
         .expect("corpus dir")
@@ -39,16 +45,34 @@ fn corpus() {
 
         for case in &cases {
             total += 1;
-            match chord2mml_core::convert(&case.input) {
-                Ok(actual) => {
-                    if actual != case.expected {
-                        failures.push(format!(
-                            "[{}] {:?}: expected {:?}, got {:?}",
-                            name, case.input, case.expected, actual
-                        ));
+            for (parser, converter) in [
+                ("tree-sitter", &mut tree_sitter),
+                ("pure-rust", &mut pure_rust),
+            ] {
+                match converter.convert(&case.input) {
+                    Ok(actual) => {
+                        if actual != case.expected {
+                            failures.push(format!(
+                                "[{}, {}] {:?}: expected {:?}, got {:?}",
+                                name, parser, case.input, case.expected, actual
+                            ));
+                        }
                     }
+                    Err(e) => failures.push(format!(
+                        "[{}, {}] {:?}: error {}",
+                        name, parser, case.input, e
+                    )),
                 }
-                Err(e) => failures.push(format!("[{}] {:?}: error {}", name, case.input, e)),
+            }
+
+            // Replace the previous case's text, reusing its tree and steps
+            let edited = session.edit(0..session.text().len(), &case.input);
+            let converted = tree_sitter.convert(&case.input);
+            if edited.as_ref().ok() != converted.as_ref().ok() {
+                failures.push(format!(
+                    "[{}, session] {:?}: {:?}, convert gave {:?}",
+                    name, case.input, edited, converted
+                ));
             }
         }
     }
@@ -56,10 +80,13 @@ fn corpus() {
     assert!(file_count > 0, "no corpus files found in {:?}", corpus_dir);
     assert!(
         failures.is_empty(),
-        "{} of {} corpus case(s) failed:\n{}",
+        "{} failure(s) in {} corpus cases:\n{}",
         failures.len(),
         total,
         failures.join("\n")
     );
-    println!("all {} corpus cases passed ({} files)", total, file_count);
+    println!(
+        "all {} corpus cases passed with both parsers ({} files)",
+        total, file_count
+    );
 }
@@ -1,14 +1,116 @@
 //! WASM bindings for chord2mml-core.
 //!
 //! This crate builds for `wasm32-unknown-unknown` with chord2mml-core's
-//! default features (no tree-sitter C dependency). Parsing happens in the
-//! browser via web-tree-sitter (JavaScript) + tree-sitter-chord.wasm; the
-//! resulting CST is passed here as JSON and converted to MML in Rust.
-//! This follows the architecture proven in tonejs-mml-to-json.
+//! default features (no tree-sitter C dependency). [`convert`] parses with
+//! chord2mml-core's pure-Rust parser, so it needs nothing else. Pages that
+//! already run web-tree-sitter (JavaScript) + tree-sitter-chord.wasm can
+//! pass the resulting CST as JSON to [`convert_cst`] instead, following
+//! the architecture proven in tonejs-mml-to-json.
 
+use chord2mml_core::{Conversion, ConvertOptions, Converter, InputError, LineError, Span};
+use serde::{Deserialize, Serialize};
 use wasm_bindgen::prelude::*;
 
-/// Convert a CST JSON (produced by web-tree-sitter) to MML.
+#[wasm_bindgen(typescript_custom_section)]
+const TS_TYPES: &str = r#"
+/** A range `start..end` of UTF-16 indices (JavaScript string indices). */
+export interface Span { start: number; end: number }
+/** Where a chord was written in the input and where its `'...'` group is in the MML. */
+export interface SourceMapping { input: Span; output: Span }
+/** A dialect reading of the input and the rewrite rules applied to get it. */
+export interface Rewrite { text: string; rules: string[] }
+/** The MIDI note numbers one chord sounds (c of the default octave is 60). */
+export interface ChordNotes { notes: number[]; span: Span | null }
+export interface Warning { message: string; span: Span | null }
+/** A conversion result; input spans index `rewrite.text`. */
+export interface Conversion {
+  mml: string;
+  rewrite: Rewrite;
+  sourceMap: SourceMapping[];
+  chords: ChordNotes[];
+  warnings: Warning[];
+}
+/**
+ * Options of `convert_with_options`. `maxInputLen` is the longest input in
+ * characters (each line with `multiLine`), 0 for no limit (default 1000);
+ * `multiLine` converts the lines as one chart, each starting in the key,
+ * modes and octave shifts the lines before it left; `chart` reads the input
+ * as a song chart (ChordPro `[Am]Hello` text or chords over lyrics) and
+ * converts its chords, each line of chords one bar with `lineBars`.
+ */
+export interface ConvertOptions {
+  maxInputLen?: number;
+  multiLine?: boolean;
+  chart?: boolean;
+  lineBars?: boolean;
+}
+/** What the conversion functions throw; `span` locates the error in the input when known. */
+export interface ConvertError extends Error { span: Span | null }
+"#;
+
+/// Convert chord notation to MML, with the same semantics as the native
+/// `chord2mml_core::convert` (dialect rewrites included). Throws a
+/// `ConvertError`.
+///
+/// # Example (JavaScript)
+/// ```javascript
+/// import init, { convert } from './chord2mml_wasm.js';
+///
+/// await init();
+/// console.log(convert('Dm G7 C')); // "v11'd1fa''g1b<df''c1eg'"
+/// ```
+#[wasm_bindgen]
+pub fn convert(input: &str) -> Result<String, JsValue> {
+    explain(input, &Options::default())
+        .map(|conversion| conversion.mml)
+        .map_err(to_js_error)
+}
+
+/// [`convert`] returning a `Conversion`: the MML, the notes of each chord,
+/// the dialect rewrite that converted, warnings and the source map.
+///
+/// # Example (JavaScript)
+/// ```javascript
+/// try {
+///   const { mml, chords, rewrite } = convert_explained('ii V I');
+///   rewrite.rules;   // ["minor-roman-numerals"]
+///   chords[0].notes; // [62, 65, 69]
+/// } catch (e) {
+///   highlight(e.span); // null when the error has no position
+/// }
+/// ```
+#[wasm_bindgen(unchecked_return_type = "Conversion")]
+pub fn convert_explained(input: &str) -> Result<JsValue, JsValue> {
+    explain(input, &Options::default())
+        .map_err(to_js_error)
+        .and_then(to_js)
+}
+
+/// [`convert_explained`] with `ConvertOptions`, e.g. to convert a whole
+/// song. With `multiLine`, `rewrite.text` holds the reading of each
+/// non-blank line, one per line, and errors name the line.
+///
+/// # Example (JavaScript)
+/// ```javascript
+/// const { mml } = convert_with_options(chart, { maxInputLen: 0, multiLine: true });
+/// ```
+#[wasm_bindgen(unchecked_return_type = "Conversion")]
+pub fn convert_with_options(
+    input: &str,
+    #[wasm_bindgen(unchecked_param_type = "ConvertOptions")] options: JsValue,
+) -> Result<JsValue, JsValue> {
+    let options: Options = if options.is_undefined() || options.is_null() {
+        Options::default()
+    } else {
+        serde_wasm_bindgen::from_value(options)?
+    };
+    explain(input, &options)
+        .map_err(to_js_error)
+        .and_then(to_js)
+}
+
+/// Convert a CST JSON (produced by web-tree-sitter) to MML. Throws a
+/// `ConvertError`.
 ///
 /// # Example (JavaScript)
 /// ```javascript
@@ -22,17 +124,300 @@ use wasm_bindgen::prelude::*;
 /// ```
 #[wasm_bindgen]
 pub fn convert_cst(cst_json: &str) -> Result<String, JsValue> {
-    chord2mml_core::convert_cst(cst_json).map_err(|e| JsValue::from_str(&e.to_string()))
+    explain_cst(cst_json)
+        .map(|conversion| conversion.mml)
+        .map_err(to_js_error)
 }
 
-/// Return all dialect-rewrite candidates for an input as a JSON string
-/// array, in trial order (the original first). The JavaScript side parses
-/// each candidate with web-tree-sitter and converts the first one that
-/// succeeds — the same brute-force contract as the JS chord2mml.parse.
+/// [`convert_cst`] returning a `Conversion`. `rewrite` is the CST's text
+/// with no rules (the page chose what to parse), and spans come from the
+/// CST's `startIndex`/`endIndex`.
+#[wasm_bindgen(unchecked_return_type = "Conversion")]
+pub fn convert_cst_explained(cst_json: &str) -> Result<JsValue, JsValue> {
+    explain_cst(cst_json).map_err(to_js_error).and_then(to_js)
+}
+
+/// Convert a CST JSON to MML with a source map, returned as `{mml,
+/// sourceMap}`. Input spans come from the CST's `startIndex`/`endIndex`
+/// (UTF-16 units, so they index the JS string); the MML is ASCII, so
+/// output offsets index it directly.
+///
+/// # Example (JavaScript)
+/// ```javascript
+/// const { mml, sourceMap } = convert_cst_with_source_map(JSON.stringify(cstJson));
+/// const { output } = sourceMap[1];
+/// mml.slice(output.start, output.end); // "'f1a<c'" for "C F"
+/// ```
+#[wasm_bindgen(unchecked_return_type = "{ mml: string; sourceMap: SourceMapping[] }")]
+pub fn convert_cst_with_source_map(cst_json: &str) -> Result<JsValue, JsValue> {
+    #[derive(Serialize)]
+    #[serde(rename_all = "camelCase")]
+    struct WithSourceMap {
+        mml: String,
+        source_map: Vec<chord2mml_core::SourceMapping>,
+    }
+
+    let conversion = explain_cst(cst_json).map_err(to_js_error)?;
+    to_js(WithSourceMap {
+        mml: conversion.mml,
+        source_map: conversion.source_map,
+    })
+}
+
+/// Return all dialect-rewrite candidates for an input, in trial order
+/// (the input as written first, after any eager chord respelling). The
+/// JavaScript side parses each candidate with web-tree-sitter and converts
+/// the first one that succeeds — the same brute-force contract as the JS
+/// chord2mml.parse.
 #[wasm_bindgen]
-pub fn preprocess_candidates(input: &str) -> String {
-    serde_json::to_string(&chord2mml_core::preprocess_candidates(input))
-        .unwrap_or_else(|_| "[]".to_string())
+pub fn preprocess_candidates(input: &str) -> Vec<String> {
+    chord2mml_core::preprocess_candidates(input)
+}
+
+/// Return the dialect-rewrite candidates as `Rewrite` objects, in the
+/// same trial order as [`preprocess_candidates`]; `rules` names the
+/// rewrites applied, so the page can show how the input was interpreted.
+#[wasm_bindgen(unchecked_return_type = "Rewrite[]")]
+pub fn preprocess_rewrites(input: &str) -> Result<JsValue, JsValue> {
+    to_js(chord2mml_core::preprocess_rewrites(input))
+}
+
+/// An error on its way to JavaScript, with its span in UTF-16 units.
+#[derive(Debug, PartialEq)]
+struct Diagnostic {
+    message: String,
+    span: Option<Span>,
+}
+
+impl Diagnostic {
+    /// `error`, whose span (if any) indexes `text` in bytes, or is already
+    /// in UTF-16 units when there is no `text`.
+    /// The span of a [`LineError`] indexes its line of `text`.
+    fn new(error: anyhow::Error, text: Option<&str>) -> Self {
+        let line_span = error.downcast_ref::<LineError>().and_then(|e| {
+            let text = text?;
+            let line = text.lines().nth(e.line - 1)?;
+            let start = line.as_ptr() as usize - text.as_ptr() as usize;
+            e.span.map(|span| {
+                let span = Span {
+                    start: start + span.start,
+                    end: start + span.end,
+                };
+                utf16_span(text, span)
+            })
+        });
+        let span = line_span.or_else(|| {
+            error.downcast_ref::<InputError>().map(|e| match text {
+                Some(text) => utf16_span(text, e.span),
+                None => e.span,
+            })
+        });
+        Diagnostic {
+            message: error.to_string(),
+            span,
+        }
+    }
+}
+
+/// The `ConvertOptions` of [`convert_with_options`].
+#[derive(Debug, Default, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+struct Options {
+    /// In characters, 0 for no limit; `None` for the default
+    max_input_len: Option<usize>,
+    multi_line: bool,
+    chart: bool,
+    line_bars: bool,
+}
+
+impl Options {
+    fn convert_options(&self) -> ConvertOptions {
+        let defaults = ConvertOptions::default();
+        ConvertOptions {
+            max_input_len: match self.max_input_len {
+                Some(0) => None,
+                Some(max) => Some(max),
+                None => defaults.max_input_len,
+            },
+            ..defaults
+        }
+    }
+}
+
+/// Convert with the pure-Rust parser, with spans in UTF-16 units: those
+/// of the conversion index `rewrite.text`, an error's indexes `input`.
+fn explain(input: &str, options: &Options) -> Result<Conversion, Diagnostic> {
+    let mut converter = Converter::pure_rust(options.convert_options());
+    let converted = if options.chart {
+        converter.convert_chart_explained(input, options.line_bars)
+    } else if options.multi_line {
+        converter.convert_lines_explained(input)
+    } else {
+        converter.convert_explained(input)
+    };
+    let mut conversion = converted.map_err(|e| Diagnostic::new(e, Some(input)))?;
+
+    let text = &conversion.rewrite.text;
+    for mapping in &mut conversion.source_map {
+        mapping.input = utf16_span(text, mapping.input);
+    }
+    let chord_spans = conversion.chords.iter_mut().map(|chord| &mut chord.span);
+    let warning_spans = conversion
+        .warnings
+        .iter_mut()
+        .map(|warning| &mut warning.span);
+    for span in chord_spans.chain(warning_spans).flatten() {
+        *span = utf16_span(text, *span);
+    }
+    Ok(conversion)
+}
+
+/// Convert a CST JSON; its spans are already in the page's units.
+fn explain_cst(cst_json: &str) -> Result<Conversion, Diagnostic> {
+    chord2mml_core::convert_cst_explained(cst_json, &Default::default())
+        .map_err(|e| Diagnostic::new(e, None))
+}
+
+/// A plain JS object (`null` for `None`, as in JSON).
+fn to_js(value: impl Serialize) -> Result<JsValue, JsValue> {
+    value
+        .serialize(&serde_wasm_bindgen::Serializer::json_compatible())
+        .map_err(JsValue::from)
+}
+
+/// A JS `Error` with a `span` property (a `ConvertError`).
+fn to_js_error(diagnostic: Diagnostic) -> JsValue {
+    let error = js_sys::Error::new(&diagnostic.message);
+    let span = match diagnostic.span {
+        Some(span) => to_js(span).unwrap_or(JsValue::NULL),
+        None => JsValue::NULL,
+    };
+    let _ = js_sys::Reflect::set(&error, &"span".into(), &span);
+    error.into()
+}
+
+/// An incremental conversion session for live editing. Parsing stays in
+/// JavaScript: `parse(text, edit)` is called with the new text and the
+/// edit (`{startIndex, oldEndIndex, newEndIndex}` in UTF-16 units, for
+/// web-tree-sitter's `tree.edit` before reparsing with the old tree) and
+/// returns the CST JSON, or `null` on a syntax error. Dialect rewrites are
+/// parsed with `edit` undefined, in the order `convert` tries them.
+/// Voicing and MML writing rerun only for the events an edit changed.
+///
+/// # Example (JavaScript)
+/// ```javascript
+/// let tree = null;
+/// const session = new Session((text, edit) => {
+///   if (edit && tree) tree.edit({ ...edit, ...positionsFor(edit) });
+///   const next = parser.parse(text, edit ? tree : null);
+///   if (edit) tree = next;
+///   return next.rootNode.hasError ? null : JSON.stringify(serializeCst(next.rootNode));
+/// });
+/// session.edit(0, 0, 'C F');   // "v11'c1eg''f1a<c'"
+/// session.edit(2, 3, 'G7');    // "v11'c1eg''g1b<df'"
+/// ```
+#[wasm_bindgen]
+pub struct Session {
+    text: String,
+    parse: js_sys::Function,
+    stages: chord2mml_core::CstSession,
+}
+
+#[wasm_bindgen]
+impl Session {
+    #[wasm_bindgen(constructor)]
+    pub fn new(parse: js_sys::Function) -> Session {
+        Session {
+            text: String::new(),
+            parse,
+            stages: chord2mml_core::CstSession::new(Default::default()),
+        }
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn text(&self) -> String {
+        self.text.clone()
+    }
+
+    /// Replace the UTF-16 range `start..end` of the text with `text` and
+    /// return the MML of the result. Throws a `ConvertError`.
+    pub fn edit(&mut self, start: usize, end: usize, text: &str) -> Result<String, JsValue> {
+        let range = match (
+            utf16_to_byte(&self.text, start),
+            utf16_to_byte(&self.text, end),
+        ) {
+            (Some(s), Some(e)) if s <= e => s..e,
+            _ => return Err(JsValue::from_str("Invalid edit range")),
+        };
+        self.text.replace_range(range, text);
+
+        let edit = js_sys::Object::new();
+        let new_end = start + text.encode_utf16().count();
+        for (key, value) in [
+            ("startIndex", start),
+            ("oldEndIndex", end),
+            ("newEndIndex", new_end),
+        ] {
+            js_sys::Reflect::set(&edit, &key.into(), &(value as f64).into())?;
+        }
+        // The candidates of `convert`, in its order; the input as written
+        // is parsed incrementally with the edit, the rewrites from
+        // scratch, and its error is the one reported
+        let mut written_error = None;
+        for rewrite in chord2mml_core::preprocess_rewrites(self.text.trim()) {
+            let written = rewrite.rules.is_empty();
+            let cst = if written {
+                self.parse
+                    .call2(&JsValue::NULL, &self.text.as_str().into(), &edit)?
+            } else {
+                self.parse.call2(
+                    &JsValue::NULL,
+                    &rewrite.text.as_str().into(),
+                    &JsValue::UNDEFINED,
+                )?
+            };
+            let error = match cst.as_string() {
+                Some(cst_json) => match self.stages.update(&cst_json) {
+                    Ok(mml) => return Ok(mml),
+                    Err(e) => Diagnostic::new(e, None),
+                },
+                None => Diagnostic {
+                    message: format!("Syntax error in chord notation: {}", rewrite.text),
+                    span: None,
+                },
+            };
+            if written {
+                written_error = Some(error);
+            }
+        }
+        let written_error = written_error.expect("the input as written is a candidate");
+        Err(to_js_error(written_error))
+    }
+}
+
+/// `span` (bytes of `text`) in UTF-16 units.
+fn utf16_span(text: &str, span: Span) -> Span {
+    let units = |byte: usize| text.get(..byte).map_or(byte, |s| s.encode_utf16().count());
+    Span {
+        start: units(span.start),
+        end: units(span.end),
+    }
+}
+
+/// Byte offset of a UTF-16 index into `text`, None past the end or inside
+/// a surrogate pair.
+fn utf16_to_byte(text: &str, index: usize) -> Option<usize> {
+    let mut units = 0;
+    for (byte, c) in text.char_indices() {
+        if units == index {
+            return Some(byte);
+        }
+        units += c.len_utf16();
+        if units > index {
+            return None;
+        }
+    }
+    (units == index).then_some(text.len())
 }
 
 #[cfg(test)]
@@ -57,12 +442,99 @@ mod tests {
         ]
     }"#;
 
+    #[wasm_bindgen_test]
+    fn test_convert() {
+        assert_eq!(convert("Dm G7 C").unwrap(), "v11'd1fa''g1b<df''c1eg'");
+        assert_eq!(convert("ii-V-I").unwrap(), convert("IIm V I").unwrap());
+        assert!(convert("").is_err());
+    }
+
     #[wasm_bindgen_test]
     fn test_convert_cst_c_major() {
         let result = convert_cst(C_MAJOR_CST).unwrap();
         assert_eq!(result, "v11'c1eg'");
     }
 
+    #[test]
+    fn test_explain_cst_source_map() {
+        let cst = C_MAJOR_CST.replace(
+            r#""type": "chord","#,
+            r#""type": "chord", "startIndex": 2, "endIndex": 3,"#,
+        );
+        let conversion = explain_cst(&cst).unwrap();
+        assert_eq!(
+            serde_json::to_value(&conversion.source_map).unwrap(),
+            serde_json::json!([{
+                "input": { "start": 2, "end": 3 },
+                "output": { "start": 3, "end": 9 }
+            }])
+        );
+        assert_eq!(conversion.chords[0].notes, [60, 64, 67]);
+        assert_eq!(conversion.chords[0].span, Some(Span { start: 2, end: 3 }));
+    }
+
+    #[test]
+    fn test_explain_spans_are_utf16() {
+        // "𝄞" is 4 bytes but 2 UTF-16 units
+        let conversion = explain("C F /*𝄞*/ G7", &Options::default()).unwrap();
+        let spans: Vec<_> = conversion.chords.iter().map(|c| c.span.unwrap()).collect();
+        assert_eq!(spans[2], Span { start: 11, end: 13 });
+        assert_eq!(conversion.source_map[2].input, spans[2]);
+
+        let error = explain("𝄞 C $", &Options::default()).unwrap_err();
+        assert_eq!(error.span, Some(Span { start: 0, end: 2 }));
+    }
+
+    #[test]
+    fn test_explain_with_options() {
+        let options: Options =
+            serde_json::from_value(serde_json::json!({ "multiLine": true })).unwrap();
+        assert_eq!(
+            options,
+            Options {
+                multi_line: true,
+                ..Options::default()
+            }
+        );
+        let conversion = explain("key=D\n\nI IV\nV I", &options).unwrap();
+        assert_eq!(conversion.mml, convert("key=D I IV V I").unwrap());
+        assert_eq!(conversion.rewrite.text, "key=D\nI IV\nV I");
+        let error = explain("C\n 𝄞 $", &options).unwrap_err();
+        assert!(error.message.starts_with("Line 2:"), "{}", error.message);
+        assert_eq!(error.span, Some(Span { start: 3, end: 5 }));
+
+        let song = "C F G C ".repeat(200);
+        assert!(explain(&song, &Options::default()).is_err());
+        let unlimited = Options {
+            max_input_len: Some(0),
+            ..Options::default()
+        };
+        assert!(explain(&song, &unlimited).is_ok());
+
+        let options: Options =
+            serde_json::from_value(serde_json::json!({ "chart": true, "lineBars": true })).unwrap();
+        let conversion = explain("[C]Hello [G]world\n[Am]so [F]long", &options).unwrap();
+        assert_eq!(conversion.rewrite.text, "C G | Am F");
+        assert_eq!(conversion.rewrite.rules, ["chord-chart"]);
+    }
+
+    #[test]
+    fn test_explain_cst_error() {
+        let error = explain_cst("{").unwrap_err();
+        assert!(error.message.starts_with("Failed to parse CST JSON"));
+        assert_eq!(error.span, None);
+    }
+
+    #[test]
+    fn test_utf16_to_byte() {
+        assert_eq!(utf16_to_byte("C・F", 0), Some(0));
+        assert_eq!(utf16_to_byte("C・F", 2), Some(4));
+        assert_eq!(utf16_to_byte("C・F", 3), Some(5));
+        assert_eq!(utf16_to_byte("C・F", 4), None);
+        assert_eq!(utf16_to_byte("𝄞C", 1), None);
+        assert_eq!(utf16_to_byte("𝄞C", 2), Some(4));
+    }
+
     #[wasm_bindgen_test]
     fn test_convert_cst_empty() {
         let result = convert_cst("");
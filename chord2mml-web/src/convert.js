@@ -1,26 +1,41 @@
 // Shared conversion with dialect-preprocess retry, used by the web app
 // and the Node WASM-path test. Mirrors the JS chord2mml.parse contract:
 // try the input as written, then every dialect rewrite (candidates come
-// from the Rust preprocess_candidates), and report the first candidate's
-// error if nothing converts.
+// from the Rust preprocess_rewrites), and report the error of the input as
+// written (the rewrite with no rules) if nothing converts.
+//
+// Returns the chord2mml-wasm Conversion ({mml, rewrite, chords, warnings,
+// sourceMap}) of the candidate that converted; errors carry a `span`
+// (null when the position is unknown), like the WASM ConvertError.
 import { nodeToCSTJson } from './cst-serializer.js';
 
 export function convertWithPreprocess(parser, wasm, input) {
-  const candidates = JSON.parse(wasm.preprocess_candidates(input));
-  let firstError = null;
+  const rewrites = wasm.preprocess_rewrites(input);
+  let writtenError = null;
 
-  for (const candidate of candidates) {
-    const tree = parser.parse(candidate);
+  for (const rewrite of rewrites) {
+    const tree = parser.parse(rewrite.text);
+    const written = rewrite.rules.length === 0;
     if (!tree || tree.rootNode.hasError) {
-      firstError ??= new Error(`コード表記を解釈できません: ${candidate}`);
+      if (written) {
+        writtenError = Object.assign(
+          new Error(`コード表記を解釈できません: ${rewrite.text}`),
+          { span: null }
+        );
+      }
       continue;
     }
     try {
-      return wasm.convert_cst(JSON.stringify(nodeToCSTJson(tree.rootNode)));
+      const conversion = wasm.convert_cst_explained(
+        JSON.stringify(nodeToCSTJson(tree.rootNode))
+      );
+      return { ...conversion, rewrite };
     } catch (e) {
-      firstError ??= e instanceof Error ? e : new Error(String(e));
+      if (written) {
+        writtenError = e instanceof Error ? e : Object.assign(new Error(String(e)), { span: null });
+      }
     }
   }
 
-  throw firstError ?? new Error('入力が空です');
+  throw writtenError ?? Object.assign(new Error('入力が空です'), { span: null });
 }
@@ -6,6 +6,8 @@ export function nodeToCSTJson(node) {
   const result = {
     type: node.type,
     text: node.text,
+    startIndex: node.startIndex,
+    endIndex: node.endIndex,
     children: [],
     fields: {}
   };
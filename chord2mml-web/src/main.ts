@@ -2,8 +2,8 @@
 //
 // Parsing pipeline (tonejs-mml-to-json pattern):
 //   input → web-tree-sitter (JS) + tree-sitter-chord.wasm → CST JSON
-//         → chord2mml-wasm convert_cst (Rust) → MML
-import init, { convert_cst, preprocess_candidates } from '../public/wasm/chord2mml_wasm.js';
+//         → chord2mml-wasm convert_cst_explained (Rust) → MML
+import init, { convert_cst_explained, preprocess_rewrites, type Conversion } from '../public/wasm/chord2mml_wasm.js';
 import { Parser, Language } from 'web-tree-sitter';
 import treeSitterWasmUrl from 'web-tree-sitter/web-tree-sitter.wasm?url';
 import { convertWithPreprocess } from './convert.js';
@@ -126,11 +126,11 @@ function showStatus(message: string, type: 'success' | 'error') {
     }, 3000);
 }
 
-function convertChord(input: string): string {
+function convertChord(input: string): Conversion {
     if (!parser) {
         throw new Error('パーサー未初期化');
     }
-    return convertWithPreprocess(parser, { convert_cst, preprocess_candidates }, input);
+    return convertWithPreprocess(parser, { convert_cst_explained, preprocess_rewrites }, input);
 }
 
 async function updateOutput(chord: string) {
@@ -142,9 +142,12 @@ async function updateOutput(chord: string) {
     }
 
     try {
-        const mml = convertChord(input);
+        const { mml, warnings } = convertChord(input);
         output.textContent = mml;
         output.classList.remove('error');
+        if (warnings.length > 0) {
+            showStatus(warnings.map(w => w.message).join('\n'), 'error');
+        }
 
         // Auto-play the generated MML
         if (mml && audioSequencer) {
@@ -1,15 +1,16 @@
-// End-to-end test of the WASM path without a browser:
+// End-to-end test of the WASM paths without a browser:
 //   input → web-tree-sitter (Node) + tree-sitter-chord.wasm → CST JSON
-//         → chord2mml-wasm convert_cst (Rust WASM) → MML
-// This is the same pipeline the browser runs, so it verifies the full
+//         → chord2mml-wasm convert_cst_explained (Rust WASM) → MML
+//   input → chord2mml-wasm convert (pure-Rust parser) → MML
+// The first is the pipeline the browser runs, so it verifies the full
 // WASM-safe architecture (tonejs-mml-to-json pattern).
 //
 // Runs the same golden corpus as the native test
-// (chord2mml-core/tests/corpus/*.json), guaranteeing both paths agree.
+// (chord2mml-core/tests/corpus/*.json), guaranteeing all paths agree.
 import { readFile, readdir } from 'fs/promises';
 import { fileURLToPath } from 'url';
 import { Parser, Language } from 'web-tree-sitter';
-import init, { convert_cst, preprocess_candidates } from '../public/wasm/chord2mml_wasm.js';
+import init, { convert_explained, convert_cst_explained, preprocess_rewrites } from '../public/wasm/chord2mml_wasm.js';
 import { convertWithPreprocess } from '../src/convert.js';
 
 const corpusDir = new URL('../../chord2mml-core/tests/corpus/', import.meta.url);
@@ -38,31 +39,47 @@ async function main() {
   const parser = new Parser();
   parser.setLanguage(language);
 
+  const wasm = { convert_cst_explained, preprocess_rewrites };
+  const paths = {
+    'web-tree-sitter': (input) => convertWithPreprocess(parser, wasm, input).mml,
+    'pure-Rust convert': (input) => convert_explained(input).mml,
+  };
+  let failed = 0;
+  for (const [path, convertInput] of Object.entries(paths)) {
+    failed += runCorpus(path, convertInput);
+  }
+  if (failed > 0) {
+    process.exit(1);
+  }
+}
+
+// Run every corpus case through one path; returns the failure count.
+function runCorpus(path, convertInput) {
   let failed = 0;
   let passed = 0;
-  const wasm = { convert_cst, preprocess_candidates };
   for (const { input, expected } of cases) {
     let actual;
     try {
-      actual = convertWithPreprocess(parser, wasm, input);
+      actual = convertInput(input);
     } catch (e) {
-      console.error(`✗ ${input}: ${e}`);
+      console.error(`✗ [${path}] ${input}: ${e}`);
       failed++;
       continue;
     }
     if (actual === expected) {
       passed++;
     } else {
-      console.error(`✗ ${input}: expected ${expected}, got ${actual}`);
+      console.error(`✗ [${path}] ${input}: expected ${expected}, got ${actual}`);
       failed++;
     }
   }
 
   if (failed > 0) {
-    console.error(`${failed} of ${failed + passed} corpus case(s) failed on the WASM path`);
-    process.exit(1);
+    console.error(`${failed} of ${failed + passed} corpus case(s) failed on the ${path} path`);
+  } else {
+    console.log(`All ${passed} corpus cases passed on the ${path} path`);
   }
-  console.log(`All ${passed} corpus cases passed on the WASM path`);
+  return failed;
 }
 
 main().catch((e) => {
@@ -12,6 +12,8 @@
 //
 // Semantics (root numbers, quality normalization) live in
 // chord2mml-core/src/cst_to_ast.rs — this grammar only names the tokens.
+// chord2mml-core/src/pure_parser.rs ports it to Rust for builds without
+// the C parser; change both together.
 
 // Build a case-insensitive regex source for a literal (JS "..."i);
 // spaces stay literal single spaces, matching the PEG grammar.
@@ -43,7 +45,8 @@ function inst(name) {
 // GM instrument name aliases (JS PC000-PC127). The alias → program number
 // mapping lives in chord2mml-core/src/cst_to_ast.rs (GM_PROGRAM_ALIASES,
 // checked in PC order like the JS ordered choice — e.g. "Choir" is @52,
-// not Pad 4); keep both lists in sync.
+// not Pad 4); keep it and the pure-Rust port of this grammar
+// (chord2mml-core/src/pure_parser.rs) in sync.
 const GM_INSTRUMENT_ALIASES = [
   'piano~1', 'acoustic grand piano', 'grand piano', 'pf',
   'piano~2', 'bright acoustic piano',
@@ -189,10 +192,18 @@ module.exports = grammar({
       `${ci('key')}[ =:]?[A-G][#＃♯]*[b♭]*(${ci('minor')}|m)?[,.]?`
     )),
 
-    // Scale directive (JS SCALE: the seven church modes, case-insensitive)
+    // Scale directive (JS SCALE: the seven church modes, case-insensitive),
+    // plus the Rust-version non-diatonic scales; '~' marks an optional
+    // space or hyphen. Offsets live in cst_to_ast.rs (parse_scale_offsets).
     scale: $ => token(new RegExp(
       `(${['ionian', 'dorian', 'phrygian', 'lydian', 'mixolydian', 'aeolian', 'locrian']
-        .map(ci).join('|')})[,.]?`
+        .map(ci)
+        .concat([
+          'harmonic~minor', 'melodic~minor', 'harmonic~major',
+          'whole~tone', 'diminished', 'half~whole', 'whole~half',
+          'major~pentatonic', 'minor~pentatonic', 'pentatonic', 'blues',
+        ].map(name => ci(name).replace(/~/g, '[ -]?')))
+        .join('|')})[,.]?`
     )),
 
     // Slash-chord mode directives (JS SLASH_CHORD_MODE_*)
@@ -321,7 +332,8 @@ module.exports = grammar({
     ),
 
     // Quality modifiers (JS: OMIT_N / ADD_N / FLATTED_FIFTH /
-    // AUGMENTED_FIFTH; parens optional for add/omit, required for fifths)
+    // AUGMENTED_FIFTH; parens optional for add/omit, required for fifths),
+    // plus the Rust-version altered tensions, parens required like fifths
     quality_modifier: $ => choice(
       /\(?add[0-9]+\)?/,
       /\(?(omit|o)[135]\)?/,
@@ -329,6 +341,14 @@ module.exports = grammar({
       '(-5)',
       '(+5)',
       '(#5)',
+      '(b9)',
+      '(-9)',
+      '(#9)',
+      '(+9)',
+      '(#11)',
+      '(+11)',
+      '(b13)',
+      '(-13)',
     ),
 
     // Lower part of a slash chord / on-chord: root, quality, and inversion